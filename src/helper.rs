@@ -1,7 +1,10 @@
 pub mod address;
+pub(crate) mod conditional_formatting;
 pub mod coordinate;
 pub mod crypt;
+pub(crate) mod data_validation;
 pub mod date;
+pub(crate) mod evaluator;
 pub mod formula;
 pub mod html;
 pub mod number_format;
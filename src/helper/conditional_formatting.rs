@@ -0,0 +1,644 @@
+//! Evaluation of conditional formatting.
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use hashbrown::HashMap;
+use helper::evaluator::*;
+use std::cmp::Ordering;
+use structs::Color;
+use structs::ConditionalFormatValueObject;
+use structs::ConditionalFormatValueObjectValues;
+use structs::ConditionalFormatValues;
+use structs::ConditionalFormatting;
+use structs::ConditionalFormattingOperatorValues;
+use structs::ConditionalFormattingRule;
+use structs::EffectiveStyle;
+use structs::Fill;
+use structs::Formula;
+use structs::PatternValues;
+use structs::Spreadsheet;
+use structs::Style;
+use structs::TimePeriodValues;
+use structs::Worksheet;
+
+/// Values of the ranges of the rules.
+/// Made once for the cells evaluated together.
+#[derive(Default)]
+pub(crate) struct RangeCache {
+    map: HashMap<Vec<(u32, u32, u32, u32)>, RangeSummary>,
+}
+
+impl RangeCache {
+    fn get(&mut self, worksheet: &Worksheet, ranges: &[(u32, u32, u32, u32)]) -> &RangeSummary {
+        self.map
+            .entry(ranges.to_vec())
+            .or_insert_with(|| RangeSummary::new(get_range_values(worksheet, ranges)))
+    }
+}
+
+struct RangeSummary {
+    /// Numbers in the ascending order.
+    numbers: Vec<f64>,
+    average: f64,
+    /// Population standard deviation.
+    std_dev: f64,
+    /// Count of each value by `get_value_key`.
+    counts: HashMap<(u8, String), usize>,
+}
+
+impl RangeSummary {
+    fn new(values: Vec<Value>) -> Self {
+        let mut counts: HashMap<(u8, String), usize> = HashMap::new();
+        for value in &values {
+            *counts.entry(get_value_key(value)).or_insert(0) += 1;
+        }
+        let mut numbers: Vec<f64> = values
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::Number(v) => Some(v),
+                _ => None,
+            })
+            .collect();
+        numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let count = numbers.len() as f64;
+        let average = numbers.iter().sum::<f64>() / count;
+        let variance = numbers.iter().map(|x| (x - average).powi(2)).sum::<f64>() / count;
+        Self {
+            numbers,
+            average,
+            std_dev: variance.sqrt(),
+            counts,
+        }
+    }
+
+    fn get_count(&self, value: &Value) -> usize {
+        self.counts.get(&get_value_key(value)).copied().unwrap_or(0)
+    }
+}
+
+/// Key of the values that are equal for `compare` and of the same type.
+fn get_value_key(value: &Value) -> (u8, String) {
+    let key = match value {
+        Value::Number(v) if *v == 0f64 => 0f64.to_bits().to_string(),
+        Value::Number(v) => v.to_bits().to_string(),
+        Value::Text(v) => v.to_lowercase(),
+        _ => to_text(value),
+    };
+    (get_type_rank(value), key)
+}
+
+/// Get the look of a cell after the conditional formatting of the worksheet is applied.
+pub(crate) fn get_effective_style(
+    worksheet: &Worksheet,
+    spreadsheet: Option<&Spreadsheet>,
+    col: u32,
+    row: u32,
+    cache: &mut RangeCache,
+) -> EffectiveStyle {
+    let mut result = EffectiveStyle::default();
+    result.set_style(worksheet.get_style((col, row)).clone());
+
+    let mut rules: Vec<(&ConditionalFormatting, &ConditionalFormattingRule)> = Vec::new();
+    for conditional_formatting in worksheet.get_conditional_formatting_collection() {
+        let ranges = get_ranges(conditional_formatting.get_sequence_of_references());
+        if !ranges.iter().any(|v| is_in_range(v, col, row)) {
+            continue;
+        }
+        for rule in conditional_formatting.get_conditional_collection() {
+            rules.push((conditional_formatting, rule));
+        }
+    }
+    rules.sort_by_key(|(_, rule)| *rule.get_priority());
+
+    let value = get_cell_value(worksheet, col, row);
+    let mut claimed = Claimed::default();
+    for (conditional_formatting, rule) in rules {
+        let ranges = get_ranges(conditional_formatting.get_sequence_of_references());
+        let context = Context {
+            worksheet,
+            spreadsheet,
+            col,
+            row,
+            col_offset: col as i64 - ranges[0].0 as i64,
+            row_offset: row as i64 - ranges[0].1 as i64,
+        };
+        match rule.get_type() {
+            ConditionalFormatValues::ColorScale => {
+                if let (Some(color_scale), Value::Number(v)) = (rule.get_color_scale(), &value) {
+                    if result.get_color_scale_color().is_some() {
+                        continue;
+                    }
+                    let values = &cache.get(worksheet, &ranges).numbers;
+                    if let Some(color) = get_color_scale_color(
+                        &context,
+                        color_scale.get_cfvo_collection(),
+                        color_scale.get_color_collection(),
+                        &values,
+                        *v,
+                    ) {
+                        if !claimed.fill {
+                            claimed.fill = true;
+                            result
+                                .get_style_mut()
+                                .set_background_color(color.get_argb());
+                        }
+                        result.set_color_scale_color(color);
+                    }
+                }
+                continue;
+            }
+            ConditionalFormatValues::DataBar => {
+                if let (Some(data_bar), Value::Number(v)) = (rule.get_data_bar(), &value) {
+                    if result.get_data_bar_length().is_some() {
+                        continue;
+                    }
+                    let values = &cache.get(worksheet, &ranges).numbers;
+                    let cfvo = data_bar.get_cfvo_collection();
+                    if cfvo.len() < 2 {
+                        continue;
+                    }
+                    let (min, max) = match (
+                        get_threshold(&context, &cfvo[0], &values),
+                        get_threshold(&context, &cfvo[1], &values),
+                    ) {
+                        (Some(min), Some(max)) => (min, max),
+                        _ => continue,
+                    };
+                    let ratio = if max > min {
+                        ((v - min) / (max - min)).clamp(0f64, 1f64)
+                    } else {
+                        1f64
+                    };
+                    let min_length = *data_bar.get_x14_min_length() as f64;
+                    let max_length = *data_bar.get_x14_max_length() as f64;
+                    result.set_data_bar_length(
+                        (min_length + (max_length - min_length) * ratio) / 100f64,
+                    );
+                    let color = match data_bar.get_fill_color() {
+                        Some(v) => Some(v.clone()),
+                        None => data_bar.get_color_collection().first().cloned(),
+                    };
+                    if let Some(v) = color {
+                        result.set_data_bar_color(v);
+                    }
+                }
+                continue;
+            }
+            ConditionalFormatValues::IconSet => {
+                if let (Some(icon_set), Value::Number(v)) = (rule.get_icon_set(), &value) {
+                    if result.get_icon().is_some() {
+                        continue;
+                    }
+                    let values = &cache.get(worksheet, &ranges).numbers;
+                    let cfvo = icon_set.get_cfvo_collection();
+                    let mut index = 0;
+                    for (i, threshold) in cfvo.iter().enumerate().skip(1) {
+                        let t = match get_threshold(&context, threshold, &values) {
+                            Some(t) => t,
+                            None => continue,
+                        };
+                        if *v > t || (*threshold.get_gte() && *v == t) {
+                            index = i;
+                        }
+                    }
+                    if *icon_set.get_reverse() {
+                        index = cfvo.len().saturating_sub(1) - index;
+                    }
+                    match icon_set.get_cf_icon_collection().get(index) {
+                        Some(icon) => {
+                            result.set_icon(icon.get_icon_set().clone(), *icon.get_icon_id());
+                        }
+                        None => {
+                            result.set_icon(icon_set.get_icon_set().clone(), index as u32);
+                        }
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        if !is_match(&context, &ranges, rule, &value, cache) {
+            continue;
+        }
+        if let Some(style) = rule.get_style() {
+            merge_style(result.get_style_mut(), style, &mut claimed);
+        }
+        if *rule.get_stop_if_true() {
+            break;
+        }
+    }
+    result
+}
+
+/// Parts of the style already given by a rule of higher priority.
+#[derive(Default)]
+struct Claimed {
+    font_color: bool,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strike: bool,
+    fill: bool,
+    number_format: bool,
+    left: bool,
+    right: bool,
+    top: bool,
+    bottom: bool,
+}
+
+fn merge_style(result: &mut Style, dxf: &Style, claimed: &mut Claimed) {
+    if let Some(font) = dxf.get_font() {
+        if !claimed.font_color && font.get_color().has_value() {
+            claimed.font_color = true;
+            result.get_font_mut().set_color(font.get_color().clone());
+        }
+        if !claimed.bold && font.get_font_bold().val.has_value() {
+            claimed.bold = true;
+            result.get_font_mut().set_bold(*font.get_bold());
+        }
+        if !claimed.italic && font.get_font_italic().val.has_value() {
+            claimed.italic = true;
+            result.get_font_mut().set_italic(*font.get_italic());
+        }
+        if !claimed.underline && font.get_font_underline().val.has_value() {
+            claimed.underline = true;
+            result
+                .get_font_mut()
+                .set_font_underline(font.get_font_underline().clone());
+        }
+        if !claimed.strike && font.get_font_strike().val.has_value() {
+            claimed.strike = true;
+            result
+                .get_font_mut()
+                .set_strikethrough(*font.get_strikethrough());
+        }
+    }
+
+    if let Some(fill) = dxf.get_fill() {
+        if !claimed.fill {
+            claimed.fill = true;
+            match fill.get_pattern_fill() {
+                // a solid fill of a differential format is given by the background color.
+                Some(pattern_fill)
+                    if pattern_fill.get_foreground_color().is_none()
+                        && pattern_fill.get_pattern_type() == &PatternValues::None =>
+                {
+                    let mut obj = Fill::default();
+                    if let Some(color) = pattern_fill.get_background_color() {
+                        obj.get_pattern_fill_mut()
+                            .set_pattern_type(PatternValues::Solid)
+                            .set_foreground_color(color.clone());
+                    }
+                    result.set_fill(obj);
+                }
+                _ => {
+                    result.set_fill(fill.clone());
+                }
+            }
+        }
+    }
+
+    if let Some(number_format) = dxf.get_number_format() {
+        if !claimed.number_format {
+            claimed.number_format = true;
+            result.set_number_format(number_format.clone());
+        }
+    }
+
+    if let Some(borders) = dxf.get_borders() {
+        if !claimed.left && borders.get_left().get_border_style() != "none" {
+            claimed.left = true;
+            result
+                .get_borders_mut()
+                .set_left(borders.get_left().clone());
+        }
+        if !claimed.right && borders.get_right().get_border_style() != "none" {
+            claimed.right = true;
+            result
+                .get_borders_mut()
+                .set_right(borders.get_right().clone());
+        }
+        if !claimed.top && borders.get_top().get_border_style() != "none" {
+            claimed.top = true;
+            result.get_borders_mut().set_top(borders.get_top().clone());
+        }
+        if !claimed.bottom && borders.get_bottom().get_border_style() != "none" {
+            claimed.bottom = true;
+            result
+                .get_borders_mut()
+                .set_bottom(borders.get_bottom().clone());
+        }
+    }
+}
+
+fn is_match(
+    context: &Context,
+    ranges: &[(u32, u32, u32, u32)],
+    rule: &ConditionalFormattingRule,
+    value: &Value,
+    cache: &mut RangeCache,
+) -> bool {
+    match rule.get_type() {
+        ConditionalFormatValues::CellIs => {
+            let get_operand = |formula: &Option<Formula>| {
+                formula
+                    .as_ref()
+                    .and_then(|v| evaluate(context, &v.get_address_str()))
+            };
+            let operand = match get_operand(rule.get_formula()) {
+                Some(v) => v,
+                None => return false,
+            };
+            let ordering = compare(value, &operand);
+            match rule.get_operator() {
+                ConditionalFormattingOperatorValues::Between
+                | ConditionalFormattingOperatorValues::NotBetween => {
+                    let operand2 = match get_operand(rule.get_formula2()) {
+                        Some(v) => v,
+                        None => return false,
+                    };
+                    // the bounds may be given in either order.
+                    let (lower, upper) = match compare(&operand, &operand2) {
+                        Ordering::Greater => (operand2, operand),
+                        _ => (operand, operand2),
+                    };
+                    let is_between = compare(value, &lower) != Ordering::Less
+                        && compare(value, &upper) != Ordering::Greater;
+                    match rule.get_operator() {
+                        ConditionalFormattingOperatorValues::Between => is_between,
+                        _ => !is_between,
+                    }
+                }
+                ConditionalFormattingOperatorValues::Equal => ordering == Ordering::Equal,
+                ConditionalFormattingOperatorValues::NotEqual => ordering != Ordering::Equal,
+                ConditionalFormattingOperatorValues::GreaterThan => ordering == Ordering::Greater,
+                ConditionalFormattingOperatorValues::GreaterThanOrEqual => {
+                    ordering != Ordering::Less
+                }
+                ConditionalFormattingOperatorValues::LessThan => ordering == Ordering::Less,
+                ConditionalFormattingOperatorValues::LessThanOrEqual => {
+                    ordering != Ordering::Greater
+                }
+                _ => false,
+            }
+        }
+        ConditionalFormatValues::Expression => match rule.get_formula() {
+            Some(v) => match evaluate(context, &v.get_address_str()) {
+                Some(v) => is_true(&v),
+                None => false,
+            },
+            None => false,
+        },
+        ConditionalFormatValues::Top10 => {
+            let v = match value {
+                Value::Number(v) => *v,
+                _ => return false,
+            };
+            let values = &cache.get(context.worksheet, ranges).numbers;
+            if values.is_empty() {
+                return false;
+            }
+            let mut rank = *rule.get_rank() as usize;
+            if *rule.get_percent() {
+                rank = (values.len() * rank / 100).max(1);
+            }
+            let rank = rank.clamp(1, values.len());
+            if *rule.get_bottom() {
+                v <= values[rank - 1]
+            } else {
+                v >= values[values.len() - rank]
+            }
+        }
+        ConditionalFormatValues::AboveAverage => {
+            let v = match value {
+                Value::Number(v) => *v,
+                _ => return false,
+            };
+            let summary = cache.get(context.worksheet, ranges);
+            if summary.numbers.is_empty() {
+                return false;
+            }
+            let average = summary.average;
+            let mut limit = average;
+            if *rule.get_std_dev() != 0 {
+                let std_dev = summary.std_dev * *rule.get_std_dev() as f64;
+                limit = if *rule.get_above_average() {
+                    average + std_dev
+                } else {
+                    average - std_dev
+                };
+            }
+            match (*rule.get_above_average(), *rule.get_equal_average()) {
+                (true, true) => v >= limit,
+                (true, false) => v > limit,
+                (false, true) => v <= limit,
+                (false, false) => v < limit,
+            }
+        }
+        ConditionalFormatValues::DuplicateValues | ConditionalFormatValues::UniqueValues => {
+            if value == &Value::Empty {
+                return false;
+            }
+            let count = cache.get(context.worksheet, ranges).get_count(value);
+            match rule.get_type() {
+                ConditionalFormatValues::DuplicateValues => count > 1,
+                _ => count == 1,
+            }
+        }
+        ConditionalFormatValues::ContainsText
+        | ConditionalFormatValues::NotContainsText
+        | ConditionalFormatValues::BeginsWith
+        | ConditionalFormatValues::EndsWith => {
+            let text = to_text(value).to_lowercase();
+            let find = rule.get_text().to_lowercase();
+            match rule.get_type() {
+                ConditionalFormatValues::ContainsText => text.contains(&find),
+                ConditionalFormatValues::NotContainsText => !text.contains(&find),
+                ConditionalFormatValues::BeginsWith => text.starts_with(&find),
+                _ => text.ends_with(&find),
+            }
+        }
+        ConditionalFormatValues::ContainsBlanks => to_text(value).trim().is_empty(),
+        ConditionalFormatValues::NotContainsBlanks => !to_text(value).trim().is_empty(),
+        ConditionalFormatValues::ContainsErrors => value == &Value::Error,
+        ConditionalFormatValues::NotContainsErrors => value != &Value::Error,
+        ConditionalFormatValues::TimePeriod => {
+            let v = match value {
+                Value::Number(v) => v.floor() as i64,
+                _ => return false,
+            };
+            // compare in the 1900 date system.
+            let offset = if *context.worksheet.get_date1904() {
+                1462
+            } else {
+                0
+            };
+            is_in_time_period(
+                rule.get_time_period(),
+                v + offset,
+                get_serial_date(Local::now().date_naive(), false),
+            )
+        }
+        _ => false,
+    }
+}
+
+fn is_in_time_period(time_period: &TimePeriodValues, value: i64, today: i64) -> bool {
+    // weeks start on sunday. (1899-12-31 is a sunday)
+    let week_start = today - (today - 1).rem_euclid(7);
+    let month = |serial: i64| {
+        let date = get_date(serial);
+        date.year() * 12 + date.month() as i32
+    };
+    match time_period {
+        TimePeriodValues::Today => value == today,
+        TimePeriodValues::Yesterday => value == today - 1,
+        TimePeriodValues::Tomorrow => value == today + 1,
+        TimePeriodValues::Last7Days => value > today - 7 && value <= today,
+        TimePeriodValues::ThisWeek => value >= week_start && value < week_start + 7,
+        TimePeriodValues::LastWeek => value >= week_start - 7 && value < week_start,
+        TimePeriodValues::NextWeek => value >= week_start + 7 && value < week_start + 14,
+        TimePeriodValues::ThisMonth => month(value) == month(today),
+        TimePeriodValues::LastMonth => month(value) == month(today) - 1,
+        TimePeriodValues::NextMonth => month(value) == month(today) + 1,
+    }
+}
+
+fn get_date(serial: i64) -> NaiveDate {
+    NaiveDate::from_ymd_opt(1899, 12, 30).unwrap() + Duration::days(serial)
+}
+
+fn get_color_scale_color(
+    context: &Context,
+    cfvo_collection: &[ConditionalFormatValueObject],
+    color_collection: &[Color],
+    values: &[f64],
+    value: f64,
+) -> Option<Color> {
+    if cfvo_collection.len() < 2 || cfvo_collection.len() != color_collection.len() {
+        return None;
+    }
+    let mut thresholds = Vec::new();
+    for cfvo in cfvo_collection {
+        thresholds.push(get_threshold(context, cfvo, values)?);
+    }
+    if value <= thresholds[0] {
+        return Some(color_collection[0].clone());
+    }
+    for i in 1..thresholds.len() {
+        if value <= thresholds[i] {
+            let width = thresholds[i] - thresholds[i - 1];
+            let ratio = if width > 0f64 {
+                (value - thresholds[i - 1]) / width
+            } else {
+                1f64
+            };
+            let argb = interpolate_argb(
+                color_collection[i - 1].get_argb(),
+                color_collection[i].get_argb(),
+                ratio,
+            )?;
+            let mut color = Color::default();
+            color.set_argb(argb);
+            return Some(color);
+        }
+    }
+    color_collection.last().cloned()
+}
+
+fn interpolate_argb(from: &str, to: &str, ratio: f64) -> Option<String> {
+    let parse = |argb: &str| -> Option<Vec<u8>> {
+        let argb = if argb.len() == 6 {
+            format!("FF{}", argb)
+        } else {
+            argb.to_string()
+        };
+        if argb.len() != 8 {
+            return None;
+        }
+        (0..4)
+            .map(|i| u8::from_str_radix(&argb[i * 2..i * 2 + 2], 16).ok())
+            .collect()
+    };
+    let from = parse(from)?;
+    let to = parse(to)?;
+    Some(
+        from.iter()
+            .zip(to.iter())
+            .map(|(a, b)| {
+                let v = *a as f64 + (*b as f64 - *a as f64) * ratio;
+                format!("{:02X}", v.round() as u8)
+            })
+            .collect(),
+    )
+}
+
+fn get_threshold(
+    context: &Context,
+    cfvo: &ConditionalFormatValueObject,
+    values: &[f64],
+) -> Option<f64> {
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if values.is_empty() {
+        return None;
+    }
+    let val = || match evaluate(context, cfvo.get_val()) {
+        Some(Value::Number(v)) => Some(v),
+        _ => None,
+    };
+    match cfvo.get_type() {
+        ConditionalFormatValueObjectValues::Min => Some(min),
+        ConditionalFormatValueObjectValues::Max => Some(max),
+        ConditionalFormatValueObjectValues::AutoMin => Some(min.min(0f64)),
+        ConditionalFormatValueObjectValues::AutoMax => Some(max.max(0f64)),
+        ConditionalFormatValueObjectValues::Number
+        | ConditionalFormatValueObjectValues::Formula => val(),
+        ConditionalFormatValueObjectValues::Percent => Some(min + (max - min) * val()? / 100f64),
+        ConditionalFormatValueObjectValues::Percentile => {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            let rank = (val()? / 100f64).clamp(0f64, 1f64) * (sorted.len() - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64))
+        }
+    }
+}
+
+fn get_range_values(worksheet: &Worksheet, ranges: &[(u32, u32, u32, u32)]) -> Vec<Value> {
+    let cells = worksheet
+        .get_cell_collection_crate()
+        .get_collection_to_hashmap();
+    let area: u64 = ranges
+        .iter()
+        .map(|v| (v.2 - v.0 + 1) as u64 * (v.3 - v.1 + 1) as u64)
+        .sum();
+    // look up the cells of a small range instead of scanning the worksheet.
+    let values: Vec<Value> = if area <= cells.len() as u64 {
+        let mut result = Vec::new();
+        for (i, range) in ranges.iter().enumerate() {
+            for row in range.1..=range.3 {
+                for col in range.0..=range.2 {
+                    if ranges[..i].iter().any(|v| is_in_range(v, col, row)) {
+                        continue;
+                    }
+                    if let Some(cell) = worksheet.get_cell((col, row)) {
+                        result.push(to_value(cell.get_raw_value()));
+                    }
+                }
+            }
+        }
+        result
+    } else {
+        cells
+            .values()
+            .filter(|cell| {
+                let col = *cell.get_coordinate().get_col_num();
+                let row = *cell.get_coordinate().get_row_num();
+                ranges.iter().any(|v| is_in_range(v, col, row))
+            })
+            .map(|cell| to_value(cell.get_raw_value()))
+            .collect()
+    };
+    values.into_iter().filter(|v| v != &Value::Empty).collect()
+}
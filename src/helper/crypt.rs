@@ -483,6 +483,23 @@ fn gen_random_16() -> Vec<u8> {
     buf.to_vec()
 }
 
+/// Make a random GUID. ex) `{1B2F8C3A-...}`
+pub(crate) fn make_guid() -> String {
+    let mut buf = gen_random_16();
+    // version 4, variant 1
+    buf[6] = (buf[6] & 0x0f) | 0x40;
+    buf[8] = (buf[8] & 0x3f) | 0x80;
+    let hex: String = buf.iter().map(|v| format!("{:02X}", v)).collect();
+    format!(
+        "{{{}-{}-{}-{}-{}}}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
 fn gen_random_32() -> Vec<u8> {
     let buf: &mut [u8] = &mut [0; 32];
     let _ = getrandom::getrandom(buf);
@@ -0,0 +1,141 @@
+//! Validation of cell values against data validations.
+use helper::evaluator::*;
+use std::cmp::Ordering;
+use structs::DataValidation;
+use structs::DataValidationOperatorValues;
+use structs::DataValidationValues;
+use structs::DataValidationViolation;
+use structs::Spreadsheet;
+use structs::Worksheet;
+
+/// Get the cells whose value does not satisfy the data validations of the worksheet,
+/// and the cells whose data validation cannot be evaluated.
+pub(crate) fn validate_cells(
+    worksheet: &Worksheet,
+    spreadsheet: Option<&Spreadsheet>,
+) -> Vec<DataValidationViolation> {
+    let mut result = Vec::new();
+    let data_validations = match worksheet.get_data_validations() {
+        Some(v) => v,
+        None => return result,
+    };
+    let (highest_col, highest_row) = worksheet.get_highest_column_and_row();
+    for data_validation in data_validations.get_data_validation_list() {
+        if data_validation.get_type() == &DataValidationValues::None {
+            continue;
+        }
+        let ranges = get_ranges(data_validation.get_sequence_of_references());
+        let (col_start, row_start) = match ranges.first() {
+            Some(v) => (v.0, v.1),
+            None => continue,
+        };
+        for range in &ranges {
+            // cells after the used area are blank.
+            for row in range.1..=range.3.min(highest_row) {
+                for col in range.0..=range.2.min(highest_col) {
+                    let value = get_cell_value(worksheet, col, row);
+                    if value == Value::Empty && *data_validation.get_allow_blank() {
+                        continue;
+                    }
+                    let context = Context {
+                        worksheet,
+                        spreadsheet,
+                        col,
+                        row,
+                        col_offset: col as i64 - col_start as i64,
+                        row_offset: row as i64 - row_start as i64,
+                    };
+                    let is_valid = is_valid(&context, data_validation, &value);
+                    if is_valid == Some(true) {
+                        continue;
+                    }
+                    let mut obj = DataValidationViolation::default();
+                    obj.set_coordinate(col, row)
+                        .set_value(to_text(&value))
+                        .set_data_validation(data_validation.clone())
+                        .set_unevaluated(is_valid.is_none());
+                    result.push(obj);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Returns None if the data validation cannot be evaluated.
+fn is_valid(context: &Context, data_validation: &DataValidation, value: &Value) -> Option<bool> {
+    match data_validation.get_type() {
+        DataValidationValues::None => Some(true),
+        DataValidationValues::Custom => {
+            evaluate(context, data_validation.get_formula1()).map(|v| is_true(&v))
+        }
+        DataValidationValues::List => {
+            let formula = data_validation.get_formula1();
+            let list = match formula.strip_prefix('"') {
+                // a list of items. ex) "a,b,c"
+                Some(v) => v
+                    .trim_end_matches('"')
+                    .split(',')
+                    .map(|v| match v.parse::<f64>() {
+                        Ok(v) => Value::Number(v),
+                        Err(_) => Value::Text(v.to_string()),
+                    })
+                    .collect(),
+                // a range of cells.
+                None => evaluate_list(context, formula)?,
+            };
+            Some(list.iter().any(|v| {
+                v != &Value::Empty
+                    && get_type_rank(v) == get_type_rank(value)
+                    && compare(v, value) == Ordering::Equal
+            }))
+        }
+        DataValidationValues::TextLength => {
+            let length = Value::Number(to_text(value).chars().count() as f64);
+            is_in_bounds(context, data_validation, &length)
+        }
+        DataValidationValues::Whole => match value {
+            Value::Number(v) if v.fract() == 0f64 => is_in_bounds(context, data_validation, value),
+            _ => Some(false),
+        },
+        DataValidationValues::Decimal | DataValidationValues::Date | DataValidationValues::Time => {
+            match value {
+                Value::Number(_) => is_in_bounds(context, data_validation, value),
+                _ => Some(false),
+            }
+        }
+    }
+}
+
+fn is_in_bounds(
+    context: &Context,
+    data_validation: &DataValidation,
+    value: &Value,
+) -> Option<bool> {
+    let get_bound = |formula: &str| match evaluate(context, formula)? {
+        Value::Number(v) => Some(v),
+        _ => None,
+    };
+    let value = match value {
+        Value::Number(v) => *v,
+        _ => return Some(false),
+    };
+    let formula1 = get_bound(data_validation.get_formula1())?;
+    let result = match data_validation.get_operator() {
+        DataValidationOperatorValues::Between => {
+            let formula2 = get_bound(data_validation.get_formula2())?;
+            value >= formula1 && value <= formula2
+        }
+        DataValidationOperatorValues::NotBetween => {
+            let formula2 = get_bound(data_validation.get_formula2())?;
+            value < formula1 || value > formula2
+        }
+        DataValidationOperatorValues::Equal => value == formula1,
+        DataValidationOperatorValues::NotEqual => value != formula1,
+        DataValidationOperatorValues::GreaterThan => value > formula1,
+        DataValidationOperatorValues::GreaterThanOrEqual => value >= formula1,
+        DataValidationOperatorValues::LessThan => value < formula1,
+        DataValidationOperatorValues::LessThanOrEqual => value <= formula1,
+    };
+    Some(result)
+}
@@ -1,45 +1,121 @@
-use chrono::{Duration, NaiveDateTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Timelike};
 
 pub const CALENDAR_WINDOWS_1900: &str = "1900";
 pub const CALENDAR_MAC_1904: &str = "1904";
 
+const MILLISECONDS_PER_DAY: f64 = 86_400_000f64;
+
+/// Convert a serial date to a date time.
+/// The serial date has no time zone, so `time_zone` does not change the result.
 pub fn excel_to_date_time_object(
     excel_timestamp: &f64,
     time_zone: Option<String>,
 ) -> NaiveDateTime {
-    let _time_zone = match time_zone {
-        Some(v) => v,
-        None => get_default_timezone(),
+    excel_to_date_time_object_by_calendar(excel_timestamp, time_zone, CALENDAR_WINDOWS_1900)
+}
+
+/// Convert a serial date of the date system to a date time.
+/// # Arguments
+/// * `calendar` - `CALENDAR_WINDOWS_1900` or `CALENDAR_MAC_1904`.
+pub fn excel_to_date_time_object_by_calendar(
+    excel_timestamp: &f64,
+    _time_zone: Option<String>,
+    calendar: &str,
+) -> NaiveDateTime {
+    let date1904 = calendar == CALENDAR_MAC_1904;
+    // 1900-02-29 does not exist, so it is shown as 1900-02-28.
+    excel_to_date_time(excel_timestamp, date1904)
+        .or_else(|| excel_to_date_time(&(excel_timestamp - 1f64), date1904))
+        .unwrap_or_default()
+}
+
+/// Convert a serial date to a date time. (in milliseconds)
+/// # Arguments
+/// * `date1904` - Whether the serial date is of the 1904 date system.
+/// # Return value
+/// * `Option<NaiveDateTime>` - None if the serial date is negative or 60 of the 1900 date system. (1900-02-29 which Excel treats as a leap day)
+pub fn excel_to_date_time(serial: &f64, date1904: bool) -> Option<NaiveDateTime> {
+    if !serial.is_finite() || serial < &0f64 {
+        return None;
+    }
+    let milliseconds = (serial * MILLISECONDS_PER_DAY).round() as i64;
+    let days = milliseconds.div_euclid(MILLISECONDS_PER_DAY as i64);
+    let milliseconds = milliseconds.rem_euclid(MILLISECONDS_PER_DAY as i64);
+    let base_date = match (date1904, days) {
+        (true, _) => NaiveDate::from_ymd_opt(1904, 1, 1)?,
+        (false, 60) => return None,
+        (false, 0..=59) => NaiveDate::from_ymd_opt(1899, 12, 31)?,
+        (false, _) => NaiveDate::from_ymd_opt(1899, 12, 30)?,
     };
+    Some(
+        base_date.and_hms_opt(0, 0, 0)?
+            + Duration::days(days)
+            + Duration::milliseconds(milliseconds),
+    )
+}
 
-    let mut base_date = if excel_timestamp < &1f64 {
-        // Unix timestamp base date
-        NaiveDateTime::parse_from_str("1970-01-01 00:00:00", "%Y-%m-%d %T").unwrap()
+/// Convert a date time to a serial date. (in milliseconds)
+/// # Arguments
+/// * `date1904` - Whether the serial date is of the 1904 date system.
+pub fn date_time_to_excel(value: &NaiveDateTime, date1904: bool) -> f64 {
+    let date = value.date();
+    let days = if date1904 {
+        (date - NaiveDate::from_ymd_opt(1904, 1, 1).unwrap()).num_days()
     } else {
-        // Allow adjustment for 1900 Leap Year in MS Excel
-        if excel_timestamp < &60f64 {
-            NaiveDateTime::parse_from_str("1899-12-31 00:00:00", "%Y-%m-%d %T").unwrap()
+        let days = (date - NaiveDate::from_ymd_opt(1899, 12, 30).unwrap()).num_days();
+        // before the leap day of Excel.
+        if days < 61 {
+            days - 1
         } else {
-            NaiveDateTime::parse_from_str("1899-12-30 00:00:00", "%Y-%m-%d %T").unwrap()
+            days
         }
     };
+    let time = value.time();
+    let milliseconds = time.num_seconds_from_midnight() as f64 * 1000f64
+        + (time.nanosecond() as f64 / 1_000_000f64).round();
+    days as f64 + milliseconds / MILLISECONDS_PER_DAY
+}
 
-    let days = excel_timestamp.floor();
-    let part_day = excel_timestamp - days;
-    let hours = (part_day * 24.0).floor();
-    let part_day = part_day * 24f64 - hours;
-    let minutes = (part_day * 60f64).floor();
-    let part_day = part_day * 60f64 - minutes;
-    let seconds = (part_day * 60f64).round();
-
-    base_date += Duration::days(days as i64);
-    base_date += Duration::hours(hours as i64);
-    base_date += Duration::minutes(minutes as i64);
-    base_date += Duration::seconds(seconds as i64);
-
-    base_date
+/// Convert a duration to a number of days.
+pub fn duration_to_excel(value: &Duration) -> f64 {
+    value.num_milliseconds() as f64 / MILLISECONDS_PER_DAY
 }
 
-fn get_default_timezone() -> String {
-    String::from("UTC")
+#[test]
+fn excel_to_date_time_test() {
+    let date_time = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    assert_eq!(
+        excel_to_date_time(&1f64, false),
+        Some(date_time("1900-01-01 00:00:00"))
+    );
+    assert_eq!(
+        excel_to_date_time(&59.5, false),
+        Some(date_time("1900-02-28 12:00:00"))
+    );
+    assert_eq!(excel_to_date_time(&60f64, false), None);
+    assert_eq!(
+        excel_to_date_time(&61f64, false),
+        Some(date_time("1900-03-01 00:00:00"))
+    );
+    assert_eq!(
+        excel_to_date_time(&0f64, true),
+        Some(date_time("1904-01-01 00:00:00"))
+    );
+
+    for value in [
+        "1900-01-01 00:00:00",
+        "1900-02-28 23:59:59.999",
+        "1900-03-01 00:00:00",
+        "2024-05-23 13:45:10.123",
+        "9999-12-31 23:59:59.999",
+    ] {
+        let value = date_time(value);
+        for date1904 in [false, true] {
+            if date1904 && value.date() < NaiveDate::from_ymd_opt(1904, 1, 1).unwrap() {
+                continue;
+            }
+            let serial = date_time_to_excel(&value, date1904);
+            assert_eq!(excel_to_date_time(&serial, date1904), Some(value));
+        }
+    }
 }
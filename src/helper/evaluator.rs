@@ -0,0 +1,837 @@
+//! Evaluation of the formulas of conditional formatting and data validation.
+use chrono::{Local, NaiveDate};
+use helper::coordinate::*;
+use helper::range::*;
+use std::cmp::Ordering;
+use structs::CellRawValue;
+use structs::SequenceOfReferences;
+use structs::Spreadsheet;
+use structs::Worksheet;
+
+const MAX_COLUMN: u32 = 16384;
+const MAX_ROW: u32 = 1048576;
+/// Limit of nested defined names. (guards against circular names)
+const MAX_NAME_DEPTH: usize = 8;
+
+/// `(col_start, row_start, col_end, row_end)` of the ranges.
+pub(crate) fn get_ranges(
+    sequence_of_references: &SequenceOfReferences,
+) -> Vec<(u32, u32, u32, u32)> {
+    sequence_of_references
+        .get_range_collection()
+        .iter()
+        .map(|range| {
+            let (row_start, row_end, col_start, col_end) =
+                get_start_and_end_point(&range.get_range().replace('$', ""));
+            match (col_start, row_start) {
+                (0, _) => (1, row_start, MAX_COLUMN, row_end),
+                (_, 0) => (col_start, 1, col_end, MAX_ROW),
+                _ => (col_start, row_start, col_end, row_end),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn is_in_range(range: &(u32, u32, u32, u32), col: u32, row: u32) -> bool {
+    col >= range.0 && col <= range.2 && row >= range.1 && row <= range.3
+}
+
+pub(crate) fn get_cell_value(worksheet: &Worksheet, col: u32, row: u32) -> Value {
+    match worksheet.get_cell((col, row)) {
+        Some(cell) => to_value(cell.get_raw_value()),
+        None => Value::Empty,
+    }
+}
+
+pub(crate) fn to_value(raw_value: &CellRawValue) -> Value {
+    match raw_value {
+        CellRawValue::Numeric(v) => Value::Number(*v),
+        CellRawValue::Bool(v) => Value::Bool(*v),
+        CellRawValue::Error(_) => Value::Error,
+        CellRawValue::Null | CellRawValue::Inline => Value::Empty,
+        _ => {
+            let text = raw_value.to_string();
+            match text.as_str() {
+                "" => Value::Empty,
+                "#NULL!" | "#DIV/0!" | "#VALUE!" | "#REF!" | "#NAME?" | "#NUM!" | "#N/A" => {
+                    Value::Error
+                }
+                _ => Value::Text(text),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Empty,
+    Error,
+}
+
+enum Operand {
+    Value(Value),
+    Range(Vec<Value>),
+}
+
+impl Operand {
+    fn into_value(self) -> Value {
+        match self {
+            Operand::Value(v) => v,
+            Operand::Range(_) => Value::Error,
+        }
+    }
+}
+
+/// Cell of the rule and its offset from the top left cell of the range.
+/// Relative references of the formulas are moved by the offset.
+pub(crate) struct Context<'a> {
+    pub(crate) worksheet: &'a Worksheet,
+    /// Workbook of the worksheet. References to the other sheets need it.
+    pub(crate) spreadsheet: Option<&'a Spreadsheet>,
+    pub(crate) col: u32,
+    pub(crate) row: u32,
+    pub(crate) col_offset: i64,
+    pub(crate) row_offset: i64,
+}
+
+impl<'a> Context<'a> {
+    /// `(col, row)` of a part of a reference such as `$A1`.
+    /// A whole column such as `$A` has no row, and a whole row such as `3` has no column.
+    fn get_position(
+        &self,
+        reference: &str,
+        is_absolute: bool,
+    ) -> Option<(Option<u32>, Option<u32>)> {
+        let (col, row, col_lock, row_lock) = index_from_coordinate(reference);
+        if col.is_none() && row.is_none() {
+            return None;
+        }
+        let shift = |value: Option<u32>, lock: Option<bool>, offset: i64| match value {
+            Some(v) => {
+                let mut v = v as i64;
+                if !is_absolute && lock != Some(true) {
+                    v += offset;
+                }
+                if v < 1 {
+                    return None;
+                }
+                Some(Some(v as u32))
+            }
+            None => Some(None),
+        };
+        Some((
+            shift(col, col_lock, self.col_offset)?,
+            shift(row, row_lock, self.row_offset)?,
+        ))
+    }
+
+    /// Worksheet of a name used in a reference. (case insensitive)
+    /// Returns None if the workbook is not given or the worksheet is not deserialized.
+    fn get_worksheet(&self, name: &str) -> Option<&'a Worksheet> {
+        let name = name.to_lowercase();
+        if self.worksheet.get_name().to_lowercase() == name {
+            return Some(self.worksheet);
+        }
+        self.spreadsheet?
+            .get_sheet_collection_no_check()
+            .iter()
+            .find(|v| v.get_name().to_lowercase() == name)
+            .filter(|v| v.is_deserialized())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    /// Sheet name and reference. ex) `(Some("Sheet 2"), "$A$1:$A$9")`
+    Reference(Option<String>, String),
+    Function(String),
+    Operator(String),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize(formula: &str) -> Option<Vec<Token>> {
+    let chars: Vec<char> = formula.trim_start_matches('=').chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '"' => {
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('"') if chars.get(i + 1) == Some(&'"') => {
+                            text.push('"');
+                            i += 2;
+                        }
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(v) => {
+                            text.push(*v);
+                            i += 1;
+                        }
+                        None => return None,
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            '<' | '>' => {
+                let mut operator = c.to_string();
+                if let Some(next) = chars.get(i + 1) {
+                    if *next == '=' || (c == '<' && *next == '>') {
+                        operator.push(*next);
+                    }
+                }
+                i += operator.len();
+                tokens.push(Token::Operator(operator));
+            }
+            '=' | '+' | '-' | '*' | '/' | '^' | '&' => {
+                tokens.push(Token::Operator(c.to_string()));
+                i += 1;
+            }
+            '0'..='9' | '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                if chars.get(i) == Some(&':') {
+                    // a range of rows. ex) 1:3
+                    i = start;
+                    tokens.push(Token::Reference(None, read_word(&chars, &mut i)));
+                    continue;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(Token::Number(text.parse().ok()?));
+            }
+            '\'' => {
+                // a quoted sheet name. ex) 'Sheet 2'!A1
+                let mut sheet_name = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('\'') if chars.get(i + 1) == Some(&'\'') => {
+                            sheet_name.push('\'');
+                            i += 2;
+                        }
+                        Some('\'') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(v) => {
+                            sheet_name.push(*v);
+                            i += 1;
+                        }
+                        None => return None,
+                    }
+                }
+                if chars.get(i) != Some(&'!') {
+                    return None;
+                }
+                i += 1;
+                let reference = read_word(&chars, &mut i).to_uppercase();
+                tokens.push(Token::Reference(Some(sheet_name), reference));
+            }
+            _ if c.is_alphabetic() || c == '$' || c == '_' => {
+                let word = read_word(&chars, &mut i);
+                if chars.get(i) == Some(&'!') {
+                    i += 1;
+                    let reference = read_word(&chars, &mut i).to_uppercase();
+                    tokens.push(Token::Reference(Some(word), reference));
+                } else if chars.get(i) == Some(&'(') {
+                    tokens.push(Token::Function(word.to_uppercase()));
+                } else {
+                    tokens.push(Token::Reference(None, word.to_uppercase()));
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(tokens)
+}
+
+/// Read a name or a reference. ex) `SUM`, `$A$1:$B2`, `Sheet2`
+fn read_word(chars: &[char], i: &mut usize) -> String {
+    let start = *i;
+    while *i < chars.len()
+        && (chars[*i].is_alphanumeric()
+            || chars[*i] == '$'
+            || chars[*i] == '_'
+            || chars[*i] == '.'
+            || chars[*i] == ':')
+    {
+        *i += 1;
+    }
+    chars[start..*i].iter().collect()
+}
+
+/// Evaluate a formula of a rule. Returns None if the formula is not supported.
+pub(crate) fn evaluate(context: &Context, formula: &str) -> Option<Value> {
+    let tokens = tokenize(formula)?;
+    let mut parser = Parser {
+        context,
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let result = parser.parse_comparison()?.into_value();
+    if parser.position != parser.tokens.len() {
+        return None;
+    }
+    Some(result)
+}
+
+/// Evaluate the source of a list. (a range or a single value)
+pub(crate) fn evaluate_list(context: &Context, formula: &str) -> Option<Vec<Value>> {
+    let tokens = tokenize(formula)?;
+    let mut parser = Parser {
+        context,
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let result = parser.parse_comparison()?;
+    if parser.position != parser.tokens.len() {
+        return None;
+    }
+    match result {
+        Operand::Value(v) => Some(vec![v]),
+        Operand::Range(v) => Some(v),
+    }
+}
+
+struct Parser<'a, 'b> {
+    context: &'a Context<'b>,
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a, 'b> Parser<'a, 'b> {
+    fn peek_operator(&self, operators: &[&str]) -> Option<String> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(v)) if operators.contains(&v.as_str()) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Option<Operand> {
+        let mut left = self.parse_concat()?;
+        while let Some(operator) = self.peek_operator(&["=", "<>", "<", ">", "<=", ">="]) {
+            self.position += 1;
+            let right = self.parse_concat()?.into_value();
+            let left_value = left.into_value();
+            if left_value == Value::Error || right == Value::Error {
+                left = Operand::Value(Value::Error);
+                continue;
+            }
+            let ordering = compare(&left_value, &right);
+            let result = match operator.as_str() {
+                "=" => ordering == Ordering::Equal,
+                "<>" => ordering != Ordering::Equal,
+                "<" => ordering == Ordering::Less,
+                ">" => ordering == Ordering::Greater,
+                "<=" => ordering != Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            };
+            left = Operand::Value(Value::Bool(result));
+        }
+        Some(left)
+    }
+
+    fn parse_concat(&mut self) -> Option<Operand> {
+        let mut left = self.parse_additive()?;
+        while self.peek_operator(&["&"]).is_some() {
+            self.position += 1;
+            let right = self.parse_additive()?.into_value();
+            let left_value = left.into_value();
+            left = Operand::Value(match (&left_value, &right) {
+                (Value::Error, _) | (_, Value::Error) => Value::Error,
+                _ => Value::Text(format!("{}{}", to_text(&left_value), to_text(&right))),
+            });
+        }
+        Some(left)
+    }
+
+    fn parse_additive(&mut self) -> Option<Operand> {
+        let mut left = self.parse_multiplicative()?;
+        while let Some(operator) = self.peek_operator(&["+", "-"]) {
+            self.position += 1;
+            let right = self.parse_multiplicative()?.into_value();
+            left = Operand::Value(calculate(&left.into_value(), &right, |a, b| {
+                if operator == "+" {
+                    Some(a + b)
+                } else {
+                    Some(a - b)
+                }
+            }));
+        }
+        Some(left)
+    }
+
+    fn parse_multiplicative(&mut self) -> Option<Operand> {
+        let mut left = self.parse_power()?;
+        while let Some(operator) = self.peek_operator(&["*", "/"]) {
+            self.position += 1;
+            let right = self.parse_power()?.into_value();
+            left = Operand::Value(calculate(&left.into_value(), &right, |a, b| {
+                if operator == "*" {
+                    Some(a * b)
+                } else if b == 0f64 {
+                    None
+                } else {
+                    Some(a / b)
+                }
+            }));
+        }
+        Some(left)
+    }
+
+    fn parse_power(&mut self) -> Option<Operand> {
+        let mut left = self.parse_unary()?;
+        while self.peek_operator(&["^"]).is_some() {
+            self.position += 1;
+            let right = self.parse_unary()?.into_value();
+            left = Operand::Value(calculate(&left.into_value(), &right, |a, b| {
+                Some(a.powf(b))
+            }));
+        }
+        Some(left)
+    }
+
+    fn parse_unary(&mut self) -> Option<Operand> {
+        if let Some(operator) = self.peek_operator(&["-", "+"]) {
+            self.position += 1;
+            let value = self.parse_unary()?.into_value();
+            if operator == "+" {
+                return Some(Operand::Value(value));
+            }
+            return Some(Operand::Value(calculate(
+                &Value::Number(0f64),
+                &value,
+                |a, b| Some(a - b),
+            )));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Option<Operand> {
+        let token = self.tokens.get(self.position)?.clone();
+        self.position += 1;
+        match token {
+            Token::Number(v) => Some(Operand::Value(Value::Number(v))),
+            Token::Text(v) => Some(Operand::Value(Value::Text(v))),
+            Token::Open => {
+                let result = self.parse_comparison()?;
+                match self.tokens.get(self.position) {
+                    Some(Token::Close) => {
+                        self.position += 1;
+                        Some(result)
+                    }
+                    _ => None,
+                }
+            }
+            Token::Reference(sheet_name, v) => self.get_reference(&sheet_name, &v),
+            Token::Function(name) => {
+                // (
+                self.position += 1;
+                let mut arguments = Vec::new();
+                if self.tokens.get(self.position) == Some(&Token::Close) {
+                    self.position += 1;
+                } else {
+                    loop {
+                        arguments.push(self.parse_comparison()?);
+                        match self.tokens.get(self.position) {
+                            Some(Token::Comma) => self.position += 1,
+                            Some(Token::Close) => {
+                                self.position += 1;
+                                break;
+                            }
+                            _ => return None,
+                        }
+                    }
+                }
+                self.call(&name, arguments).map(Operand::Value)
+            }
+            _ => None,
+        }
+    }
+
+    fn get_reference(&self, sheet_name: &Option<String>, reference: &str) -> Option<Operand> {
+        let worksheet = match sheet_name {
+            Some(v) => self.context.get_worksheet(v)?,
+            None => {
+                match reference {
+                    "TRUE" => return Some(Operand::Value(Value::Bool(true))),
+                    "FALSE" => return Some(Operand::Value(Value::Bool(false))),
+                    _ => {}
+                }
+                if let Some(operand) = self.get_defined_name(reference) {
+                    return Some(operand);
+                }
+                self.context.worksheet
+            }
+        };
+        self.get_area(worksheet, reference, false)
+    }
+
+    /// Values of a cell or a range of the worksheet. ex) `A1`, `$A$1:$B3`, `$A:$A`, `1:3`
+    /// A whole column or row ends at the used area of the worksheet.
+    fn get_area(
+        &self,
+        worksheet: &Worksheet,
+        reference: &str,
+        is_absolute: bool,
+    ) -> Option<Operand> {
+        let split: Vec<&str> = reference.split(':').collect();
+        match split.len() {
+            1 => match self.context.get_position(split[0], is_absolute)? {
+                (Some(col), Some(row)) => Some(Operand::Value(get_cell_value(worksheet, col, row))),
+                _ => None,
+            },
+            2 => {
+                let start = self.context.get_position(split[0], is_absolute)?;
+                let end = self.context.get_position(split[1], is_absolute)?;
+                let (start, end) = match (start, end) {
+                    ((Some(col1), Some(row1)), (Some(col2), Some(row2))) => {
+                        ((col1, row1), (col2, row2))
+                    }
+                    ((Some(col1), None), (Some(col2), None)) => {
+                        let (_, highest_row) = worksheet.get_highest_column_and_row();
+                        ((col1, 1), (col2, highest_row.max(1)))
+                    }
+                    ((None, Some(row1)), (None, Some(row2))) => {
+                        let (highest_col, _) = worksheet.get_highest_column_and_row();
+                        ((1, row1), (highest_col.max(1), row2))
+                    }
+                    _ => return None,
+                };
+                Some(get_range_values(worksheet, start, end))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve a defined name as a formula of the worksheet does.
+    /// Without the workbook, only the names of the worksheet are found.
+    /// The address of a name is absolute, and the other bodies are evaluated as formulas.
+    fn get_defined_name(&self, name: &str) -> Option<Operand> {
+        let worksheet = self.context.worksheet;
+        let defined_name = match self.context.spreadsheet {
+            Some(spreadsheet) => {
+                let sheet_index = spreadsheet
+                    .get_sheet_collection_no_check()
+                    .iter()
+                    .position(|v| std::ptr::eq(v, worksheet));
+                spreadsheet.find_defined_name(name, sheet_index)
+            }
+            None => worksheet
+                .get_defined_names()
+                .iter()
+                .find(|v| v.is_name(name)),
+        }?;
+        if defined_name.is_address() {
+            let address = defined_name.get_address_obj();
+            let worksheet = match address.get_sheet_name() {
+                "" => self.context.worksheet,
+                v => self.context.get_worksheet(v)?,
+            };
+            return self.get_area(worksheet, &address.get_range().get_range(), true);
+        }
+        if self.depth >= MAX_NAME_DEPTH {
+            return None;
+        }
+        let mut parser = Parser {
+            context: self.context,
+            tokens: tokenize(&defined_name.get_address_str())?,
+            position: 0,
+            depth: self.depth + 1,
+        };
+        let result = parser.parse_comparison()?;
+        if parser.position != parser.tokens.len() {
+            return None;
+        }
+        Some(result)
+    }
+
+    /// Functions used in the formulas of rules.
+    fn call(&self, name: &str, arguments: Vec<Operand>) -> Option<Value> {
+        let mut values = Vec::new();
+        let mut ranges = Vec::new();
+        for argument in arguments {
+            match argument {
+                Operand::Value(v) => {
+                    ranges.push(vec![v.clone()]);
+                    values.push(v);
+                }
+                Operand::Range(v) => {
+                    values.push(Value::Error);
+                    ranges.push(v);
+                }
+            }
+        }
+        let number = |i: usize| values.get(i).and_then(to_number);
+        let text = |i: usize| values.get(i).map(to_text);
+        let result = match (name, values.len()) {
+            ("AND", n) if n > 0 => Value::Bool(
+                ranges
+                    .iter()
+                    .flatten()
+                    .all(|v| is_true(v) || v == &Value::Empty),
+            ),
+            ("OR", n) if n > 0 => Value::Bool(ranges.iter().flatten().any(is_true)),
+            ("NOT", 1) => Value::Bool(!is_true(&values[0])),
+            ("IF", 2) | ("IF", 3) => {
+                if is_true(&values[0]) {
+                    values[1].clone()
+                } else {
+                    values.get(2).cloned().unwrap_or(Value::Bool(false))
+                }
+            }
+            ("ISBLANK", 1) => Value::Bool(values[0] == Value::Empty),
+            ("ISNUMBER", 1) => Value::Bool(matches!(values[0], Value::Number(_))),
+            ("ISTEXT", 1) => Value::Bool(matches!(values[0], Value::Text(_))),
+            ("ISERROR", 1) => Value::Bool(values[0] == Value::Error),
+            ("ABS", 1) => Value::Number(number(0)?.abs()),
+            ("MOD", 2) => {
+                let (a, b) = (number(0)?, number(1)?);
+                if b == 0f64 {
+                    Value::Error
+                } else {
+                    Value::Number(a - b * (a / b).floor())
+                }
+            }
+            ("ROW", 0) => Value::Number(self.context.row as f64),
+            ("COLUMN", 0) => Value::Number(self.context.col as f64),
+            ("LEN", 1) => Value::Number(text(0)?.chars().count() as f64),
+            ("LOWER", 1) => Value::Text(text(0)?.to_lowercase()),
+            ("UPPER", 1) => Value::Text(text(0)?.to_uppercase()),
+            ("TRIM", 1) => Value::Text(text(0)?.split_whitespace().collect::<Vec<_>>().join(" ")),
+            ("LEFT", 1) | ("LEFT", 2) => {
+                let count = number(1).unwrap_or(1f64) as usize;
+                Value::Text(text(0)?.chars().take(count).collect())
+            }
+            ("RIGHT", 1) | ("RIGHT", 2) => {
+                let count = number(1).unwrap_or(1f64) as usize;
+                let chars: Vec<char> = text(0)?.chars().collect();
+                Value::Text(chars[chars.len().saturating_sub(count)..].iter().collect())
+            }
+            ("SEARCH", 2) | ("FIND", 2) => {
+                let (find, within) = if name == "SEARCH" {
+                    (text(0)?.to_lowercase(), text(1)?.to_lowercase())
+                } else {
+                    (text(0)?, text(1)?)
+                };
+                match within.find(&find) {
+                    Some(v) => Value::Number((within[..v].chars().count() + 1) as f64),
+                    None => Value::Error,
+                }
+            }
+            ("DATE", 3) => {
+                let date = NaiveDate::from_ymd_opt(number(0)? as i32, 1, 1)?.checked_add_months(
+                    chrono::Months::new((number(1)? as i64 - 1).max(0) as u32),
+                )? + chrono::Duration::days(number(2)? as i64 - 1);
+                Value::Number(get_serial_date(date, *self.context.worksheet.get_date1904()) as f64)
+            }
+            ("TIME", 3) => Value::Number(
+                ((number(0)? * 3600f64 + number(1)? * 60f64 + number(2)?) / 86400f64).fract(),
+            ),
+            ("TODAY", 0) => Value::Number(get_serial_date(
+                Local::now().date_naive(),
+                *self.context.worksheet.get_date1904(),
+            ) as f64),
+            ("SUM", _) => Value::Number(ranges.iter().flatten().filter_map(as_number).sum()),
+            ("COUNT", _) => {
+                Value::Number(ranges.iter().flatten().filter_map(as_number).count() as f64)
+            }
+            ("AVERAGE", _) | ("MIN", _) | ("MAX", _) => {
+                let numbers: Vec<f64> = ranges.iter().flatten().filter_map(as_number).collect();
+                if numbers.is_empty() {
+                    return Some(if name == "AVERAGE" {
+                        Value::Error
+                    } else {
+                        Value::Number(0f64)
+                    });
+                }
+                Value::Number(match name {
+                    "AVERAGE" => numbers.iter().sum::<f64>() / numbers.len() as f64,
+                    "MIN" => numbers.iter().cloned().fold(f64::INFINITY, f64::min),
+                    _ => numbers.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+                })
+            }
+            ("COUNTIF", 2) => {
+                let criteria = ranges[1].first().cloned().unwrap_or(Value::Empty);
+                Value::Number(
+                    ranges[0]
+                        .iter()
+                        .filter(|v| is_match_criteria(v, &criteria))
+                        .count() as f64,
+                )
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+}
+
+fn get_range_values(worksheet: &Worksheet, start: (u32, u32), end: (u32, u32)) -> Operand {
+    let range = (
+        start.0.min(end.0),
+        start.1.min(end.1),
+        start.0.max(end.0),
+        start.1.max(end.1),
+    );
+    let mut values = Vec::new();
+    for row in range.1..=range.3 {
+        for col in range.0..=range.2 {
+            values.push(get_cell_value(worksheet, col, row));
+        }
+    }
+    Operand::Range(values)
+}
+
+fn is_match_criteria(value: &Value, criteria: &Value) -> bool {
+    let text = match criteria {
+        Value::Text(v) => v.clone(),
+        _ => return value != &Value::Empty && compare(value, criteria) == Ordering::Equal,
+    };
+    let (operator, operand) = ["<=", ">=", "<>", "<", ">", "="]
+        .iter()
+        .find(|v| text.starts_with(**v))
+        .map(|v| (*v, &text[v.len()..]))
+        .unwrap_or(("=", text.as_str()));
+    let operand = match operand.parse::<f64>() {
+        Ok(v) => Value::Number(v),
+        Err(_) => Value::Text(operand.to_string()),
+    };
+    if operand == Value::Text(String::new()) {
+        return match operator {
+            "<>" => value != &Value::Empty,
+            _ => value == &Value::Empty,
+        };
+    }
+    if value == &Value::Empty || get_type_rank(value) != get_type_rank(&operand) {
+        return operator == "<>";
+    }
+    let ordering = compare(value, &operand);
+    match operator {
+        "<=" => ordering != Ordering::Greater,
+        ">=" => ordering != Ordering::Less,
+        "<>" => ordering != Ordering::Equal,
+        "<" => ordering == Ordering::Less,
+        ">" => ordering == Ordering::Greater,
+        _ => ordering == Ordering::Equal,
+    }
+}
+
+fn calculate<F: Fn(f64, f64) -> Option<f64>>(left: &Value, right: &Value, function: F) -> Value {
+    match (to_number(left), to_number(right)) {
+        (Some(a), Some(b)) => match function(a, b) {
+            Some(v) if v.is_finite() => Value::Number(v),
+            _ => Value::Error,
+        },
+        _ => Value::Error,
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(v) => Some(*v),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(v) => Some(*v),
+        Value::Bool(v) => Some(if *v { 1f64 } else { 0f64 }),
+        Value::Empty => Some(0f64),
+        Value::Text(v) => v.trim().parse::<f64>().ok(),
+        Value::Error => None,
+    }
+}
+
+pub(crate) fn to_text(value: &Value) -> String {
+    match value {
+        Value::Number(v) => v.to_string(),
+        Value::Text(v) => v.clone(),
+        Value::Bool(v) => if *v { "TRUE" } else { "FALSE" }.to_string(),
+        Value::Empty | Value::Error => String::new(),
+    }
+}
+
+pub(crate) fn is_true(value: &Value) -> bool {
+    match value {
+        Value::Bool(v) => *v,
+        Value::Number(v) => *v != 0f64,
+        _ => false,
+    }
+}
+
+/// Numbers are less than texts and texts are less than booleans.
+pub(crate) fn get_type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Number(_) => 0,
+        Value::Text(_) => 1,
+        Value::Bool(_) => 2,
+        Value::Empty => 3,
+        Value::Error => 4,
+    }
+}
+
+pub(crate) fn compare(left: &Value, right: &Value) -> Ordering {
+    // an empty cell is the zero value of the other side.
+    let empty_as = |other: &Value| match other {
+        Value::Number(_) => Value::Number(0f64),
+        Value::Bool(_) => Value::Bool(false),
+        _ => Value::Text(String::new()),
+    };
+    let left = if left == &Value::Empty {
+        empty_as(right)
+    } else {
+        left.clone()
+    };
+    let right = if right == &Value::Empty {
+        empty_as(&left)
+    } else {
+        right.clone()
+    };
+    match (&left, &right) {
+        (Value::Number(a), Value::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (Value::Text(a), Value::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => get_type_rank(&left).cmp(&get_type_rank(&right)),
+    }
+}
+
+/// Days from 1899-12-30, or from 1904-01-01 in the 1904 date system. (the serial number of the date)
+pub(crate) fn get_serial_date(date: NaiveDate, date1904: bool) -> i64 {
+    let base_date = if date1904 {
+        NaiveDate::from_ymd_opt(1904, 1, 1).unwrap()
+    } else {
+        NaiveDate::from_ymd_opt(1899, 12, 30).unwrap()
+    };
+    (date - base_date).num_days()
+}
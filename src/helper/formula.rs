@@ -135,3 +135,322 @@ pub fn adjustment_remove_formula_coordinate(
 
     result.into()
 }
+
+/// Functions written with the `_xlfn.` prefix in the file. `(name, prefix)`
+const FUTURE_FUNCTIONS: &[(&str, &str)] = &[
+    ("AGGREGATE", "_xlfn."),
+    ("ANCHORARRAY", "_xlfn."),
+    ("BYCOL", "_xlfn."),
+    ("BYROW", "_xlfn."),
+    ("CEILING.MATH", "_xlfn."),
+    ("CHOOSECOLS", "_xlfn."),
+    ("CHOOSEROWS", "_xlfn."),
+    ("CONCAT", "_xlfn."),
+    ("DAYS", "_xlfn."),
+    ("DROP", "_xlfn."),
+    ("EXPAND", "_xlfn."),
+    ("FILTER", "_xlfn._xlws."),
+    ("FLOOR.MATH", "_xlfn."),
+    ("FORECAST.LINEAR", "_xlfn."),
+    ("HSTACK", "_xlfn."),
+    ("IFNA", "_xlfn."),
+    ("IFS", "_xlfn."),
+    ("ISOMITTED", "_xlfn."),
+    ("ISOWEEKNUM", "_xlfn."),
+    ("LAMBDA", "_xlfn."),
+    ("LET", "_xlfn."),
+    ("MAKEARRAY", "_xlfn."),
+    ("MAP", "_xlfn."),
+    ("MAXIFS", "_xlfn."),
+    ("MINIFS", "_xlfn."),
+    ("MODE.SNGL", "_xlfn."),
+    ("NORM.DIST", "_xlfn."),
+    ("NORM.INV", "_xlfn."),
+    ("NORM.S.DIST", "_xlfn."),
+    ("NORM.S.INV", "_xlfn."),
+    ("PERCENTILE.EXC", "_xlfn."),
+    ("PERCENTILE.INC", "_xlfn."),
+    ("QUARTILE.EXC", "_xlfn."),
+    ("QUARTILE.INC", "_xlfn."),
+    ("RANDARRAY", "_xlfn."),
+    ("RANK.AVG", "_xlfn."),
+    ("RANK.EQ", "_xlfn."),
+    ("REDUCE", "_xlfn."),
+    ("SCAN", "_xlfn."),
+    ("SEQUENCE", "_xlfn."),
+    ("SINGLE", "_xlfn."),
+    ("SORT", "_xlfn._xlws."),
+    ("SORTBY", "_xlfn."),
+    ("STDEV.P", "_xlfn."),
+    ("STDEV.S", "_xlfn."),
+    ("SWITCH", "_xlfn."),
+    ("TAKE", "_xlfn."),
+    ("TEXTAFTER", "_xlfn."),
+    ("TEXTBEFORE", "_xlfn."),
+    ("TEXTJOIN", "_xlfn."),
+    ("TEXTSPLIT", "_xlfn."),
+    ("TOCOL", "_xlfn."),
+    ("TOROW", "_xlfn."),
+    ("UNIQUE", "_xlfn."),
+    ("VAR.P", "_xlfn."),
+    ("VAR.S", "_xlfn."),
+    ("VSTACK", "_xlfn."),
+    ("WRAPCOLS", "_xlfn."),
+    ("WRAPROWS", "_xlfn."),
+    ("XLOOKUP", "_xlfn."),
+    ("XMATCH", "_xlfn."),
+];
+
+/// Apply `f` to the parts of the formula outside of the string literals and quoted sheet names.
+fn replace_outside_quotes<F: FnMut(&str) -> String>(formula: &str, mut f: F) -> String {
+    let mut result = String::new();
+    let mut part = String::new();
+    let mut quote: Option<char> = None;
+    for c in formula.chars() {
+        match quote {
+            Some(q) => {
+                result.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '"' || c == '\'' => {
+                result.push_str(&f(&part));
+                part.clear();
+                result.push(c);
+                quote = Some(c);
+            }
+            None => part.push(c),
+        }
+    }
+    result.push_str(&f(&part));
+    result
+}
+
+/// Apply `f` to the names of the functions in the formula.
+fn replace_function_names<F: Fn(&str) -> Option<String>>(formula: &str, f: F) -> String {
+    replace_outside_quotes(formula, |part| {
+        let chars: Vec<char> = part.chars().collect();
+        let mut result = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let is_start = (c.is_ascii_alphabetic() || c == '_')
+                && (i == 0 || !(chars[i - 1].is_ascii_alphanumeric() || chars[i - 1] == '_'));
+            if !is_start {
+                result.push(c);
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            let name: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'(') {
+                if let Some(v) = f(&name) {
+                    result.push_str(&v);
+                    continue;
+                }
+            }
+            result.push_str(&name);
+        }
+        result
+    })
+}
+
+/// (This method is crate only.)
+/// Add the `_xlfn.` prefix to the functions added after Excel 2007. ex) `FILTER(` to `_xlfn._xlws.FILTER(`
+pub(crate) fn add_future_function_prefix(formula: &str) -> String {
+    replace_function_names(formula, |name| {
+        let upper = name.to_uppercase();
+        FUTURE_FUNCTIONS
+            .iter()
+            .find(|(v, _)| *v == upper)
+            .map(|(_, prefix)| format!("{}{}", prefix, name))
+    })
+}
+
+/// (This method is crate only.)
+/// Remove the `_xlfn.` prefix of the functions known by `add_future_function_prefix`.
+pub(crate) fn remove_future_function_prefix(formula: &str) -> String {
+    if !formula.contains("_xl") {
+        return formula.to_string();
+    }
+    replace_function_names(formula, |name| {
+        let upper = name.to_uppercase();
+        FUTURE_FUNCTIONS.iter().find_map(|(v, prefix)| {
+            if upper == format!("{}{}", prefix.to_uppercase(), v) {
+                Some(name[prefix.len()..].to_string())
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// (This method is crate only.)
+/// Split the arguments of a function call at the top level commas. ex) `"a,b",C(1,2)` to `["\"a,b\"", "C(1,2)"]`
+pub(crate) fn split_function_arguments(arguments: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut part = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+    for c in arguments.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '{' => depth += 1,
+                ')' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    result.push(part.trim().to_string());
+                    part.clear();
+                    continue;
+                }
+                _ => {}
+            },
+        }
+        part.push(c);
+    }
+    result.push(part.trim().to_string());
+    result
+}
+
+/// (This method is crate only.)
+/// Get the value of a text literal. ex) `"say ""hi"""` to `say "hi"`
+pub(crate) fn get_string_literal(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+    if inner.replace("\"\"", "").contains('"') {
+        return None;
+    }
+    Some(inner.replace("\"\"", "\""))
+}
+
+/// (This method is crate only.)
+/// Renumber the references to the external links after the link of `index` is removed. ex) `[2]Sheet1!A1` to `[1]Sheet1!A1`
+/// Returns `None` when the formula refers to the removed link.
+pub(crate) fn remove_external_link_index(formula: &str, index: &u32) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\[([0-9]+)\]").unwrap();
+    }
+    let mut is_removed = false;
+    // the odd parts are the string literals.
+    let parts: Vec<String> = formula
+        .split('"')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                return part.to_string();
+            }
+            RE.replace_all(part, |caps: &Captures| {
+                let number = caps[1].parse::<u32>().unwrap_or(0);
+                if &number == index {
+                    is_removed = true;
+                }
+                match &number > index {
+                    true => format!("[{}]", number - 1),
+                    false => caps[0].to_string(),
+                }
+            })
+            .to_string()
+        })
+        .collect();
+    match is_removed {
+        true => None,
+        false => Some(parts.join("\"")),
+    }
+}
+
+/// Move the relative references of the formula. The references out of the sheet become `#REF!`.
+/// Used to copy a formula to another cell, like the dependent cells of a shared formula.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::formula::*;
+/// assert_eq!(translate_formula("SUM(A1:B2)*$C$1+'Sheet 2'!A1", 1, 2), "SUM(B3:C4)*$C$1+'Sheet 2'!B3");
+/// ```
+pub fn translate_formula(formula: &str, col_offset: i32, row_offset: i32) -> String {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"(?<![A-Za-z0-9_.$])(\$?)([A-Za-z]{1,3})(\$?)([0-9]+)(?![A-Za-z0-9_(.])")
+                .unwrap();
+    }
+    if col_offset == 0 && row_offset == 0 {
+        return formula.to_string();
+    }
+    replace_outside_quotes(formula, |part| {
+        RE.replace_all(part, |caps: &Captures| {
+            let is_lock_col = !caps[1].is_empty();
+            let is_lock_row = !caps[3].is_empty();
+            let mut col = column_index_from_string(caps[2].to_uppercase()) as i64;
+            let mut row = caps[4].parse::<i64>().unwrap_or(0);
+            if !is_lock_col {
+                col += col_offset as i64;
+            }
+            if !is_lock_row {
+                row += row_offset as i64;
+            }
+            if !(1..=16384).contains(&col) || !(1..=1048576).contains(&row) {
+                return "#REF!".to_string();
+            }
+            coordinate_from_index_with_lock(
+                &(col as u32),
+                &(row as u32),
+                &is_lock_col,
+                &is_lock_row,
+            )
+        })
+        .to_string()
+    })
+}
+
+#[test]
+fn test_future_function_prefix() {
+    let formula = r#"FILTER(A1:A10,A1:A10>0,"SORT(")+SUM(UNIQUE(B1:B3))"#;
+    let written = add_future_function_prefix(formula);
+    assert_eq!(
+        written,
+        r#"_xlfn._xlws.FILTER(A1:A10,A1:A10>0,"SORT(")+SUM(_xlfn.UNIQUE(B1:B3))"#
+    );
+    assert_eq!(remove_future_function_prefix(&written), formula);
+    assert_eq!(
+        remove_future_function_prefix("_xlfn.BETA.DIST(1,2,3,TRUE)"),
+        "_xlfn.BETA.DIST(1,2,3,TRUE)"
+    );
+}
+
+#[test]
+fn test_translate_formula() {
+    assert_eq!(translate_formula("A1*2", 0, 1), "A2*2");
+    assert_eq!(translate_formula("A$1+$B2", 1, 1), "B$1+$B3");
+    assert_eq!(
+        translate_formula("LOG10(A1)&\"A1\"", 0, 2),
+        "LOG10(A3)&\"A1\""
+    );
+    assert_eq!(translate_formula("A1", 0, -1), "#REF!");
+}
+
+#[test]
+fn test_split_function_arguments() {
+    assert_eq!(
+        split_function_arguments(r#""a,b", SUM(1,2)"#),
+        vec![r#""a,b""#, "SUM(1,2)"]
+    );
+    assert_eq!(
+        get_string_literal(r#""say ""hi""""#),
+        Some(r#"say "hi""#.into())
+    );
+    assert_eq!(get_string_literal("A1"), None);
+}
+
+#[test]
+fn test_remove_external_link_index() {
+    assert_eq!(
+        remove_external_link_index(r#"[3]Sheet1!A1+'[2]My Sheet'!B2&"[2]""#, &1),
+        Some(r#"[2]Sheet1!A1+'[1]My Sheet'!B2&"[2]""#.into())
+    );
+    assert_eq!(remove_external_link_index("SUM([1]Sheet1!A1:A3)", &1), None);
+}
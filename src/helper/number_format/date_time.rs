@@ -0,0 +1,183 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+use super::format_code::{Section, Token};
+use super::locale::{get_japanese_era, to_db_num, NumberFormatLocale};
+
+const OUT_OF_RANGE: &str = "########";
+
+/// `(year, month, day, day of the week)` of the serial date. The 1900 date system keeps the 1900-02-29 of Excel.
+fn get_date(days: i64, date1904: bool) -> Option<(i32, u32, u32, u32)> {
+    if date1904 {
+        let date = NaiveDate::from_ymd_opt(1904, 1, 1)?.checked_add_signed(Duration::days(days))?;
+        return Some((
+            date.year(),
+            date.month(),
+            date.day(),
+            ((days + 5) % 7) as u32,
+        ));
+    }
+    let weekday = ((days + 6) % 7) as u32;
+    match days {
+        0 => Some((1900, 1, 0, weekday)),
+        60 => Some((1900, 2, 29, weekday)),
+        _ => {
+            let base = if days < 60 {
+                NaiveDate::from_ymd_opt(1899, 12, 31)?
+            } else {
+                NaiveDate::from_ymd_opt(1899, 12, 30)?
+            };
+            let date = base.checked_add_signed(Duration::days(days))?;
+            Some((date.year(), date.month(), date.day(), weekday))
+        }
+    }
+}
+
+/// Write the serial date with the section of the date format.
+pub(crate) fn format_date_time(
+    section: &Section,
+    value: f64,
+    locale: &NumberFormatLocale,
+    date1904: bool,
+) -> String {
+    if value < 0f64 || !value.is_finite() {
+        return OUT_OF_RANGE.into();
+    }
+    let precision = section
+        .tokens
+        .iter()
+        .filter_map(|v| match v {
+            Token::SubSecond(n) => Some(*n),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0) as u32;
+    let scale = 10i64.pow(precision);
+    let units_per_day = 86400 * scale;
+    let total = (value * units_per_day as f64).round() as i64;
+    let days = total / units_per_day;
+    let total_seconds = total / scale;
+    let sub_second = total % scale;
+    let second_of_day = total_seconds % 86400;
+    let (hour, minute, second) = (
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60,
+    );
+
+    let (year, month, day, weekday) = match get_date(days, date1904) {
+        Some(v) if v.0 <= 9999 => v,
+        _ => return OUT_OF_RANGE.into(),
+    };
+    let names = section
+        .lcid
+        .and_then(NumberFormatLocale::from_lcid)
+        .unwrap_or_else(|| locale.clone());
+    let has_am_pm = section
+        .tokens
+        .iter()
+        .any(|v| matches!(v, Token::AmPm(_, _)));
+    let db_num = |value: String, positional: bool| -> String {
+        match section.db_num {
+            Some(n) => to_db_num(&value, n, names.is_chinese(), positional),
+            None => value,
+        }
+    };
+    let pad = |value: i64, length: usize| -> String {
+        if length >= 2 {
+            format!("{:02}", value)
+        } else {
+            value.to_string()
+        }
+    };
+
+    let mut result = String::new();
+    for token in &section.tokens {
+        match token {
+            Token::Year(n) if *n <= 2 => {
+                result.push_str(&db_num(format!("{:02}", year % 100), false))
+            }
+            Token::Year(_) => result.push_str(&db_num(format!("{:04}", year), false)),
+            Token::Month(n) => match n {
+                1 | 2 => result.push_str(&db_num(pad(month as i64, *n), true)),
+                3 => result.push_str(names.get_month_name(month, true)),
+                4 => result.push_str(names.get_month_name(month, false)),
+                _ => result.extend(names.get_month_name(month, false).chars().take(1)),
+            },
+            Token::Day(n) => match n {
+                1 | 2 => result.push_str(&db_num(pad(day as i64, *n), true)),
+                3 => result.push_str(names.get_day_name(weekday, true)),
+                _ => result.push_str(names.get_day_name(weekday, false)),
+            },
+            Token::Hour(n) => {
+                let hour = if has_am_pm {
+                    match hour % 12 {
+                        0 => 12,
+                        v => v,
+                    }
+                } else {
+                    hour
+                };
+                result.push_str(&db_num(pad(hour, *n), true));
+            }
+            Token::Minute(n) => result.push_str(&db_num(pad(minute, *n), true)),
+            Token::Second(n) => result.push_str(&db_num(pad(second, *n), true)),
+            Token::SubSecond(n) => {
+                let digits = format!("{:0width$}", sub_second, width = precision as usize);
+                result.push(*locale.get_decimal_separator());
+                result.extend(digits.chars().take(*n));
+            }
+            Token::ElapsedHour(n) => {
+                result.push_str(&format!("{:0width$}", total_seconds / 3600, width = n))
+            }
+            Token::ElapsedMinute(n) => {
+                result.push_str(&format!("{:0width$}", total_seconds / 60, width = n))
+            }
+            Token::ElapsedSecond(n) => {
+                result.push_str(&format!("{:0width$}", total_seconds, width = n))
+            }
+            Token::AmPm(am, pm) => {
+                let is_am = hour < 12;
+                match names.get_am_pm() {
+                    Some((l_am, l_pm)) if am.len() == 2 => {
+                        result.push_str(if is_am { l_am } else { l_pm })
+                    }
+                    _ => result.push_str(if is_am { am } else { pm }),
+                }
+            }
+            Token::Era(n) => {
+                if names.is_japanese() {
+                    if let Some((era, _)) = get_japanese_era(year, month, day, *n) {
+                        result.push_str(era);
+                    }
+                }
+            }
+            Token::EraYear(n) => {
+                let era_year = if names.is_japanese() {
+                    get_japanese_era(year, month, day, 3).map(|v| v.1)
+                } else {
+                    None
+                };
+                match era_year {
+                    Some(v) => result.push_str(&db_num(pad(v as i64, *n), true)),
+                    None => result.push_str(&db_num(year.to_string(), false)),
+                }
+            }
+            Token::BuddhistYear(n) => {
+                let year = year + 543;
+                if *n <= 2 {
+                    result.push_str(&db_num(format!("{:02}", year % 100), false));
+                } else {
+                    result.push_str(&db_num(year.to_string(), false));
+                }
+            }
+            Token::Literal(v) => result.push_str(v),
+            Token::Fill(v) => result.push(*v),
+            Token::DecimalPoint => result.push(*locale.get_decimal_separator()),
+            Token::Thousands => result.push(','),
+            Token::Percent => result.push('%'),
+            Token::Digit('0') => result.push('0'),
+            _ => {}
+        }
+    }
+    result
+}
@@ -0,0 +1,447 @@
+use structs::Color;
+
+const NAMED_COLORS: [(&str, &str); 8] = [
+    ("black", "FF000000"),
+    ("white", "FFFFFFFF"),
+    ("red", "FFFF0000"),
+    ("green", "FF00FF00"),
+    ("blue", "FF0000FF"),
+    ("yellow", "FFFFFF00"),
+    ("magenta", "FFFF00FF"),
+    ("cyan", "FF00FFFF"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Literal(String),
+    /// `*x`. The character is written once.
+    Fill(char),
+    /// `0`, `#` or `?`.
+    Digit(char),
+    DecimalPoint,
+    Thousands,
+    Percent,
+    /// `E+` or `E-`. (letter, show plus sign)
+    Exponent(char, bool),
+    Slash,
+    /// Fixed denominator of a fraction. ex) `# ?/16`
+    Denominator(u32),
+    Text,
+    General,
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize),
+    Minute(usize),
+    Second(usize),
+    SubSecond(usize),
+    ElapsedHour(usize),
+    ElapsedMinute(usize),
+    ElapsedSecond(usize),
+    /// (am, pm) as written in the format.
+    AmPm(String, String),
+    Era(usize),
+    EraYear(usize),
+    BuddhistYear(usize),
+}
+impl Token {
+    fn is_date_time(&self) -> bool {
+        matches!(
+            self,
+            Token::Year(_)
+                | Token::Month(_)
+                | Token::Day(_)
+                | Token::Hour(_)
+                | Token::Minute(_)
+                | Token::Second(_)
+                | Token::SubSecond(_)
+                | Token::ElapsedHour(_)
+                | Token::ElapsedMinute(_)
+                | Token::ElapsedSecond(_)
+                | Token::AmPm(_, _)
+                | Token::Era(_)
+                | Token::EraYear(_)
+                | Token::BuddhistYear(_)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Operator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Condition {
+    operator: Operator,
+    value: f64,
+}
+impl Condition {
+    fn parse(value: &str) -> Option<Self> {
+        let (operator, rest) = if let Some(v) = value.strip_prefix("<=") {
+            (Operator::LessThanOrEqual, v)
+        } else if let Some(v) = value.strip_prefix(">=") {
+            (Operator::GreaterThanOrEqual, v)
+        } else if let Some(v) = value.strip_prefix("<>") {
+            (Operator::NotEqual, v)
+        } else if let Some(v) = value.strip_prefix('<') {
+            (Operator::LessThan, v)
+        } else if let Some(v) = value.strip_prefix('>') {
+            (Operator::GreaterThan, v)
+        } else if let Some(v) = value.strip_prefix('=') {
+            (Operator::Equal, v)
+        } else {
+            return None;
+        };
+        let value = rest.trim().parse::<f64>().ok()?;
+        Some(Self { operator, value })
+    }
+
+    fn is_match(&self, value: f64) -> bool {
+        match self.operator {
+            Operator::Equal => value == self.value,
+            Operator::NotEqual => value != self.value,
+            Operator::LessThan => value < self.value,
+            Operator::LessThanOrEqual => value <= self.value,
+            Operator::GreaterThan => value > self.value,
+            Operator::GreaterThanOrEqual => value >= self.value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Section {
+    pub(crate) tokens: Vec<Token>,
+    pub(crate) color: Option<String>,
+    pub(crate) condition: Option<Condition>,
+    pub(crate) lcid: Option<u32>,
+    pub(crate) db_num: Option<u8>,
+}
+impl Section {
+    pub(crate) fn is_date_time(&self) -> bool {
+        self.tokens.iter().any(|v| v.is_date_time())
+    }
+
+    pub(crate) fn is_text(&self) -> bool {
+        self.tokens.iter().any(|v| v == &Token::Text)
+            && !self
+                .tokens
+                .iter()
+                .any(|v| v.is_date_time() || matches!(v, Token::Digit(_) | Token::General))
+    }
+
+    fn push_literal(&mut self, value: &str) {
+        if let Some(Token::Literal(v)) = self.tokens.last_mut() {
+            v.push_str(value);
+            return;
+        }
+        self.tokens.push(Token::Literal(value.to_string()));
+    }
+
+    /// `m` is a minute after an hour or before a second.
+    fn resolve_minutes(&mut self) {
+        let indexes: Vec<usize> = (0..self.tokens.len())
+            .filter(|i| self.tokens[*i].is_date_time())
+            .collect();
+        for (n, i) in indexes.iter().enumerate() {
+            let length = match self.tokens[*i] {
+                Token::Month(v) if v <= 2 => v,
+                _ => continue,
+            };
+            let after_hour = n > 0
+                && matches!(
+                    self.tokens[indexes[n - 1]],
+                    Token::Hour(_) | Token::ElapsedHour(_)
+                );
+            let before_second = indexes.get(n + 1).map_or(false, |v| {
+                matches!(self.tokens[*v], Token::Second(_) | Token::ElapsedSecond(_))
+            });
+            if after_hour || before_second {
+                self.tokens[*i] = Token::Minute(length);
+            }
+        }
+    }
+
+    /// `/` is a literal in the date format.
+    fn resolve_slashes(&mut self) {
+        if !self.is_date_time() {
+            return;
+        }
+        for token in self.tokens.iter_mut() {
+            match token {
+                Token::Slash => *token = Token::Literal("/".into()),
+                Token::Denominator(v) => *token = Token::Literal(format!("/{}", v)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parsed number format code.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct FormatCode {
+    sections: Vec<Section>,
+}
+impl FormatCode {
+    pub(crate) fn parse(format: &str) -> Self {
+        let chars: Vec<char> = format.chars().collect();
+        let mut sections: Vec<Section> = Vec::new();
+        let mut section = Section::default();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let count = chars[i..]
+                .iter()
+                .take_while(|v| v.eq_ignore_ascii_case(&c))
+                .count();
+            match c {
+                ';' => {
+                    sections.push(std::mem::take(&mut section));
+                }
+                '"' => {
+                    let text: String = chars[i + 1..].iter().take_while(|v| **v != '"').collect();
+                    i += text.chars().count() + 1;
+                    section.push_literal(&text);
+                }
+                '\\' => {
+                    if let Some(v) = chars.get(i + 1) {
+                        section.push_literal(&v.to_string());
+                        i += 1;
+                    }
+                }
+                '_' => {
+                    section.push_literal(" ");
+                    i += 1;
+                }
+                '*' => {
+                    if let Some(v) = chars.get(i + 1) {
+                        section.tokens.push(Token::Fill(*v));
+                        i += 1;
+                    }
+                }
+                '[' => {
+                    let text: String = chars[i + 1..].iter().take_while(|v| **v != ']').collect();
+                    i += text.chars().count() + 1;
+                    Self::parse_bracket(&mut section, &text);
+                }
+                '0' | '#' | '?' => {
+                    section.tokens.push(Token::Digit(c));
+                }
+                '.' => {
+                    let zeros = chars[i + 1..].iter().take_while(|v| **v == '0').count();
+                    let after_second = section
+                        .tokens
+                        .iter()
+                        .rev()
+                        .find(|v| v.is_date_time())
+                        .map_or(false, |v| {
+                            matches!(v, Token::Second(_) | Token::ElapsedSecond(_))
+                        });
+                    if after_second && zeros > 0 {
+                        section.tokens.push(Token::SubSecond(zeros.min(3)));
+                        i += zeros;
+                    } else {
+                        section.tokens.push(Token::DecimalPoint);
+                    }
+                }
+                ',' => section.tokens.push(Token::Thousands),
+                '%' => section.tokens.push(Token::Percent),
+                '@' => section.tokens.push(Token::Text),
+                '/' => {
+                    let digits: String = chars[i + 1..]
+                        .iter()
+                        .take_while(|v| v.is_ascii_digit())
+                        .collect();
+                    match digits.parse::<u32>() {
+                        Ok(v) if v > 0 && !digits.starts_with('0') => {
+                            section.tokens.push(Token::Denominator(v));
+                            i += digits.len();
+                        }
+                        _ => section.tokens.push(Token::Slash),
+                    }
+                }
+                'E' | 'e' if matches!(chars.get(i + 1), Some('+') | Some('-')) => {
+                    section.tokens.push(Token::Exponent(c, chars[i + 1] == '+'));
+                    i += 1;
+                }
+                'G' | 'g' => {
+                    let word: String = chars[i..].iter().take(7).collect();
+                    if word.eq_ignore_ascii_case("general") {
+                        section.tokens.push(Token::General);
+                        i += 6;
+                    } else {
+                        section.tokens.push(Token::Era(count.min(3)));
+                        i += count - 1;
+                    }
+                }
+                'y' | 'Y' => {
+                    section.tokens.push(Token::Year(count));
+                    i += count - 1;
+                }
+                'm' | 'M' => {
+                    section.tokens.push(Token::Month(count.min(5)));
+                    i += count - 1;
+                }
+                'd' | 'D' => {
+                    section.tokens.push(Token::Day(count));
+                    i += count - 1;
+                }
+                'h' | 'H' => {
+                    section.tokens.push(Token::Hour(count));
+                    i += count - 1;
+                }
+                's' | 'S' => {
+                    section.tokens.push(Token::Second(count));
+                    i += count - 1;
+                }
+                'e' => {
+                    section.tokens.push(Token::EraYear(count));
+                    i += count - 1;
+                }
+                'b' | 'B' if count >= 2 => {
+                    section.tokens.push(Token::BuddhistYear(count));
+                    i += count - 1;
+                }
+                'a' | 'A' => {
+                    let word: String = chars[i..].iter().take(5).collect();
+                    let short: String = chars[i..].iter().take(3).collect();
+                    if word.eq_ignore_ascii_case("am/pm") {
+                        section
+                            .tokens
+                            .push(Token::AmPm(word[0..2].to_string(), word[3..5].to_string()));
+                        i += 4;
+                    } else if short.eq_ignore_ascii_case("a/p") {
+                        section.tokens.push(Token::AmPm(
+                            short[0..1].to_string(),
+                            short[2..3].to_string(),
+                        ));
+                        i += 2;
+                    } else if count >= 3 {
+                        // day of the week in the Japanese format. ex) aaa
+                        section.tokens.push(Token::Day(count));
+                        section.lcid.get_or_insert(0x0411);
+                        i += count - 1;
+                    } else {
+                        section.push_literal(&c.to_string());
+                    }
+                }
+                _ => section.push_literal(&c.to_string()),
+            }
+            i += 1;
+        }
+        sections.push(section);
+        for section in sections.iter_mut() {
+            section.resolve_minutes();
+            section.resolve_slashes();
+        }
+        Self { sections }
+    }
+
+    fn parse_bracket(section: &mut Section, text: &str) {
+        let lower = text.to_ascii_lowercase();
+        if let Some((_, argb)) = NAMED_COLORS.iter().find(|(name, _)| *name == lower) {
+            section.color = Some(argb.to_string());
+        } else if let Some(index) = lower
+            .strip_prefix("color")
+            .and_then(|v| v.parse::<u32>().ok())
+        {
+            if (1..=56).contains(&index) {
+                let mut color = Color::default();
+                color.set_indexed(index);
+                section.color = Some(color.get_argb().to_string());
+            }
+        } else if let Some(value) = text.strip_prefix('$') {
+            let (symbol, lcid) = match value.find('-') {
+                Some(pos) => (&value[..pos], Some(&value[pos + 1..])),
+                None => (value, None),
+            };
+            if !symbol.is_empty() {
+                section.push_literal(symbol);
+            }
+            if let Some(lcid) = lcid.and_then(|v| u32::from_str_radix(v, 16).ok()) {
+                section.lcid = Some(lcid & 0xFFFF);
+            }
+        } else if let Some(condition) = Condition::parse(text) {
+            section.condition = Some(condition);
+        } else if !lower.is_empty() && lower.chars().all(|v| v == lower.chars().next().unwrap()) {
+            let count = lower.chars().count();
+            match lower.chars().next().unwrap() {
+                'h' => section.tokens.push(Token::ElapsedHour(count)),
+                'm' => section.tokens.push(Token::ElapsedMinute(count)),
+                's' => section.tokens.push(Token::ElapsedSecond(count)),
+                _ => {}
+            }
+        } else if let Some(num) = lower
+            .strip_prefix("dbnum")
+            .and_then(|v| v.parse::<u8>().ok())
+        {
+            section.db_num = Some(num);
+        }
+    }
+
+    pub(crate) fn get_sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Section used for the number, and whether the value is written without its sign.
+    pub(crate) fn get_number_section(&self, value: f64) -> Option<(&Section, bool)> {
+        let mut sections: Vec<&Section> = self.sections.iter().take(3).collect();
+        if sections.len() > 1 && sections.last().unwrap().is_text() {
+            sections.pop();
+        }
+        if sections.iter().any(|v| v.condition.is_some()) {
+            for (i, section) in sections.iter().enumerate() {
+                match &section.condition {
+                    Some(condition) if condition.is_match(value) => {
+                        return Some((section, false));
+                    }
+                    Some(_) => {}
+                    None if i == 0 => {
+                        if value >= 0f64 {
+                            return Some((section, false));
+                        }
+                    }
+                    None => return Some((section, sections[0].condition.is_none())),
+                }
+            }
+            return None;
+        }
+        match sections.len() {
+            0 => None,
+            1 => Some((sections[0], false)),
+            2 => {
+                if value >= 0f64 {
+                    Some((sections[0], false))
+                } else {
+                    Some((sections[1], true))
+                }
+            }
+            _ => {
+                if value > 0f64 {
+                    Some((sections[0], false))
+                } else if value < 0f64 {
+                    Some((sections[1], true))
+                } else {
+                    Some((sections[2], false))
+                }
+            }
+        }
+    }
+
+    /// Section used for the text. None writes the text as is.
+    pub(crate) fn get_text_section(&self) -> Option<&Section> {
+        if let Some(section) = self.sections.get(3) {
+            return Some(section);
+        }
+        self.sections.last().filter(|v| v.is_text())
+    }
+
+    pub(crate) fn is_date_time(&self) -> bool {
+        self.sections.iter().any(|v| v.is_date_time())
+    }
+}
@@ -0,0 +1,398 @@
+use chrono::{Datelike, NaiveDate};
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_MONTHS_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const EN_DAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const EN_DAYS_SHORT: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const DE_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+const DE_MONTHS_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+];
+const DE_DAYS: [&str; 7] = [
+    "Sonntag",
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+];
+const DE_DAYS_SHORT: [&str; 7] = ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"];
+
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const FR_MONTHS_SHORT: [&str; 12] = [
+    "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+    "déc.",
+];
+const FR_DAYS: [&str; 7] = [
+    "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+];
+const FR_DAYS_SHORT: [&str; 7] = ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."];
+
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+const ES_MONTHS_SHORT: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+];
+const ES_DAYS: [&str; 7] = [
+    "domingo",
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+];
+const ES_DAYS_SHORT: [&str; 7] = ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"];
+
+const JA_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+const JA_DAYS: [&str; 7] = [
+    "日曜日",
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+];
+const JA_DAYS_SHORT: [&str; 7] = ["日", "月", "火", "水", "木", "金", "土"];
+
+const ZH_MONTHS: [&str; 12] = [
+    "一月",
+    "二月",
+    "三月",
+    "四月",
+    "五月",
+    "六月",
+    "七月",
+    "八月",
+    "九月",
+    "十月",
+    "十一月",
+    "十二月",
+];
+const ZH_DAYS: [&str; 7] = [
+    "星期日",
+    "星期一",
+    "星期二",
+    "星期三",
+    "星期四",
+    "星期五",
+    "星期六",
+];
+const ZH_DAYS_SHORT: [&str; 7] = ["周日", "周一", "周二", "周三", "周四", "周五", "周六"];
+
+/// `(start date, name, short name, letter)` of the Japanese eras.
+const JAPANESE_ERAS: [((i32, u32, u32), &str, &str, &str); 5] = [
+    ((2019, 5, 1), "令和", "令", "R"),
+    ((1989, 1, 8), "平成", "平", "H"),
+    ((1926, 12, 25), "昭和", "昭", "S"),
+    ((1912, 7, 30), "大正", "大", "T"),
+    ((1868, 9, 8), "明治", "明", "M"),
+];
+
+/// Locale used by the number formats.
+/// Decides the separators of numbers and the names of months and days.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::number_format::*;
+/// let locale = NumberFormatLocale::from_lcid(0x0407).unwrap();
+/// let value = format_number(1234.5, "#,##0.00", &locale, "1900");
+/// assert_eq!(value.get_text(), "1.234,50");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormatLocale {
+    lcid: u32,
+    decimal_separator: char,
+    thousands_separator: char,
+    month_names: &'static [&'static str; 12],
+    month_short_names: &'static [&'static str; 12],
+    day_names: &'static [&'static str; 7],
+    day_short_names: &'static [&'static str; 7],
+    am_pm: Option<(&'static str, &'static str)>,
+}
+
+impl Default for NumberFormatLocale {
+    fn default() -> Self {
+        Self::new(0x0409, '.', ',')
+    }
+}
+
+impl NumberFormatLocale {
+    fn new(lcid: u32, decimal_separator: char, thousands_separator: char) -> Self {
+        Self {
+            lcid,
+            decimal_separator,
+            thousands_separator,
+            month_names: &EN_MONTHS,
+            month_short_names: &EN_MONTHS_SHORT,
+            day_names: &EN_DAYS,
+            day_short_names: &EN_DAYS_SHORT,
+            am_pm: None,
+        }
+    }
+
+    /// Get a built-in locale by the language id. ex) 0x0411
+    /// Supported: en-US, en-GB, de-DE, fr-FR, es-ES, ja-JP and zh-CN.
+    pub fn from_lcid(lcid: u32) -> Option<Self> {
+        let lcid = lcid & 0xFFFF;
+        let mut locale = match lcid {
+            0x0409 | 0x0809 => Self::new(lcid, '.', ','),
+            0x0407 => Self::new(lcid, ',', '.'),
+            0x040C => Self::new(lcid, ',', '\u{a0}'),
+            0x040A | 0x0C0A => Self::new(lcid, ',', '.'),
+            0x0411 | 0x0804 => Self::new(lcid, '.', ','),
+            _ => return None,
+        };
+        match lcid {
+            0x0407 => locale.set_names(&DE_MONTHS, &DE_MONTHS_SHORT, &DE_DAYS, &DE_DAYS_SHORT),
+            0x040C => locale.set_names(&FR_MONTHS, &FR_MONTHS_SHORT, &FR_DAYS, &FR_DAYS_SHORT),
+            0x040A | 0x0C0A => {
+                locale.set_names(&ES_MONTHS, &ES_MONTHS_SHORT, &ES_DAYS, &ES_DAYS_SHORT)
+            }
+            0x0411 => {
+                locale.set_names(&JA_MONTHS, &JA_MONTHS, &JA_DAYS, &JA_DAYS_SHORT);
+                locale.am_pm = Some(("午前", "午後"));
+            }
+            0x0804 => {
+                locale.set_names(&ZH_MONTHS, &JA_MONTHS, &ZH_DAYS, &ZH_DAYS_SHORT);
+                locale.am_pm = Some(("上午", "下午"));
+            }
+            _ => {}
+        }
+        Some(locale)
+    }
+
+    /// Get a built-in locale by the name. ex) "ja-JP"
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lcid = match name {
+            "en-US" => 0x0409,
+            "en-GB" => 0x0809,
+            "de-DE" => 0x0407,
+            "fr-FR" => 0x040C,
+            "es-ES" => 0x0C0A,
+            "ja-JP" => 0x0411,
+            "zh-CN" => 0x0804,
+            _ => return None,
+        };
+        Self::from_lcid(lcid)
+    }
+
+    fn set_names(
+        &mut self,
+        month_names: &'static [&'static str; 12],
+        month_short_names: &'static [&'static str; 12],
+        day_names: &'static [&'static str; 7],
+        day_short_names: &'static [&'static str; 7],
+    ) {
+        self.month_names = month_names;
+        self.month_short_names = month_short_names;
+        self.day_names = day_names;
+        self.day_short_names = day_short_names;
+    }
+
+    pub fn get_lcid(&self) -> &u32 {
+        &self.lcid
+    }
+
+    pub fn get_decimal_separator(&self) -> &char {
+        &self.decimal_separator
+    }
+
+    pub fn set_decimal_separator(&mut self, value: char) -> &mut Self {
+        self.decimal_separator = value;
+        self
+    }
+
+    pub fn get_thousands_separator(&self) -> &char {
+        &self.thousands_separator
+    }
+
+    pub fn set_thousands_separator(&mut self, value: char) -> &mut Self {
+        self.thousands_separator = value;
+        self
+    }
+
+    /// Name of the month. (1 to 12)
+    pub(crate) fn get_month_name(&self, month: u32, short: bool) -> &'static str {
+        let names = if short {
+            self.month_short_names
+        } else {
+            self.month_names
+        };
+        names[(month as usize + 11) % 12]
+    }
+
+    /// Name of the day of the week. (0 is Sunday)
+    pub(crate) fn get_day_name(&self, weekday: u32, short: bool) -> &'static str {
+        let names = if short {
+            self.day_short_names
+        } else {
+            self.day_names
+        };
+        names[weekday as usize % 7]
+    }
+
+    pub(crate) fn get_am_pm(&self) -> Option<(&'static str, &'static str)> {
+        self.am_pm
+    }
+
+    pub(crate) fn is_japanese(&self) -> bool {
+        self.lcid == 0x0411
+    }
+
+    pub(crate) fn is_chinese(&self) -> bool {
+        self.lcid == 0x0804
+    }
+}
+
+/// `(name, year of the era)` of the Japanese era. `length` is 1 to 3. (g, gg, ggg)
+pub(crate) fn get_japanese_era(
+    year: i32,
+    month: u32,
+    day: u32,
+    length: usize,
+) -> Option<(&'static str, i32)> {
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    for ((y, m, d), name, short_name, letter) in JAPANESE_ERAS.iter() {
+        let start = NaiveDate::from_ymd_opt(*y, *m, *d)?;
+        if date >= start {
+            let name = match length {
+                1 => *letter,
+                2 => *short_name,
+                _ => *name,
+            };
+            return Some((name, date.year() - start.year() + 1));
+        }
+    }
+    None
+}
+
+/// Convert the digits to the numerals of [DBNum1] to [DBNum3].
+/// `positional` writes the units of the digits. ex) 千二百三十四
+pub(crate) fn to_db_num(value: &str, db_num: u8, chinese: bool, positional: bool) -> String {
+    const JA_DIGITS: [[&str; 10]; 2] = [
+        ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+        ["〇", "壱", "弐", "参", "四", "伍", "六", "七", "八", "九"],
+    ];
+    const ZH_DIGITS: [[&str; 10]; 2] = [
+        ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+        ["零", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"],
+    ];
+    const JA_UNITS: [[&str; 4]; 2] = [["", "十", "百", "千"], ["", "拾", "百", "阡"]];
+    const ZH_UNITS: [[&str; 4]; 2] = [["", "十", "百", "千"], ["", "拾", "佰", "仟"]];
+    const JA_LARGE_UNITS: [[&str; 4]; 2] = [["", "万", "億", "兆"], ["", "萬", "億", "兆"]];
+    const ZH_LARGE_UNITS: [[&str; 4]; 2] = [["", "万", "亿", "兆"], ["", "万", "亿", "兆"]];
+
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    if db_num >= 3 {
+        // full width digits.
+        return digits
+            .iter()
+            .map(|v| char::from_u32(0xFF10 + v).unwrap())
+            .collect();
+    }
+    let style = if db_num == 2 { 1 } else { 0 };
+    let (names, units, large_units) = if chinese {
+        (&ZH_DIGITS[style], &ZH_UNITS[style], &ZH_LARGE_UNITS[style])
+    } else {
+        (&JA_DIGITS[style], &JA_UNITS[style], &JA_LARGE_UNITS[style])
+    };
+    if !positional || digits.len() > 16 {
+        return digits.iter().map(|v| names[*v as usize]).collect();
+    }
+    if digits.iter().all(|v| *v == 0) {
+        return names[0].to_string();
+    }
+    let mut result = String::new();
+    let mut pending_zero = false;
+    let count = digits.len();
+    for (i, digit) in digits.iter().enumerate() {
+        let position = count - i - 1;
+        let unit = position % 4;
+        if *digit == 0 {
+            pending_zero = true;
+        } else {
+            if chinese && pending_zero && !result.is_empty() {
+                result.push_str(names[0]);
+            }
+            pending_zero = false;
+            // 1 is omitted before the units in Japanese. ex) 千, 百, 十
+            if !(*digit == 1 && unit > 0 && !chinese && style == 0) {
+                result.push_str(names[*digit as usize]);
+            }
+            result.push_str(units[unit]);
+        }
+        if unit == 0 && position > 0 {
+            let group_start = i + 1 - 4.min(i + 1);
+            if digits[group_start..=i].iter().any(|v| *v != 0) {
+                result.push_str(large_units[position / 4]);
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,559 @@
+use super::format_code::{Section, Token};
+use super::locale::{to_db_num, NumberFormatLocale};
+
+/// Round the value at 15 significant digits with the decimal half-up rule.
+/// Returns the digits of the integer part (without leading zeros) and `decimals` digits of the fraction part.
+/// Zero, subnormal and non-finite values are rounded as 0.
+pub(crate) fn round_decimal(value: f64, decimals: usize) -> (String, String) {
+    let zero = || (String::new(), "0".repeat(decimals));
+    if !value.is_normal() {
+        return zero();
+    }
+    let text = format!("{:.14e}", value.abs());
+    let (mantissa, exponent) = match text.split_once('e') {
+        Some(v) => v,
+        None => return zero(),
+    };
+    let mut exponent: i64 = match exponent.parse() {
+        Ok(v) => v,
+        Err(_) => return zero(),
+    };
+    let mut digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(|v| v.is_ascii_digit())
+        .map(|v| v - b'0')
+        .collect();
+
+    let keep = exponent + 1 + decimals as i64;
+    if keep < 0 {
+        return (String::new(), "0".repeat(decimals));
+    }
+    let keep = keep as usize;
+    if keep < digits.len() {
+        let round_up = digits[keep] >= 5;
+        digits.truncate(keep);
+        if round_up {
+            let mut carry = true;
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                digits.insert(0, 1);
+                exponent += 1;
+            }
+        }
+    }
+
+    let integer_length = exponent + 1;
+    let mut integer = String::new();
+    let mut fraction = String::new();
+    if integer_length <= 0 {
+        fraction.push_str(&"0".repeat((-integer_length) as usize));
+        fraction.extend(digits.iter().map(|v| (v + b'0') as char));
+    } else {
+        for i in 0..integer_length as usize {
+            integer.push((digits.get(i).copied().unwrap_or(0) + b'0') as char);
+        }
+        fraction.extend(
+            digits
+                .iter()
+                .skip(integer_length as usize)
+                .map(|v| (v + b'0') as char),
+        );
+    }
+    let integer = integer.trim_start_matches('0').to_string();
+    let mut fraction: String = fraction.chars().take(decimals).collect();
+    while fraction.len() < decimals {
+        fraction.push('0');
+    }
+    (integer, fraction)
+}
+
+/// Write the value in the General format. (11 characters at most)
+pub(crate) fn format_general(value: f64, locale: &NumberFormatLocale) -> String {
+    if !value.is_normal() {
+        return "0".into();
+    }
+    let sign = if value < 0f64 { "-" } else { "" };
+    let abs = value.abs();
+    if !(1e-9..1e11).contains(&abs) {
+        let mut exponent = abs.log10().floor() as i32;
+        let mut mantissa = round_decimal(abs / 10f64.powi(exponent), 5);
+        if mantissa.0.len() > 1 {
+            exponent += 1;
+            mantissa = round_decimal(abs / 10f64.powi(exponent), 5);
+        }
+        let fraction = mantissa.1.trim_end_matches('0');
+        let mut result = format!("{}{}", sign, mantissa.0);
+        if !fraction.is_empty() {
+            result.push(*locale.get_decimal_separator());
+            result.push_str(fraction);
+        }
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        return format!("{}E{}{:02}", result, exponent_sign, exponent.abs());
+    }
+    let integer_length = if abs >= 1f64 {
+        abs.log10().floor() as usize + 1
+    } else {
+        1
+    };
+    let (integer, fraction) = round_decimal(abs, 10usize.saturating_sub(integer_length));
+    let fraction = fraction.trim_end_matches('0');
+    let mut result = format!(
+        "{}{}",
+        sign,
+        if integer.is_empty() { "0" } else { &integer }
+    );
+    if !fraction.is_empty() {
+        result.push(*locale.get_decimal_separator());
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Write the number with the section of the number format.
+pub(crate) fn format_number(section: &Section, value: f64, locale: &NumberFormatLocale) -> String {
+    let tokens = &section.tokens;
+    let result = if tokens.contains(&Token::General) {
+        format_tokens_general(tokens, value, locale)
+    } else if tokens.iter().any(|v| matches!(v, Token::Exponent(_, _))) {
+        format_scientific(tokens, value, locale)
+    } else if tokens
+        .iter()
+        .any(|v| matches!(v, Token::Slash | Token::Denominator(_)))
+    {
+        format_fraction(tokens, value, locale)
+    } else {
+        format_decimal(tokens, value, locale)
+    };
+    match section.db_num {
+        Some(db_num) => apply_db_num(&result, db_num, locale, section.lcid),
+        None => result,
+    }
+}
+
+fn write_literal(result: &mut String, token: &Token, value: f64, locale: &NumberFormatLocale) {
+    match token {
+        Token::Literal(v) => result.push_str(v),
+        Token::Fill(v) => result.push(*v),
+        Token::Percent => result.push('%'),
+        Token::Text => result.push_str(&format_general(value, locale)),
+        _ => {}
+    }
+}
+
+fn format_tokens_general(tokens: &[Token], value: f64, locale: &NumberFormatLocale) -> String {
+    let mut result = String::new();
+    for token in tokens {
+        match token {
+            Token::General => result.push_str(&format_general(value, locale)),
+            _ => write_literal(&mut result, token, value, locale),
+        }
+    }
+    result
+}
+
+fn percent_count(tokens: &[Token]) -> i32 {
+    tokens.iter().filter(|v| **v == Token::Percent).count() as i32
+}
+
+/// Write the integer digits into the placeholders. The leftmost placeholder takes the extra digits.
+fn fill_integer(placeholders: &[char], digits: &str, grouping: Option<char>) -> Vec<String> {
+    let digits: Vec<char> = digits.chars().collect();
+    let mut result: Vec<String> = vec![String::new(); placeholders.len()];
+    let mut rest = digits.len();
+    let mut position = 0;
+    let mut push = |cell: &mut String, c: char, position: usize| {
+        if let Some(separator) = grouping {
+            if position > 0 && position % 3 == 0 {
+                cell.insert(0, if c == ' ' { ' ' } else { separator });
+            }
+        }
+        cell.insert(0, c);
+    };
+    for (i, placeholder) in placeholders.iter().enumerate().rev() {
+        if rest > 0 {
+            rest -= 1;
+            push(&mut result[i], digits[rest], position);
+            position += 1;
+            if i == 0 {
+                while rest > 0 {
+                    rest -= 1;
+                    push(&mut result[i], digits[rest], position);
+                    position += 1;
+                }
+            }
+        } else {
+            match placeholder {
+                '0' => push(&mut result[i], '0', position),
+                '?' => push(&mut result[i], ' ', position),
+                _ => continue,
+            }
+            position += 1;
+        }
+    }
+    result
+}
+
+/// Write the fraction digits into the placeholders. Trailing zeros are dropped for `#` and spaced for `?`.
+fn fill_fraction(placeholders: &[char], digits: &str) -> Vec<String> {
+    let mut result: Vec<String> = digits.chars().map(|v| v.to_string()).collect();
+    for (i, placeholder) in placeholders.iter().enumerate().rev() {
+        if result[i] != "0" {
+            break;
+        }
+        match placeholder {
+            '#' => result[i] = String::new(),
+            '?' => result[i] = " ".into(),
+            _ => break,
+        }
+    }
+    result
+}
+
+fn format_decimal(tokens: &[Token], value: f64, locale: &NumberFormatLocale) -> String {
+    let decimal_index = tokens.iter().position(|v| *v == Token::DecimalPoint);
+    let integer_end = decimal_index.unwrap_or(tokens.len());
+    let digit_indexes: Vec<usize> = (0..tokens.len())
+        .filter(|i| matches!(tokens[*i], Token::Digit(_)))
+        .collect();
+    let integer_indexes: Vec<usize> = digit_indexes
+        .iter()
+        .copied()
+        .filter(|i| *i < integer_end)
+        .collect();
+    let fraction_indexes: Vec<usize> = digit_indexes
+        .iter()
+        .copied()
+        .filter(|i| *i > integer_end)
+        .collect();
+
+    // commas between the integer digits group thousands, commas after the digits scale by 1000.
+    let mut grouping = false;
+    let mut scale = 0;
+    let last_digit = digit_indexes.last().copied().unwrap_or(0);
+    let last_integer = integer_indexes.last().copied();
+    for (i, token) in tokens.iter().enumerate() {
+        if *token != Token::Thousands {
+            continue;
+        }
+        let is_scale = match last_integer {
+            Some(last) => i > last && (i < integer_end || i > last_digit),
+            None => false,
+        };
+        if is_scale {
+            scale += 1;
+        } else if integer_indexes.first().map_or(false, |v| *v < i) && i < integer_end {
+            grouping = true;
+        }
+    }
+
+    let value = value.abs() * 100f64.powi(percent_count(tokens)) / 1000f64.powi(scale);
+    let (integer, fraction) = round_decimal(value, fraction_indexes.len());
+
+    let integer_placeholders: Vec<char> = integer_indexes
+        .iter()
+        .map(|i| match tokens[*i] {
+            Token::Digit(v) => v,
+            _ => '#',
+        })
+        .collect();
+    let fraction_placeholders: Vec<char> = fraction_indexes
+        .iter()
+        .map(|i| match tokens[*i] {
+            Token::Digit(v) => v,
+            _ => '#',
+        })
+        .collect();
+    let separator = if grouping {
+        Some(*locale.get_thousands_separator())
+    } else {
+        None
+    };
+    let mut integer_cells = fill_integer(&integer_placeholders, &integer, separator).into_iter();
+    let mut fraction_cells = fill_fraction(&fraction_placeholders, &fraction).into_iter();
+
+    let mut result = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Digit(_) if i < integer_end => {
+                result.push_str(&integer_cells.next().unwrap_or_default())
+            }
+            Token::Digit(_) => result.push_str(&fraction_cells.next().unwrap_or_default()),
+            Token::DecimalPoint => {
+                if integer_placeholders.is_empty() {
+                    result.push_str(&integer);
+                }
+                result.push(*locale.get_decimal_separator());
+            }
+            Token::Thousands => {}
+            _ => write_literal(&mut result, token, value, locale),
+        }
+    }
+    result
+}
+
+fn format_scientific(tokens: &[Token], value: f64, locale: &NumberFormatLocale) -> String {
+    let exponent_index = tokens
+        .iter()
+        .position(|v| matches!(v, Token::Exponent(_, _)))
+        .unwrap();
+    let mantissa_tokens = &tokens[..exponent_index];
+    let integer_placeholders: Vec<char> = mantissa_tokens
+        .iter()
+        .take_while(|v| **v != Token::DecimalPoint)
+        .filter_map(|v| match v {
+            Token::Digit(c) => Some(*c),
+            _ => None,
+        })
+        .collect();
+    let integer_count = integer_placeholders.len().max(1) as i32;
+    let engineering = integer_count > 1 && integer_placeholders[0] == '#';
+
+    let value = value.abs() * 100f64.powi(percent_count(tokens));
+    // subnormal values can not be scaled to the mantissa.
+    let value = if value.is_normal() { value } else { 0f64 };
+    let mut exponent = if value == 0f64 {
+        0
+    } else {
+        value.log10().floor() as i32
+    };
+    exponent = if engineering {
+        exponent.div_euclid(integer_count) * integer_count
+    } else {
+        exponent - (integer_count - 1)
+    };
+    let fraction_count = mantissa_tokens
+        .iter()
+        .skip_while(|v| **v != Token::DecimalPoint)
+        .filter(|v| matches!(v, Token::Digit(_)))
+        .count();
+    let mut mantissa = value / 10f64.powi(exponent);
+    let (integer, _) = round_decimal(mantissa, fraction_count);
+    let max_length = if engineering {
+        integer_count
+    } else {
+        integer_count.max(1)
+    } as usize;
+    if value != 0f64 && integer.len() > max_length {
+        exponent += if engineering { integer_count } else { 1 };
+        mantissa = value / 10f64.powi(exponent);
+    }
+
+    let mut result = format_decimal(mantissa_tokens, mantissa, locale);
+    if let Token::Exponent(letter, plus) = &tokens[exponent_index] {
+        result.push(*letter);
+        if exponent < 0 {
+            result.push('-');
+        } else if *plus {
+            result.push('+');
+        }
+    }
+    let exponent_digits = tokens[exponent_index + 1..]
+        .iter()
+        .filter(|v| matches!(v, Token::Digit(_)))
+        .count();
+    let exponent_text = format!("{:0width$}", exponent.abs(), width = exponent_digits);
+    let mut wrote = false;
+    for token in &tokens[exponent_index + 1..] {
+        match token {
+            Token::Digit(_) => {
+                if !wrote {
+                    result.push_str(&exponent_text);
+                    wrote = true;
+                }
+            }
+            _ => write_literal(&mut result, token, value, locale),
+        }
+    }
+    result
+}
+
+/// Best rational approximation with the denominator up to `max_denominator`.
+fn approximate_fraction(value: f64, max_denominator: u64) -> (u64, u64) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0u64, 1u64, 1u64, 0u64);
+    let mut x = value;
+    loop {
+        let a = x.floor() as u64;
+        let q2 = q0 + a * q1;
+        if q2 > max_denominator {
+            break;
+        }
+        let p2 = p0 + a * p1;
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+        let rest = x - a as f64;
+        if rest.abs() < 1e-10 {
+            break;
+        }
+        x = 1f64 / rest;
+    }
+    if q1 == 0 {
+        return (0, 1);
+    }
+    // semiconvergent between the last two convergents.
+    let k = (max_denominator - q0) / q1;
+    let (p2, q2) = (p0 + k * p1, q0 + k * q1);
+    let error1 = (value - p1 as f64 / q1 as f64).abs();
+    let error2 = (value - p2 as f64 / q2 as f64).abs();
+    if q2 > 0 && error2 < error1 {
+        (p2, q2)
+    } else {
+        (p1, q1)
+    }
+}
+
+fn format_fraction(tokens: &[Token], value: f64, locale: &NumberFormatLocale) -> String {
+    let slash_index = tokens
+        .iter()
+        .position(|v| matches!(v, Token::Slash | Token::Denominator(_)))
+        .unwrap();
+    // digit groups before the slash: [integer] numerator
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+    while i < slash_index {
+        if matches!(tokens[i], Token::Digit(_)) {
+            let start = i;
+            while i < slash_index && matches!(tokens[i], Token::Digit(_) | Token::Thousands) {
+                i += 1;
+            }
+            groups.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+    let numerator_group = groups.pop().unwrap_or((slash_index, slash_index));
+    let integer_group = groups.pop();
+    let denominator_end = match tokens[slash_index] {
+        Token::Denominator(_) => slash_index + 1,
+        _ => {
+            let mut end = slash_index + 1;
+            while matches!(tokens.get(end), Some(Token::Digit(_))) {
+                end += 1;
+            }
+            end
+        }
+    };
+
+    let value = value.abs();
+    let mut integer = if integer_group.is_some() {
+        value.trunc()
+    } else {
+        0f64
+    };
+    let rest = value - integer;
+    let (mut numerator, denominator) = match tokens[slash_index] {
+        Token::Denominator(v) => ((rest * v as f64).round() as u64, v as u64),
+        _ => {
+            let count = (denominator_end - slash_index - 1).clamp(1, 9);
+            approximate_fraction(rest, 10u64.pow(count as u32) - 1)
+        }
+    };
+    if integer_group.is_some() && numerator >= denominator {
+        integer += (numerator / denominator) as f64;
+        numerator %= denominator;
+    }
+
+    let placeholders = |start: usize, end: usize| -> Vec<char> {
+        tokens[start..end]
+            .iter()
+            .filter_map(|v| match v {
+                Token::Digit(c) => Some(*c),
+                _ => None,
+            })
+            .collect()
+    };
+    let blank = numerator == 0 && integer_group.is_some();
+    let mut result = String::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some((start, end)) = integer_group {
+            if i == start {
+                let (digits, _) = round_decimal(integer, 0);
+                let digits = if blank && digits.is_empty() {
+                    "0".to_string()
+                } else {
+                    digits
+                };
+                let grouping = tokens[start..end].contains(&Token::Thousands);
+                let separator = if grouping {
+                    Some(*locale.get_thousands_separator())
+                } else {
+                    None
+                };
+                result.push_str(
+                    &fill_integer(&placeholders(start, end), &digits, separator).concat(),
+                );
+                i = end;
+                continue;
+            }
+        }
+        if i == numerator_group.0 {
+            let mut text = String::new();
+            let numerator_text = numerator.to_string();
+            let numerator_text = fill_integer(
+                &placeholders(numerator_group.0, numerator_group.1),
+                &numerator_text,
+                None,
+            )
+            .concat();
+            text.push_str(&numerator_text);
+            text.push('/');
+            let denominator_text = denominator.to_string();
+            let denominator_text = match tokens[slash_index] {
+                Token::Denominator(_) => denominator_text,
+                _ => {
+                    let count = denominator_end - slash_index - 1;
+                    let mut v = denominator_text;
+                    while v.chars().count() < count {
+                        v.push(' ');
+                    }
+                    v
+                }
+            };
+            text.push_str(&denominator_text);
+            if blank {
+                text = " ".repeat(text.chars().count());
+            }
+            result.push_str(&text);
+            i = denominator_end;
+            continue;
+        }
+        write_literal(&mut result, &tokens[i], value, locale);
+        i += 1;
+    }
+    result
+}
+
+/// Convert the digits of the number to the numerals of [DBNum1] to [DBNum3].
+fn apply_db_num(value: &str, db_num: u8, locale: &NumberFormatLocale, lcid: Option<u32>) -> String {
+    let chinese = lcid.map_or(locale.is_chinese(), |v| v == 0x0804);
+    let chars: Vec<char> = value.chars().collect();
+    let decimal = *locale.get_decimal_separator();
+    let mut result = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let run: String = chars[start..i].iter().collect();
+            let positional = !(start > 0 && chars[start - 1] == decimal)
+                && !(i < chars.len() && chars[i] == decimal);
+            result.push_str(&to_db_num(&run, db_num, chinese, positional));
+        } else {
+            result.push(chars[i]);
+            i += 1;
+        }
+    }
+    result
+}
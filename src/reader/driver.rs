@@ -91,3 +91,53 @@ pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, FromUtf8Er
     let value = attr.value.clone().into_owned();
     String::from_utf8(value)
 }
+
+/// Read an element and all of its children as raw XML.
+/// Used to keep parts of a document that are not modeled.
+pub(crate) fn read_raw_xml<R: std::io::BufRead>(
+    reader: &mut quick_xml::Reader<R>,
+    e: &quick_xml::events::BytesStart,
+    empty_flag: bool,
+) -> String {
+    use quick_xml::events::Event;
+
+    let mut writer = quick_xml::Writer::new(std::io::Cursor::new(Vec::new()));
+    if empty_flag {
+        let _ = writer.write_event(Event::Empty(e.borrow()));
+    } else {
+        let _ = writer.write_event(Event::Start(e.borrow()));
+        let mut depth = 0;
+        let mut buf = Vec::new();
+        loop {
+            let ev = match reader.read_event_into(&mut buf) {
+                Ok(v) => v,
+                Err(e) => panic!("Error at position {}: {e:?}", reader.buffer_position()),
+            };
+            let is_end = match &ev {
+                Event::Start(_) => {
+                    depth += 1;
+                    false
+                }
+                Event::End(_) => {
+                    if depth == 0 {
+                        true
+                    } else {
+                        depth -= 1;
+                        false
+                    }
+                }
+                Event::Eof => panic!(
+                    "Error not find {} end element",
+                    String::from_utf8_lossy(e.name().into_inner())
+                ),
+                _ => false,
+            };
+            let _ = writer.write_event(ev);
+            if is_end {
+                break;
+            }
+            buf.clear();
+        }
+    }
+    String::from_utf8(writer.into_inner().into_inner()).unwrap()
+}
@@ -16,16 +16,21 @@ use structs::Stylesheet;
 use structs::Worksheet;
 
 pub(crate) mod chart;
+mod chartsheet;
 pub(crate) mod comment;
 mod content_types;
 mod doc_props_app;
 mod doc_props_core;
+mod doc_props_custom;
 pub(crate) mod drawing;
+mod external_link;
+mod person;
 mod rels;
 mod shared_strings;
 mod styles;
 pub(crate) mod table;
 mod theme;
+pub(crate) mod threaded_comment;
 mod vba_project_bin;
 pub(crate) mod vml_drawing;
 mod workbook;
@@ -91,6 +96,7 @@ pub fn read_reader<R: io::Read + io::Seek>(
     let mut book = workbook::read(&mut arv).unwrap();
     doc_props_app::read(&mut arv, &mut book).unwrap();
     doc_props_core::read(&mut arv, &mut book).unwrap();
+    doc_props_custom::read(&mut arv, &mut book).unwrap();
     vba_project_bin::read(&mut arv, &mut book).unwrap();
     content_types::read(&mut arv, &mut book).unwrap();
     let workbook_rel = workbook_rels::read(&mut arv, &mut book).unwrap();
@@ -103,11 +109,45 @@ pub fn read_reader<R: io::Read + io::Seek>(
             let theme = theme::read(&mut arv, rel_target).unwrap();
             book.set_theme(theme);
         }
+        if type_value.as_str() == "http://schemas.microsoft.com/office/2017/10/relationships/person"
+        {
+            person::read(&mut arv, rel_target, &mut book).unwrap();
+        }
+    }
+
+    for external_link in book.get_external_links_mut() {
+        for (rel_id, _, rel_target) in &workbook_rel {
+            if external_link.get_r_id() != rel_id {
+                continue;
+            }
+            external_link::read(&mut arv, rel_target, external_link).unwrap();
+        }
     }
 
     shared_strings::read(&mut arv, &mut book).unwrap();
     styles::read(&mut arv, &mut book).unwrap();
 
+    let mut chart_sheet_r_ids: Vec<String> = Vec::new();
+    for (rel_id, type_value, _) in &workbook_rel {
+        if type_value.as_str()
+            == "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet"
+        {
+            chart_sheet_r_ids.push(rel_id.clone());
+        }
+    }
+    if !chart_sheet_r_ids.is_empty() {
+        book.convert_to_chart_sheets(&chart_sheet_r_ids);
+        let theme = book.get_theme().clone();
+        for chart_sheet in book.get_chart_sheet_collection_mut() {
+            for (rel_id, _, rel_target) in &workbook_rel {
+                if chart_sheet.get_r_id() != rel_id {
+                    continue;
+                }
+                chartsheet::read(&mut arv, rel_target, chart_sheet, &theme).unwrap();
+            }
+        }
+    }
+
     for sheet in book.get_sheet_collection_mut() {
         for (rel_id, _, rel_target) in &workbook_rel {
             if sheet.get_r_id() != rel_id {
@@ -199,6 +239,10 @@ pub(crate) fn raw_to_deserialize_by_worksheet(
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table" => {
                     table::read(worksheet, relationship.get_raw_file()).unwrap();
                 }
+                // threaded comment
+                "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment" => {
+                    threaded_comment::read(worksheet, relationship.get_raw_file()).unwrap();
+                }
                 _ => {}
             }
         }
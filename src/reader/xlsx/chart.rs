@@ -3,6 +3,7 @@ use crate::xml_read_loop;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::result;
+use structs::drawing::chart_ex::ChartSpace as ChartExSpace;
 use structs::drawing::charts::ChartSpace;
 use structs::raw::RawFile;
 
@@ -27,3 +28,25 @@ pub(crate) fn read(
 
     Ok(())
 }
+
+pub(crate) fn read_chart_ex(
+    raw_file: &RawFile,
+    chart_space: &mut ChartExSpace,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(raw_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+
+    reader.trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"cx:chartSpace" {
+                chart_space.set_attributes(&mut reader, e);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
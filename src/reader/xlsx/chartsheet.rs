@@ -0,0 +1,100 @@
+use super::driver::*;
+use super::XlsxError;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::{io, result};
+
+use structs::drawing::Theme;
+use structs::raw::RawFile;
+use structs::raw::RawRelationships;
+use structs::Chartsheet;
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    target: &str,
+    chart_sheet: &mut Chartsheet,
+    theme: &Theme,
+) -> result::Result<(), XlsxError> {
+    let mut chart_sheet_file = RawFile::default();
+    chart_sheet_file.set_attributes(arv, "xl", target);
+
+    let mut chart_sheet_relationships = RawRelationships::default();
+    chart_sheet_relationships.set_attributes(
+        arv,
+        &chart_sheet_file.get_path(),
+        &chart_sheet_file.make_rel_name(),
+    );
+
+    let data = std::io::Cursor::new(chart_sheet_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.trim_text(true);
+
+    let mut drawing_r_id: Option<String> = None;
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => match e.name().into_inner() {
+            b"tabColor" => {
+                chart_sheet
+                    .get_tab_color_mut()
+                    .set_attributes(&mut reader, e, true);
+                chart_sheet.get_tab_color_mut().set_argb_by_theme(theme);
+            }
+            b"sheetView" => {
+                if let Some(v) = get_attribute(e, b"zoomScale") {
+                    chart_sheet.set_zoom_scale(v.parse::<u32>().unwrap_or(100));
+                }
+                if let Some(v) = get_attribute(e, b"zoomToFit") {
+                    chart_sheet.set_zoom_to_fit(v == "1" || v == "true");
+                }
+            }
+            b"pageMargins" => {
+                chart_sheet
+                    .get_page_margins_mut()
+                    .set_attributes(&mut reader, e);
+            }
+            b"drawing" => {
+                drawing_r_id = get_attribute(e, b"r:id");
+            }
+            _ => (),
+        },
+        Event::Eof => break,
+    );
+
+    let drawing_r_id = match drawing_r_id {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+    let drawing_file = chart_sheet_relationships
+        .get_relationship_by_rid(&drawing_r_id)
+        .get_raw_file();
+
+    let mut drawing_relationships = RawRelationships::default();
+    let has_drawing_relationships = drawing_relationships.set_attributes(
+        arv,
+        &drawing_file.get_path(),
+        &drawing_file.make_rel_name(),
+    );
+    let drawing_relationships = match has_drawing_relationships {
+        true => Some(&drawing_relationships),
+        false => None,
+    };
+
+    let data = std::io::Cursor::new(drawing_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"xdr:absoluteAnchor" {
+                chart_sheet
+                    .get_absolute_anchor_mut()
+                    .set_attributes(&mut reader, e, drawing_relationships);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -25,20 +25,47 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     let mut buf = Vec::new();
     let mut string_value: String = String::from("");
     loop {
+        let app_properties = spreadsheet.get_app_properties_mut();
         match reader.read_event_into(&mut buf) {
             Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
             Ok(Event::End(ref e)) => {
                 match e.name().into_inner() {
+                    b"Application" => {
+                        app_properties.set_application(string_value.clone());
+                    }
+                    b"AppVersion" => {
+                        app_properties.set_app_version(string_value.clone());
+                    }
                     b"Manager" => {
+                        app_properties.set_manager(string_value.clone());
                         spreadsheet
                             .get_properties_mut()
                             .set_manager(string_value.clone());
                     }
                     b"Company" => {
+                        app_properties.set_company(string_value.clone());
                         spreadsheet
                             .get_properties_mut()
                             .set_company(string_value.clone());
                     }
+                    b"HyperlinkBase" => {
+                        app_properties.set_hyperlink_base(string_value.clone());
+                    }
+                    b"DocSecurity" => {
+                        app_properties.set_doc_security(string_value.parse().unwrap_or(0));
+                    }
+                    b"ScaleCrop" => {
+                        app_properties.set_scale_crop(string_value == "true");
+                    }
+                    b"LinksUpToDate" => {
+                        app_properties.set_links_up_to_date(string_value == "true");
+                    }
+                    b"SharedDoc" => {
+                        app_properties.set_shared_doc(string_value == "true");
+                    }
+                    b"HyperlinksChanged" => {
+                        app_properties.set_hyperlinks_changed(string_value == "true");
+                    }
                     _ => (),
                 }
                 string_value = String::from("");
@@ -0,0 +1,47 @@
+use super::XlsxError;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::{io, result};
+use structs::CustomProperty;
+use structs::Spreadsheet;
+
+const FILE_PATH: &str = "docProps/custom.xml";
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    spreadsheet: &mut Spreadsheet,
+) -> result::Result<(), XlsxError> {
+    let r = io::BufReader::new(match arv.by_name(FILE_PATH) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => {
+            return Ok(());
+        }
+        Err(e) => {
+            return Err(e.into());
+        }
+    });
+    let mut reader = Reader::from_reader(r);
+    reader.trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"property" {
+                let mut obj = CustomProperty::default();
+                obj.set_attributes(&mut reader, e, false);
+                spreadsheet.get_properties_mut().get_custom_properties_mut().push(obj);
+            }
+        },
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"property" {
+                let mut obj = CustomProperty::default();
+                obj.set_attributes(&mut reader, e, true);
+                spreadsheet.get_properties_mut().get_custom_properties_mut().push(obj);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
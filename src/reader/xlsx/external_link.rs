@@ -0,0 +1,35 @@
+use super::XlsxError;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::{io, result};
+use structs::raw::RawFile;
+use structs::raw::RawRelationships;
+use structs::ExternalLink;
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    external_link: &mut ExternalLink,
+) -> result::Result<(), XlsxError> {
+    let mut file = RawFile::default();
+    file.set_attributes(arv, "xl", target);
+    let mut relationships = RawRelationships::default();
+    relationships.set_attributes(arv, &file.get_path(), &file.make_rel_name());
+
+    let data = std::io::Cursor::new(file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"externalLink" {
+                external_link.set_attributes(&mut reader, e, &file, &relationships);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use super::XlsxError;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::{io, result};
+use structs::Person;
+use structs::Spreadsheet;
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    spreadsheet: &mut Spreadsheet,
+) -> result::Result<(), XlsxError> {
+    let r = io::BufReader::new(arv.by_name(&format!("xl/{}", target))?);
+    let mut reader = Reader::from_reader(r);
+    reader.trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"person" {
+                let mut obj = Person::default();
+                obj.set_attributes(e);
+                spreadsheet.get_person_collection_mut().push(obj);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use super::XlsxError;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::result;
+use structs::raw::RawFile;
+use structs::ThreadedComment;
+use structs::Worksheet;
+
+pub(crate) fn read(
+    worksheet: &mut Worksheet,
+    threaded_comment_file: &RawFile,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(threaded_comment_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.trim_text(false);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"threadedComment" {
+                let mut obj = ThreadedComment::default();
+                obj.set_attributes(&mut reader, e, false);
+                worksheet.get_threaded_comments_mut().push(obj);
+            }
+        },
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"threadedComment" {
+                let mut obj = ThreadedComment::default();
+                obj.set_attributes(&mut reader, e, true);
+                worksheet.get_threaded_comments_mut().push(obj);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -7,7 +7,9 @@ use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::{io, result};
 
+use structs::CalculationProperties;
 use structs::DefinedName;
+use structs::ExternalLink;
 use structs::Spreadsheet;
 use structs::WorkbookProtection;
 use structs::WorkbookView;
@@ -23,8 +25,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     reader.trim_text(true);
     let mut spreadsheet = Spreadsheet::default();
 
-    let mut defined_name_value = String::from("");
-    let mut is_local_only = false;
+    let mut defined_name = DefinedName::default();
     let mut string_value = String::from("");
     let mut defined_names: Vec<DefinedName> = Vec::new();
 
@@ -37,6 +38,16 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                     obj.set_attributes(&mut reader, e);
                     spreadsheet.set_workbook_view(obj);
                 }
+                b"workbookPr" => {
+                    if let Some(v) = get_attribute(e, b"date1904") {
+                        spreadsheet.set_date1904(v == "1" || v == "true");
+                    }
+                }
+                b"calcPr" => {
+                    let mut obj = CalculationProperties::default();
+                    obj.set_attributes(&mut reader, e);
+                    spreadsheet.set_calculation_properties(obj);
+                }
                 b"workbookProtection" => {
                     let mut obj = WorkbookProtection::default();
                     obj.set_attributes(&mut reader, e);
@@ -52,6 +63,11 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                     worksheet.set_r_id(r_id_value);
                     let _ = spreadsheet.add_sheet(worksheet);
                 }
+                b"externalReference" => {
+                    let mut obj = ExternalLink::default();
+                    obj.set_r_id(get_attribute(e, b"r:id").unwrap());
+                    spreadsheet.add_external_link(obj);
+                }
                 b"pivotCache" => {
                     let cache_id = get_attribute(e, b"cacheId").unwrap();
                     let r_id = get_attribute(e, b"r:id").unwrap();
@@ -62,33 +78,48 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         },
         Event::Start(ref e) => {
             if e.name().into_inner() == b"definedName" {
-                defined_name_value = get_attribute(e, b"name").unwrap();
-                is_local_only = get_attribute(e, b"localSheetId").is_some();
+                defined_name = DefinedName::default();
+                defined_name.set_name(get_attribute(e, b"name").unwrap());
+                if let Some(v) = get_attribute(e, b"localSheetId") {
+                    defined_name.set_local_sheet_id(v.parse::<u32>().unwrap_or(0));
+                }
+                if let Some(v) = get_attribute(e, b"hidden") {
+                    defined_name.set_hidden(v == "1" || v == "true");
+                }
+                if let Some(v) = get_attribute(e, b"comment") {
+                    defined_name.set_comment(v);
+                }
             }
         },
         Event::Text(e) => string_value = e.unescape().unwrap().to_string(),
         Event::End(ref e) => {
             if e.name().into_inner() == b"definedName" {
-                let mut defined_name = DefinedName::default();
-                defined_name.set_name(defined_name_value);
                 defined_name.set_address(string_value);
-                defined_name.set_is_local_only(is_local_only);
                 defined_names.push(defined_name);
 
-                defined_name_value = String::from("");
+                defined_name = DefinedName::default();
                 string_value = String::from("");
-                is_local_only = false;
             }
         },
         Event::Eof => break
     );
 
-    for sheet in spreadsheet.get_sheet_collection_mut() {
-        for defined_name in &defined_names {
-            let def_sheet_name = defined_name.get_address_obj().get_sheet_name();
-            if sheet.get_name() == def_sheet_name {
-                sheet.add_defined_names(defined_name.clone());
+    // names scoped to a sheet or addressing a single sheet belong to the worksheet.
+    for defined_name in defined_names {
+        let sheet_index = match defined_name.get_local_sheet_id() {
+            Some(v) => Some(v as usize),
+            None if defined_name.is_address() => {
+                let def_sheet_name = defined_name.get_address_obj().get_sheet_name();
+                spreadsheet
+                    .get_sheet_collection_no_check()
+                    .iter()
+                    .position(|sheet| sheet.get_name() == def_sheet_name)
             }
+            None => None,
+        };
+        match sheet_index.and_then(|v| spreadsheet.get_sheet_collection_mut().get_mut(v)) {
+            Some(sheet) => sheet.add_defined_names(defined_name),
+            None => spreadsheet.get_defined_names_mut().push(defined_name),
         }
     }
     Ok(spreadsheet)
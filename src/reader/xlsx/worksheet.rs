@@ -4,7 +4,6 @@ use quick_xml::events::Event;
 use quick_xml::Reader;
 
 use structs::drawing::Theme;
-use structs::raw::RawRelationships;
 use structs::raw::RawWorksheet;
 use structs::Cells;
 use structs::Columns;
@@ -15,6 +14,7 @@ use structs::OleObjects;
 use structs::Row;
 use structs::SharedStringTable;
 use structs::SheetProtection;
+use structs::SparklineGroups;
 use structs::Stylesheet;
 use structs::Worksheet;
 
@@ -29,10 +29,18 @@ pub(crate) fn read(
     let mut reader = Reader::from_reader(data);
     reader.trim_text(true);
 
+    // depth of the elements that are not read by a struct. (worksheet = 0)
+    let mut depth: usize = 0;
+
     xml_read_loop!(
         reader,
         Event::Start(ref e) => match e.name().into_inner() {
+            b"worksheet" => {
+                read_namespaces(worksheet, e);
+                depth += 1;
+            }
             b"sheetPr" => {
+                depth += 1;
                 for a in e.attributes().with_checks(false) {
                     match a {
                         Ok(ref attr) if attr.key.0 == b"codeName" => {
@@ -54,6 +62,7 @@ pub(crate) fn read(
                     .set_attributes(&mut reader, e);
             }
             b"selection" => {
+                depth += 1;
                 for a in e.attributes().with_checks(false) {
                     match a {
                         Ok(ref attr) if attr.key.0 == b"activeCell" => {
@@ -77,6 +86,7 @@ pub(crate) fn read(
                 worksheet.set_row_dimension(obj);
             }
             b"autoFilter" => {
+                depth += 1;
                 worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
             }
             b"cols" => {
@@ -123,7 +133,59 @@ pub(crate) fn read(
                     .get_column_breaks_mut()
                     .set_attributes(&mut reader, e);
             }
-            _ => (),
+            b"x14:sparklineGroups" => {
+                let mut obj = SparklineGroups::default();
+                obj.set_attributes(&mut reader, e);
+                worksheet.set_sparkline_groups(obj);
+            }
+            b"x14:dataValidations" => {
+                // the data validations are added to those of the worksheet.
+                if worksheet.get_data_validations().is_none() {
+                    worksheet.set_data_validations(DataValidations::default());
+                }
+                worksheet
+                    .get_data_validations_mut()
+                    .as_mut()
+                    .unwrap()
+                    .set_attributes(&mut reader, e);
+            }
+            b"x14:conditionalFormatting" => {
+                let xml = read_raw_xml(&mut reader, e, false);
+                let mut x14_reader = Reader::from_str(&xml);
+                x14_reader.trim_text(true);
+                let mut obj = ConditionalFormatting::default();
+                let mut buf = Vec::new();
+                let is_supported = match x14_reader.read_event_into(&mut buf) {
+                    Ok(Event::Start(ref e)) => obj.set_attributes_x14(&mut x14_reader, e),
+                    _ => false,
+                };
+                if !is_supported || !worksheet.add_conditional_formatting_x14(obj) {
+                    add_unknown_element(worksheet, "x14:conditionalFormatting".to_string(), xml);
+                }
+            }
+            b"ext" if depth == 2 => {
+                match get_attribute(e, b"uri").as_deref() {
+                    Some(SPARKLINE_GROUPS_URI)
+                    | Some(CONDITIONAL_FORMATTINGS_URI)
+                    | Some(DATA_VALIDATIONS_URI) => {
+                        depth += 1;
+                    }
+                    _ => {
+                        add_unknown_extension(worksheet, read_raw_xml(&mut reader, e, false));
+                    }
+                }
+            }
+            v => {
+                if depth == 1 && is_unknown_element(v) {
+                    let name = String::from_utf8_lossy(v).to_string();
+                    add_unknown_element(worksheet, name, read_raw_xml(&mut reader, e, false));
+                } else {
+                    depth += 1;
+                }
+            }
+        },
+        Event::End(_) => {
+            depth = depth.saturating_sub(1);
         },
         Event::Empty(ref e) => match e.name().into_inner() {
             b"sheetPr" => {
@@ -180,8 +242,10 @@ pub(crate) fn read(
                     .set_attributes(&mut reader, e);
             }
             b"hyperlink" => {
-                let (coor, hyperlink) = get_hyperlink(e, raw_data_of_worksheet.get_worksheet_relationships());
-                let _ = worksheet.get_cell_mut(coor).set_hyperlink(hyperlink);
+                let mut obj = Hyperlink::default();
+                let coordinate =
+                    obj.set_attributes(e, raw_data_of_worksheet.get_worksheet_relationships());
+                worksheet.get_cell_mut(coordinate).set_hyperlink(obj);
             }
             b"printOptions" => {
                 worksheet
@@ -203,7 +267,15 @@ pub(crate) fn read(
                 );
                 worksheet.set_sheet_protection(obj);
             }
-            _ => (),
+            b"ext" if depth == 2 => {
+                add_unknown_extension(worksheet, read_raw_xml(&mut reader, e, true));
+            }
+            v => {
+                if depth == 1 && is_unknown_element(v) {
+                    let name = String::from_utf8_lossy(v).to_string();
+                    add_unknown_element(worksheet, name, read_raw_xml(&mut reader, e, true));
+                }
+            }
         },
         Event::Eof => break,
     );
@@ -211,6 +283,98 @@ pub(crate) fn read(
     Ok(())
 }
 
+const SPARKLINE_GROUPS_URI: &str = "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}";
+const CONDITIONAL_FORMATTINGS_URI: &str = "{78C0D931-6437-407d-A8EE-F0AAD7539E65}";
+const DATA_VALIDATIONS_URI: &str = "{CCE6A557-97BC-4b89-ADB6-D9C93CAAB3DF}";
+
+/// Elements of the worksheet written from the model.
+const MODEL_ELEMENTS: &[&[u8]] = &[
+    b"sheetPr",
+    b"dimension",
+    b"sheetViews",
+    b"sheetFormatPr",
+    b"cols",
+    b"sheetData",
+    b"sheetProtection",
+    b"autoFilter",
+    b"mergeCells",
+    b"conditionalFormatting",
+    b"dataValidations",
+    b"hyperlinks",
+    b"printOptions",
+    b"pageMargins",
+    b"pageSetup",
+    b"headerFooter",
+    b"rowBreaks",
+    b"colBreaks",
+    b"drawing",
+    b"legacyDrawing",
+    b"oleObjects",
+    b"mc:AlternateContent",
+    b"tableParts",
+    b"extLst",
+];
+
+/// Elements that point to other parts by relationship id.
+/// Those parts are not kept, so the elements are not kept either.
+const RELATIONSHIP_ELEMENTS: &[&[u8]] = &[
+    b"legacyDrawingHF",
+    b"drawingHF",
+    b"picture",
+    b"controls",
+    b"customProperties",
+];
+
+fn is_unknown_element(name: &[u8]) -> bool {
+    !MODEL_ELEMENTS.contains(&name) && !RELATIONSHIP_ELEMENTS.contains(&name)
+}
+
+/// Whether the xml points to another part by relationship id. ex) `<x14:slicer r:id="rId2"/>`
+/// The relationships of the worksheet are written again, so such xml is not kept.
+fn has_relationship_id(xml: &str) -> bool {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                let is_match = e
+                    .attributes()
+                    .with_checks(false)
+                    .flatten()
+                    .any(|a| a.key.into_inner().ends_with(b":id"));
+                if is_match {
+                    return true;
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => (),
+        }
+    }
+}
+
+fn add_unknown_element(worksheet: &mut Worksheet, name: String, xml: String) {
+    if !has_relationship_id(&xml) {
+        worksheet.add_unknown_element(name, xml);
+    }
+}
+
+fn add_unknown_extension(worksheet: &mut Worksheet, xml: String) {
+    if !has_relationship_id(&xml) {
+        worksheet.add_unknown_extension(xml);
+    }
+}
+
+fn read_namespaces(worksheet: &mut Worksheet, e: &quick_xml::events::BytesStart) {
+    for a in e.attributes().with_checks(false).flatten() {
+        let key = String::from_utf8_lossy(a.key.into_inner()).to_string();
+        if !key.starts_with("xmlns:") {
+            continue;
+        }
+        if let Ok(v) = get_attribute_value(&a) {
+            worksheet.add_namespace(key, v);
+        }
+    }
+}
+
 pub(crate) fn read_lite(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
@@ -255,28 +419,3 @@ pub(crate) fn read_lite(
 
     Ok(cells)
 }
-
-fn get_hyperlink(
-    e: &quick_xml::events::BytesStart<'_>,
-    raw_relationships: Option<&RawRelationships>,
-) -> (String, Hyperlink) {
-    let mut hyperlink = Hyperlink::default();
-    let mut rid = String::from("");
-
-    let coordition = get_attribute(e, b"ref").unwrap_or_default();
-    match get_attribute(e, b"location") {
-        Some(v) => {
-            let _ = hyperlink.set_url(v);
-            let _ = hyperlink.set_location(true);
-        }
-        None => {}
-    }
-    match get_attribute(e, b"r:id") {
-        Some(v) => {
-            let relationship = raw_relationships.unwrap().get_relationship_by_rid(&v);
-            hyperlink.set_url(relationship.get_target());
-        }
-        None => {}
-    }
-    (coordition, hyperlink)
-}
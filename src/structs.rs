@@ -319,6 +319,15 @@ pub use self::chart::*;
 mod chart_type;
 pub use self::chart_type::*;
 
+mod chart_ex;
+pub use self::chart_ex::*;
+
+mod chart_ex_type;
+pub use self::chart_ex_type::*;
+
+mod chartsheet;
+pub use self::chartsheet::*;
+
 mod merge_cells;
 pub(crate) use self::merge_cells::*;
 
@@ -388,9 +397,21 @@ pub use self::conditional_format_value_object_values::*;
 mod data_bar;
 pub use self::data_bar::*;
 
+mod data_bar_direction_values;
+pub use self::data_bar_direction_values::*;
+
+mod data_bar_axis_position_values;
+pub use self::data_bar_axis_position_values::*;
+
 mod icon_set;
 pub use self::icon_set::*;
 
+mod icon_set_values;
+pub use self::icon_set_values::*;
+
+mod conditional_formatting_icon;
+pub use self::conditional_formatting_icon::*;
+
 mod formula;
 pub use self::formula::*;
 
@@ -417,3 +438,93 @@ pub use self::sheet_protection::*;
 
 mod workbook_protection;
 pub use self::workbook_protection::*;
+
+mod sparkline_type_values;
+pub use self::sparkline_type_values::*;
+
+mod sparkline_axis_min_max_values;
+pub use self::sparkline_axis_min_max_values::*;
+
+mod display_empty_cells_as_values;
+pub use self::display_empty_cells_as_values::*;
+
+mod sparkline;
+pub use self::sparkline::*;
+
+mod sparkline_group;
+pub use self::sparkline_group::*;
+
+mod sparkline_groups;
+pub use self::sparkline_groups::*;
+
+mod effective_style;
+pub use self::effective_style::*;
+
+mod data_validation_error_style_values;
+pub use self::data_validation_error_style_values::*;
+
+mod data_validation_ime_mode_values;
+pub use self::data_validation_ime_mode_values::*;
+
+mod data_validation_violation;
+pub use self::data_validation_violation::*;
+
+mod person;
+pub use self::person::*;
+
+mod threaded_comment_mention;
+pub use self::threaded_comment_mention::*;
+
+mod threaded_comment;
+pub use self::threaded_comment::*;
+
+mod note_option;
+pub use self::note_option::*;
+
+mod builtin_cell_style_values;
+pub use self::builtin_cell_style_values::*;
+
+mod named_cell_style;
+pub use self::named_cell_style::*;
+
+mod xlsx_writer_option;
+pub use self::xlsx_writer_option::*;
+
+mod calculation_mode_values;
+pub use self::calculation_mode_values::*;
+
+mod calculation_properties;
+pub use self::calculation_properties::*;
+
+mod cell_error_type;
+pub use self::cell_error_type::*;
+
+mod cell_data;
+pub use self::cell_data::*;
+
+mod cell_formula_values;
+pub use self::cell_formula_values::*;
+
+mod cell_formula;
+pub use self::cell_formula::*;
+
+mod custom_property_value;
+pub use self::custom_property_value::*;
+
+mod custom_property;
+pub use self::custom_property::*;
+
+mod app_properties;
+pub use self::app_properties::*;
+
+mod external_cell;
+pub use self::external_cell::*;
+
+mod external_sheet_data;
+pub use self::external_sheet_data::*;
+
+mod external_defined_name;
+pub use self::external_defined_name::*;
+
+mod external_link;
+pub use self::external_link::*;
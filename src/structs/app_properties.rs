@@ -0,0 +1,125 @@
+/// Extended properties of the document. (docProps/app.xml)
+/// `TitlesOfParts` and `HeadingPairs` are made from the sheets and the defined names when writing.
+#[derive(Clone, Debug)]
+pub struct AppProperties {
+    application: String,
+    app_version: String,
+    company: String,
+    manager: String,
+    hyperlink_base: String,
+    doc_security: u32,
+    scale_crop: bool,
+    links_up_to_date: bool,
+    shared_doc: bool,
+    hyperlinks_changed: bool,
+}
+impl Default for AppProperties {
+    fn default() -> Self {
+        Self {
+            application: String::from("Microsoft Excel"),
+            app_version: String::from("14.0300"),
+            company: String::from(""),
+            manager: String::from(""),
+            hyperlink_base: String::from(""),
+            doc_security: 0,
+            scale_crop: false,
+            links_up_to_date: false,
+            shared_doc: false,
+            hyperlinks_changed: false,
+        }
+    }
+}
+impl AppProperties {
+    pub fn get_application(&self) -> &str {
+        &self.application
+    }
+
+    pub fn set_application<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.application = value.into();
+        self
+    }
+
+    /// Get the version of the application. ex) `"16.0300"`
+    pub fn get_app_version(&self) -> &str {
+        &self.app_version
+    }
+
+    pub fn set_app_version<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.app_version = value.into();
+        self
+    }
+
+    pub fn get_company(&self) -> &str {
+        &self.company
+    }
+
+    pub fn set_company<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.company = value.into();
+        self
+    }
+
+    pub fn get_manager(&self) -> &str {
+        &self.manager
+    }
+
+    pub fn set_manager<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.manager = value.into();
+        self
+    }
+
+    /// Get the base of the relative hyperlinks.
+    pub fn get_hyperlink_base(&self) -> &str {
+        &self.hyperlink_base
+    }
+
+    pub fn set_hyperlink_base<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.hyperlink_base = value.into();
+        self
+    }
+
+    /// Get the security level. 1: password protected, 2: read-only recommended, 4: read-only enforced, 8: locked for annotations.
+    pub fn get_doc_security(&self) -> &u32 {
+        &self.doc_security
+    }
+
+    pub fn set_doc_security(&mut self, value: u32) -> &mut Self {
+        self.doc_security = value;
+        self
+    }
+
+    pub fn get_scale_crop(&self) -> &bool {
+        &self.scale_crop
+    }
+
+    pub fn set_scale_crop(&mut self, value: bool) -> &mut Self {
+        self.scale_crop = value;
+        self
+    }
+
+    pub fn get_links_up_to_date(&self) -> &bool {
+        &self.links_up_to_date
+    }
+
+    pub fn set_links_up_to_date(&mut self, value: bool) -> &mut Self {
+        self.links_up_to_date = value;
+        self
+    }
+
+    pub fn get_shared_doc(&self) -> &bool {
+        &self.shared_doc
+    }
+
+    pub fn set_shared_doc(&mut self, value: bool) -> &mut Self {
+        self.shared_doc = value;
+        self
+    }
+
+    pub fn get_hyperlinks_changed(&self) -> &bool {
+        &self.hyperlinks_changed
+    }
+
+    pub fn set_hyperlinks_changed(&mut self, value: bool) -> &mut Self {
+        self.hyperlinks_changed = value;
+        self
+    }
+}
@@ -0,0 +1,281 @@
+use super::Border;
+use super::NumberingFormat;
+use super::Style;
+
+/// The built-in cell styles of Excel.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum BuiltinCellStyleValues {
+    Normal,
+    Comma,
+    Currency,
+    Percent,
+    CommaZero,
+    CurrencyZero,
+    Hyperlink,
+    FollowedHyperlink,
+    Note,
+    WarningText,
+    Title,
+    Heading1,
+    Heading2,
+    Heading3,
+    Heading4,
+    Input,
+    Output,
+    Calculation,
+    CheckCell,
+    LinkedCell,
+    Total,
+    Good,
+    Bad,
+    Neutral,
+    ExplanatoryText,
+}
+impl BuiltinCellStyleValues {
+    /// Get all the built-in cell styles.
+    pub fn get_list() -> Vec<Self> {
+        vec![
+            Self::Normal,
+            Self::Comma,
+            Self::Currency,
+            Self::Percent,
+            Self::CommaZero,
+            Self::CurrencyZero,
+            Self::Hyperlink,
+            Self::FollowedHyperlink,
+            Self::Note,
+            Self::WarningText,
+            Self::Title,
+            Self::Heading1,
+            Self::Heading2,
+            Self::Heading3,
+            Self::Heading4,
+            Self::Input,
+            Self::Output,
+            Self::Calculation,
+            Self::CheckCell,
+            Self::LinkedCell,
+            Self::Total,
+            Self::Good,
+            Self::Bad,
+            Self::Neutral,
+            Self::ExplanatoryText,
+        ]
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Comma => "Comma",
+            Self::Currency => "Currency",
+            Self::Percent => "Percent",
+            Self::CommaZero => "Comma [0]",
+            Self::CurrencyZero => "Currency [0]",
+            Self::Hyperlink => "Hyperlink",
+            Self::FollowedHyperlink => "Followed Hyperlink",
+            Self::Note => "Note",
+            Self::WarningText => "Warning Text",
+            Self::Title => "Title",
+            Self::Heading1 => "Heading 1",
+            Self::Heading2 => "Heading 2",
+            Self::Heading3 => "Heading 3",
+            Self::Heading4 => "Heading 4",
+            Self::Input => "Input",
+            Self::Output => "Output",
+            Self::Calculation => "Calculation",
+            Self::CheckCell => "Check Cell",
+            Self::LinkedCell => "Linked Cell",
+            Self::Total => "Total",
+            Self::Good => "Good",
+            Self::Bad => "Bad",
+            Self::Neutral => "Neutral",
+            Self::ExplanatoryText => "Explanatory Text",
+        }
+    }
+
+    /// Get the builtinId of the cell style.
+    pub fn get_builtin_id(&self) -> u32 {
+        match self {
+            Self::Normal => 0,
+            Self::Comma => 3,
+            Self::Currency => 4,
+            Self::Percent => 5,
+            Self::CommaZero => 6,
+            Self::CurrencyZero => 7,
+            Self::Hyperlink => 8,
+            Self::FollowedHyperlink => 9,
+            Self::Note => 10,
+            Self::WarningText => 11,
+            Self::Title => 15,
+            Self::Heading1 => 16,
+            Self::Heading2 => 17,
+            Self::Heading3 => 18,
+            Self::Heading4 => 19,
+            Self::Input => 20,
+            Self::Output => 21,
+            Self::Calculation => 22,
+            Self::CheckCell => 23,
+            Self::LinkedCell => 24,
+            Self::Total => 25,
+            Self::Good => 26,
+            Self::Bad => 27,
+            Self::Neutral => 28,
+            Self::ExplanatoryText => 53,
+        }
+    }
+
+    /// Get the formatting of the cell style in the default theme.
+    pub fn get_style(&self) -> Style {
+        let mut style = Style::default();
+        match self {
+            Self::Normal => {}
+            Self::Comma => set_number_format(
+                &mut style,
+                r#"_(* #,##0.00_);_(* \(#,##0.00\);_(* "-"??_);_(@_)"#,
+            ),
+            Self::Currency => set_number_format(
+                &mut style,
+                r#"_("$"* #,##0.00_);_("$"* \(#,##0.00\);_("$"* "-"??_);_(@_)"#,
+            ),
+            Self::Percent => set_number_format(&mut style, NumberingFormat::FORMAT_PERCENTAGE),
+            Self::CommaZero => {
+                set_number_format(&mut style, r#"_(* #,##0_);_(* \(#,##0\);_(* "-"_);_(@_)"#)
+            }
+            Self::CurrencyZero => set_number_format(
+                &mut style,
+                r#"_("$"* #,##0_);_("$"* \(#,##0\);_("$"* "-"_);_(@_)"#,
+            ),
+            Self::Hyperlink => {
+                style
+                    .get_font_mut()
+                    .set_underline(super::Font::UNDERLINE_SINGLE)
+                    .get_color_mut()
+                    .set_theme_index(10);
+            }
+            Self::FollowedHyperlink => {
+                style
+                    .get_font_mut()
+                    .set_underline(super::Font::UNDERLINE_SINGLE)
+                    .get_color_mut()
+                    .set_theme_index(11);
+            }
+            Self::Note => {
+                style.set_background_color("FFFFFFCC");
+                set_borders(&mut style, Border::BORDER_THIN, "FFB2B2B2");
+            }
+            Self::WarningText => set_font_color(&mut style, "FFFF0000"),
+            Self::Title => {
+                style
+                    .get_font_mut()
+                    .set_name_with_scheme("Calibri Light", "major")
+                    .set_size(18.0)
+                    .get_color_mut()
+                    .set_theme_index(3);
+            }
+            Self::Heading1 => set_heading(&mut style, 15.0, Border::BORDER_THICK, 0.0),
+            Self::Heading2 => set_heading(&mut style, 13.0, Border::BORDER_THICK, 0.4999),
+            Self::Heading3 => set_heading(&mut style, 11.0, Border::BORDER_MEDIUM, 0.3999),
+            Self::Heading4 => {
+                style
+                    .get_font_mut()
+                    .set_bold(true)
+                    .get_color_mut()
+                    .set_theme_index(3);
+            }
+            Self::Input => {
+                style.set_background_color("FFFFCC99");
+                set_font_color(&mut style, "FF3F3F76");
+                set_borders(&mut style, Border::BORDER_THIN, "FF7F7F7F");
+            }
+            Self::Output => {
+                style.set_background_color("FFF2F2F2");
+                set_font_color(&mut style, "FF3F3F3F");
+                style.get_font_mut().set_bold(true);
+                set_borders(&mut style, Border::BORDER_THIN, "FF3F3F3F");
+            }
+            Self::Calculation => {
+                style.set_background_color("FFF2F2F2");
+                set_font_color(&mut style, "FFFA7D00");
+                style.get_font_mut().set_bold(true);
+                set_borders(&mut style, Border::BORDER_THIN, "FF7F7F7F");
+            }
+            Self::CheckCell => {
+                style.set_background_color("FFA5A5A5");
+                style
+                    .get_font_mut()
+                    .set_bold(true)
+                    .get_color_mut()
+                    .set_theme_index(0);
+                set_borders(&mut style, Border::BORDER_DOUBLE, "FF3F3F3F");
+            }
+            Self::LinkedCell => {
+                set_font_color(&mut style, "FFFA7D00");
+                let bottom = style.get_borders_mut().get_bottom_mut();
+                bottom.set_border_style(Border::BORDER_DOUBLE);
+                bottom.get_color_mut().set_argb("FFFF8001");
+            }
+            Self::Total => {
+                style.get_font_mut().set_bold(true);
+                let top = style.get_borders_mut().get_top_mut();
+                top.set_border_style(Border::BORDER_THIN);
+                top.get_color_mut().set_theme_index(4);
+                let bottom = style.get_borders_mut().get_bottom_mut();
+                bottom.set_border_style(Border::BORDER_DOUBLE);
+                bottom.get_color_mut().set_theme_index(4);
+            }
+            Self::Good => {
+                style.set_background_color("FFC6EFCE");
+                set_font_color(&mut style, "FF006100");
+            }
+            Self::Bad => {
+                style.set_background_color("FFFFC7CE");
+                set_font_color(&mut style, "FF9C0006");
+            }
+            Self::Neutral => {
+                style.set_background_color("FFFFEB9C");
+                set_font_color(&mut style, "FF9C5700");
+            }
+            Self::ExplanatoryText => {
+                style.get_font_mut().set_italic(true);
+                set_font_color(&mut style, "FF7F7F7F");
+            }
+        }
+        style
+    }
+}
+
+fn set_number_format(style: &mut Style, format_code: &str) {
+    style.get_number_format_mut().set_format_code(format_code);
+}
+
+fn set_font_color(style: &mut Style, argb: &str) {
+    style.get_font_mut().get_color_mut().set_argb(argb);
+}
+
+fn set_borders(style: &mut Style, border_style: &str, argb: &str) {
+    let set = |border: &mut Border| {
+        border.set_border_style(border_style);
+        border.get_color_mut().set_argb(argb);
+    };
+    let borders = style.get_borders_mut();
+    set(borders.get_left_mut());
+    set(borders.get_right_mut());
+    set(borders.get_top_mut());
+    set(borders.get_bottom_mut());
+}
+
+fn set_heading(style: &mut Style, size: f64, border_style: &str, tint: f64) {
+    style
+        .get_font_mut()
+        .set_bold(true)
+        .set_size(size)
+        .get_color_mut()
+        .set_theme_index(3);
+    let bottom = style.get_borders_mut().get_bottom_mut();
+    bottom.set_border_style(border_style);
+    let color = bottom.get_color_mut();
+    color.set_theme_index(4);
+    if tint != 0.0 {
+        color.set_tint(tint);
+    }
+}
@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum CalculationModeValues {
+    Auto,
+    AutoNoTable,
+    Manual,
+}
+impl Default for CalculationModeValues {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+impl EnumTrait for CalculationModeValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Auto => "auto",
+            Self::AutoNoTable => "autoNoTable",
+            Self::Manual => "manual",
+        }
+    }
+}
+impl FromStr for CalculationModeValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "auto" => Ok(Self::Auto),
+            "autoNoTable" => Ok(Self::AutoNoTable),
+            "manual" => Ok(Self::Manual),
+            _ => Err(()),
+        }
+    }
+}
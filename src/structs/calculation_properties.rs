@@ -0,0 +1,149 @@
+// calcPr
+use super::BooleanValue;
+use super::CalculationModeValues;
+use super::DoubleValue;
+use super::EnumValue;
+use super::UInt32Value;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct CalculationProperties {
+    calculation_id: UInt32Value,
+    calculation_mode: EnumValue<CalculationModeValues>,
+    full_calculation_on_load: BooleanValue,
+    force_full_calculation: BooleanValue,
+    iterate: BooleanValue,
+    iterate_count: UInt32Value,
+    iterate_delta: DoubleValue,
+}
+
+impl CalculationProperties {
+    /// The version of the calculation engine which last calculated the workbook.
+    pub fn get_calculation_id(&self) -> &u32 {
+        self.calculation_id.get_value()
+    }
+
+    pub fn set_calculation_id(&mut self, value: u32) -> &mut Self {
+        self.calculation_id.set_value(value);
+        self
+    }
+
+    pub fn get_calculation_mode(&self) -> &CalculationModeValues {
+        self.calculation_mode.get_value()
+    }
+
+    pub fn set_calculation_mode(&mut self, value: CalculationModeValues) -> &mut Self {
+        self.calculation_mode.set_value(value);
+        self
+    }
+
+    /// Whether Excel recalculates all formulas when the workbook is opened.
+    pub fn get_full_calculation_on_load(&self) -> &bool {
+        self.full_calculation_on_load.get_value()
+    }
+
+    pub fn set_full_calculation_on_load(&mut self, value: bool) -> &mut Self {
+        self.full_calculation_on_load.set_value(value);
+        self
+    }
+
+    pub fn get_force_full_calculation(&self) -> &bool {
+        self.force_full_calculation.get_value()
+    }
+
+    pub fn set_force_full_calculation(&mut self, value: bool) -> &mut Self {
+        self.force_full_calculation.set_value(value);
+        self
+    }
+
+    /// Whether iterative calculation is enabled. (for circular references)
+    pub fn get_iterate(&self) -> &bool {
+        self.iterate.get_value()
+    }
+
+    pub fn set_iterate(&mut self, value: bool) -> &mut Self {
+        self.iterate.set_value(value);
+        self
+    }
+
+    pub fn get_iterate_count(&self) -> &u32 {
+        if self.iterate_count.has_value() {
+            return self.iterate_count.get_value();
+        }
+        &100
+    }
+
+    pub fn set_iterate_count(&mut self, value: u32) -> &mut Self {
+        self.iterate_count.set_value(value);
+        self
+    }
+
+    pub fn get_iterate_delta(&self) -> &f64 {
+        if self.iterate_delta.has_value() {
+            return self.iterate_delta.get_value();
+        }
+        &0.001
+    }
+
+    pub fn set_iterate_delta(&mut self, value: f64) -> &mut Self {
+        self.iterate_delta.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, calculation_id, "calcId");
+        set_string_from_xml!(self, e, calculation_mode, "calcMode");
+        set_string_from_xml!(self, e, full_calculation_on_load, "fullCalcOnLoad");
+        set_string_from_xml!(self, e, force_full_calculation, "forceFullCalc");
+        set_string_from_xml!(self, e, iterate, "iterate");
+        set_string_from_xml!(self, e, iterate_count, "iterateCount");
+        set_string_from_xml!(self, e, iterate_delta, "iterateDelta");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // calcPr
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let calculation_id = if self.calculation_id.has_value() {
+            self.calculation_id.get_value_string()
+        } else {
+            String::from("122211")
+        };
+        attributes.push(("calcId", &calculation_id));
+        if self.calculation_mode.has_value() {
+            attributes.push(("calcMode", self.calculation_mode.get_value_string()));
+        }
+        if self.full_calculation_on_load.has_value() {
+            attributes.push((
+                "fullCalcOnLoad",
+                self.full_calculation_on_load.get_value_string(),
+            ));
+        }
+        if self.force_full_calculation.has_value() {
+            attributes.push((
+                "forceFullCalc",
+                self.force_full_calculation.get_value_string(),
+            ));
+        }
+        if self.iterate.has_value() {
+            attributes.push(("iterate", self.iterate.get_value_string()));
+        }
+        let iterate_count = self.iterate_count.get_value_string();
+        if self.iterate_count.has_value() {
+            attributes.push(("iterateCount", &iterate_count));
+        }
+        let iterate_delta = self.iterate_delta.get_value_string();
+        if self.iterate_delta.has_value() {
+            attributes.push(("iterateDelta", &iterate_delta));
+        }
+        write_start_tag(writer, "calcPr", attributes, true);
+    }
+}
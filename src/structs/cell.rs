@@ -1,3 +1,5 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use helper::date::*;
 use helper::number_format::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -6,6 +8,10 @@ use reader::driver::*;
 use std::borrow::Cow;
 use std::io::Cursor;
 use std::sync::{Arc, RwLock};
+use structs::CellData;
+use structs::CellErrorType;
+use structs::CellFormula;
+use structs::CellFormulaValues;
 use structs::CellRawValue;
 use structs::CellValue;
 use structs::Coordinate;
@@ -24,6 +30,7 @@ pub struct Cell {
     pub(crate) cell_value: CellValue,
     style: Style,
     hyperlink: Option<Hyperlink>,
+    date1904: bool,
 }
 impl Cell {
     pub fn get_cell_value(&self) -> &CellValue {
@@ -78,6 +85,20 @@ impl Cell {
         self
     }
 
+    pub fn remove_hyperlink(&mut self) -> &mut Self {
+        self.hyperlink = None;
+        self
+    }
+
+    /// Get the hyperlink of the `HYPERLINK()` formula of the cell.
+    pub fn get_formula_hyperlink(&self) -> Option<Hyperlink> {
+        Hyperlink::from_formula(self.cell_value.get_formula())
+    }
+
+    pub(crate) fn get_hyperlink_crate_mut(&mut self) -> Option<&mut Hyperlink> {
+        self.hyperlink.as_mut()
+    }
+
     pub fn get_value(&self) -> Cow<'static, str> {
         self.cell_value.get_value()
     }
@@ -136,6 +157,85 @@ impl Cell {
         self
     }
 
+    /// Set a date time as a serial date of the date system of the workbook.
+    /// A cell out of a worksheet uses the 1900 date system.
+    /// A date format is set unless the cell already has one.
+    pub fn set_value_datetime(&mut self, value: NaiveDateTime) -> &mut Self {
+        let date1904 = self.date1904;
+        self.set_value_datetime_crate(value, date1904)
+    }
+
+    /// (This method is crate only.)
+    pub(crate) fn set_value_datetime_crate(
+        &mut self,
+        value: NaiveDateTime,
+        date1904: bool,
+    ) -> &mut Self {
+        let format_code = if value.nanosecond() >= 1_000_000 {
+            NumberingFormat::FORMAT_DATE_YYYYMMDD_HHMMSS_000
+        } else {
+            NumberingFormat::FORMAT_DATE_YYYYMMDD_HHMMSS
+        };
+        self.set_date_format(format_code);
+        self.set_value_number(date_time_to_excel(&value, date1904))
+    }
+
+    /// Set a date as a serial date of the date system of the workbook.
+    /// A cell out of a worksheet uses the 1900 date system.
+    /// A date format is set unless the cell already has one.
+    pub fn set_value_date(&mut self, value: NaiveDate) -> &mut Self {
+        let date1904 = self.date1904;
+        self.set_value_date_crate(value, date1904)
+    }
+
+    /// (This method is crate only.)
+    pub(crate) fn set_value_date_crate(&mut self, value: NaiveDate, date1904: bool) -> &mut Self {
+        self.set_date_format(NumberingFormat::FORMAT_DATE_YYYYMMDD2);
+        self.set_value_number(date_time_to_excel(
+            &value.and_time(NaiveTime::MIN),
+            date1904,
+        ))
+    }
+
+    /// Set a duration as a number of days with an elapsed time format. ex) 26:30:00
+    pub fn set_value_duration(&mut self, value: Duration) -> &mut Self {
+        self.set_date_format(NumberingFormat::FORMAT_DURATION);
+        self.set_value_number(duration_to_excel(&value))
+    }
+
+    /// Get the date time of a numeric cell with a date format.
+    /// The value is read as a serial date of the date system of the workbook.
+    /// A cell out of a worksheet uses the 1900 date system.
+    pub fn get_value_datetime(&self) -> Option<NaiveDateTime> {
+        self.get_value_datetime_crate(self.date1904)
+    }
+
+    /// (This method is crate only.)
+    pub(crate) fn get_value_datetime_crate(&self, date1904: bool) -> Option<NaiveDateTime> {
+        let serial = self.get_value_number()?;
+        let format_code = self
+            .get_style()
+            .get_number_format()
+            .as_ref()?
+            .get_format_code();
+        if !is_date_format(format_code) {
+            return None;
+        }
+        excel_to_date_time(&serial, date1904)
+    }
+
+    fn set_date_format(&mut self, format_code: &str) {
+        let is_date = match self.get_style().get_number_format() {
+            Some(v) => is_date_format(v.get_format_code()),
+            None => false,
+        };
+        if !is_date {
+            self.get_style_mut()
+                .get_number_format_mut()
+                .set_format_code(format_code);
+        }
+    }
+
     pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
         self.cell_value.set_rich_text(value);
         self
@@ -146,6 +246,62 @@ impl Cell {
         self
     }
 
+    /// Set the error value. ex) `#N/A`, `#DIV/0!`
+    pub fn set_value_error(&mut self, value: CellErrorType) -> &mut Self {
+        self.cell_value.set_value_error(value);
+        self
+    }
+
+    pub fn get_value_error(&self) -> Option<&CellErrorType> {
+        self.cell_value.get_value_error()
+    }
+
+    /// Get the typed value of the cell.
+    /// A number with a date format is read as a date of the date system of the workbook.
+    /// A cell out of a worksheet uses the 1900 date system.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut cell = Cell::default();
+    /// cell.set_value_error(CellErrorType::NA);
+    /// assert_eq!(cell.get_typed_value(), CellData::Error(CellErrorType::NA));
+    /// ```
+    pub fn get_typed_value(&self) -> CellData {
+        self.get_typed_value_crate(self.date1904)
+    }
+
+    /// (This method is crate only.)
+    /// Set the date system of the workbook. The value follows the worksheet of the cell.
+    pub(crate) fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    pub(crate) fn get_typed_value_crate(&self, date1904: bool) -> CellData {
+        let raw_value = match self.get_raw_value() {
+            CellRawValue::Lazy(v) => CellValue::guess_typed_data(v),
+            v => v.clone(),
+        };
+        match raw_value {
+            CellRawValue::String(v) | CellRawValue::Str(v) | CellRawValue::Lazy(v) => {
+                if v.is_empty() {
+                    CellData::Empty
+                } else {
+                    CellData::Text(v)
+                }
+            }
+            CellRawValue::RichText(v) => CellData::RichText(v),
+            CellRawValue::Numeric(v) => match self.get_value_datetime_crate(date1904) {
+                Some(date) => CellData::Date(date),
+                None => CellData::Number(v),
+            },
+            CellRawValue::Bool(v) => CellData::Bool(v),
+            CellRawValue::Error(v) => CellData::Error(v),
+            CellRawValue::Inline | CellRawValue::Null => CellData::Empty,
+        }
+    }
+
     pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.cell_value.set_formula(value);
         self
@@ -176,12 +332,53 @@ impl Cell {
         self.cell_value.get_formula()
     }
 
-    pub(crate) fn get_formula_attributes(&self) -> Vec<(&str, &str)> {
-        self.cell_value.get_formula_attributes()
+    pub fn get_cell_formula(&self) -> Option<&CellFormula> {
+        self.cell_value.get_cell_formula()
+    }
+
+    pub fn get_cell_formula_mut(&mut self) -> Option<&mut CellFormula> {
+        self.cell_value.get_cell_formula_mut()
+    }
+
+    pub fn set_cell_formula(&mut self, value: CellFormula) -> &mut Self {
+        self.cell_value.set_cell_formula(value);
+        self
+    }
+
+    /// Set an array formula. (CSE formula)
+    /// # Arguments
+    /// * `value` - Formula without `=`. ex) `"A1:A3*B1:B3"`
+    /// * `reference` - Range of the result. ex) `"C1:C3"`
+    pub fn set_formula_array<S: Into<String>, R: Into<String>>(
+        &mut self,
+        value: S,
+        reference: R,
+    ) -> &mut Self {
+        let mut formula = CellFormula::default();
+        formula
+            .set_formula_type(CellFormulaValues::Array)
+            .set_text(value)
+            .set_reference(reference);
+        self.set_cell_formula(formula)
+    }
+
+    /// Set a dynamic array formula. The result spills into the reference.
+    /// # Arguments
+    /// * `value` - Formula without `=`. ex) `"FILTER(A1:A10,B1:B10>0)"`
+    /// * `reference` - Range of the spilled result. ex) `"C1:C10"`
+    pub fn set_formula_dynamic_array<S: Into<String>, R: Into<String>>(
+        &mut self,
+        value: S,
+        reference: R,
+    ) -> &mut Self {
+        self.set_formula_array(value, reference);
+        self.get_cell_formula_mut().unwrap().set_dynamic_array(true);
+        self
     }
 
-    pub(crate) fn set_formula_attributes(&mut self, attributes: Vec<(String, String)>) {
-        self.cell_value.set_formula_attributes(attributes);
+    pub(crate) fn is_dynamic_array_formula(&self) -> bool {
+        self.get_cell_formula()
+            .map_or(false, |v| *v.get_dynamic_array())
     }
 
     pub(crate) fn get_width_point(&self, column_font_size: &f64) -> f64 {
@@ -221,14 +418,43 @@ impl Cell {
     }
 
     pub(crate) fn get_formatted_value(&self) -> String {
-        let value = self.get_value();
+        self.get_formatted_value_by_calendar(CALENDAR_WINDOWS_1900)
+    }
+
+    /// (This method is crate only.)
+    /// Get the formatted value with the date system of the workbook.
+    pub(crate) fn get_formatted_value_by_calendar(&self, calendar: &str) -> String {
+        self.get_formatted_value_by_locale(&NumberFormatLocale::default(), calendar)
+            .get_text()
+            .trim()
+            .to_string()
+    }
 
-        // convert value
-        let result = match self.get_style().get_number_format() {
-            Some(nmuber_format) => to_formatted_string(&value, nmuber_format.get_format_code()),
-            None => to_formatted_string(&value, NumberingFormat::FORMAT_GENERAL),
+    /// (This method is crate only.)
+    /// Get the formatted value and the color of the format with the locale.
+    pub(crate) fn get_formatted_value_by_locale(
+        &self,
+        locale: &NumberFormatLocale,
+        calendar: &str,
+    ) -> FormattedValue {
+        let format = match self.get_style().get_number_format() {
+            Some(v) => v.get_format_code(),
+            None => NumberingFormat::FORMAT_GENERAL,
         };
-        result
+        match self.get_raw_value() {
+            CellRawValue::Numeric(v) => format_number(*v, format, locale, calendar),
+            CellRawValue::Bool(_) | CellRawValue::Error(_) => format_text(&self.get_value(), ""),
+            CellRawValue::String(_) | CellRawValue::RichText(_) | CellRawValue::Str(_) => {
+                format_text(&self.get_value(), format)
+            }
+            _ => {
+                let value = self.get_value();
+                match value.parse::<f64>() {
+                    Ok(v) if !value.is_empty() => format_number(v, format, locale, calendar),
+                    _ => format_text(&value, format),
+                }
+            }
+        }
     }
 
     pub(crate) fn set_obj(&mut self, cell: Self) -> &mut Self {
@@ -261,6 +487,9 @@ impl Cell {
             type_value = v;
         }
 
+        // cell metadata. written only for the dynamic array formulas.
+        let is_dynamic_array = get_attribute(e, b"cm").is_some();
+
         if empty_flag {
             return;
         }
@@ -272,18 +501,10 @@ impl Cell {
                 Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
                 Ok(Event::Start(ref s)) => match s.name().into_inner() {
                     b"f" => {
-                        let mut attrs = vec![];
-                        s.attributes().for_each(|a| {
-                            if let Ok(attribute) = a {
-                                if let (Ok(key), Ok(value)) = (
-                                    std::str::from_utf8(attribute.key.into_inner()),
-                                    std::str::from_utf8(attribute.value.as_ref()),
-                                ) {
-                                    attrs.push((key.to_owned(), value.to_owned()));
-                                }
-                            }
-                        });
-                        self.set_formula_attributes(attrs);
+                        let mut obj = CellFormula::default();
+                        obj.set_attributes(reader, s, false);
+                        obj.set_dynamic_array(is_dynamic_array && obj.is_array());
+                        self.set_cell_formula(obj);
                     }
                     b"t" => {
                         if let Some(Ok(attribute)) = s.attributes().next() {
@@ -298,24 +519,13 @@ impl Cell {
                 },
                 Ok(Event::Empty(ref s)) => {
                     if s.name().into_inner() == b"f" {
-                        let mut attrs = vec![];
-                        s.attributes().for_each(|a| {
-                            if let Ok(attribute) = a {
-                                if let (Ok(key), Ok(value)) = (
-                                    std::str::from_utf8(attribute.key.into_inner()),
-                                    std::str::from_utf8(attribute.value.as_ref()),
-                                ) {
-                                    attrs.push((key.to_owned(), value.to_owned()));
-                                }
-                            }
-                        });
-                        self.set_formula_attributes(attrs);
+                        let mut obj = CellFormula::default();
+                        obj.set_attributes(reader, s, true);
+                        obj.set_dynamic_array(is_dynamic_array && obj.is_array());
+                        self.set_cell_formula(obj);
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"f" => {
-                        self.set_formula(string_value.clone());
-                    }
                     b"v" => {
                         if type_value == "str" {
                             self.set_value_str(string_value.clone());
@@ -331,7 +541,8 @@ impl Cell {
                             let prm = &string_value == "1";
                             let _ = self.set_value_bool(prm);
                         } else if type_value == "e" {
-                            let _ = self.set_error();
+                            let value = string_value.parse::<CellErrorType>().unwrap_or_default();
+                            self.cell_value.set_value_error_crate(value);
                         } else if type_value.is_empty() || type_value == "n" {
                             let _ = self.set_value_crate(string_value.clone());
                         };
@@ -384,21 +595,15 @@ impl Cell {
             xf_index_str = xf_index.to_string();
             attributes.push(("s", &xf_index_str));
         }
+        if self.is_dynamic_array_formula() {
+            attributes.push(("cm", "1"));
+        }
 
         if !empty_flag_value {
             write_start_tag(writer, "c", attributes, false);
             // f
-            match &self.cell_value.formula {
-                Some(v) => {
-                    write_start_tag(writer, "f", self.get_formula_attributes(), false);
-                    write_text_node(writer, v);
-                    write_end_tag(writer, "f");
-                }
-                None => {
-                    if !self.get_formula_attributes().is_empty() {
-                        write_start_tag(writer, "f", self.get_formula_attributes(), true);
-                    }
-                }
+            if let Some(v) = &self.cell_value.formula {
+                v.write_to(writer);
             }
 
             // v
@@ -419,10 +624,6 @@ impl Cell {
                         let prm = if upper_value == "TRUE" { "1" } else { "0" };
                         write_text_node(writer, prm);
                     }
-                    "e" => {
-                        let prm = "#VALUE!";
-                        write_text_node(writer, prm);
-                    }
                     _ => write_text_node(writer, self.get_value()),
                 }
                 write_end_tag(writer, "v");
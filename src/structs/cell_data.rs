@@ -0,0 +1,61 @@
+use super::CellErrorType;
+use super::RichText;
+use chrono::NaiveDateTime;
+
+/// Typed value of a cell.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellData {
+    Empty,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(CellErrorType),
+    /// Number with a date format.
+    Date(NaiveDateTime),
+    RichText(RichText),
+}
+impl Default for CellData {
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+impl CellData {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    pub fn get_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_error(&self) -> Option<&CellErrorType> {
+        match self {
+            Self::Error(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get_date(&self) -> Option<&NaiveDateTime> {
+        match self {
+            Self::Date(v) => Some(v),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::EnumTrait;
+use std::fmt;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CellErrorType {
+    Div0,
+    GettingData,
+    NA,
+    Name,
+    Null,
+    Num,
+    Ref,
+    Value,
+    Spill,
+    Calc,
+}
+impl Default for CellErrorType {
+    fn default() -> Self {
+        Self::Value
+    }
+}
+impl EnumTrait for CellErrorType {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Div0 => "#DIV/0!",
+            Self::GettingData => "#GETTING_DATA",
+            Self::NA => "#N/A",
+            Self::Name => "#NAME?",
+            Self::Null => "#NULL!",
+            Self::Num => "#NUM!",
+            Self::Ref => "#REF!",
+            Self::Value => "#VALUE!",
+            Self::Spill => "#SPILL!",
+            Self::Calc => "#CALC!",
+        }
+    }
+}
+impl FromStr for CellErrorType {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_uppercase().as_str() {
+            "#DIV/0!" => Ok(Self::Div0),
+            "#GETTING_DATA" => Ok(Self::GettingData),
+            "#N/A" => Ok(Self::NA),
+            "#NAME?" => Ok(Self::Name),
+            "#NULL!" => Ok(Self::Null),
+            "#NUM!" => Ok(Self::Num),
+            "#REF!" => Ok(Self::Ref),
+            "#VALUE!" => Ok(Self::Value),
+            "#SPILL!" => Ok(Self::Spill),
+            "#CALC!" => Ok(Self::Calc),
+            _ => Err(()),
+        }
+    }
+}
+impl fmt::Display for CellErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_value_string())
+    }
+}
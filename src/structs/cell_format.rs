@@ -188,6 +188,7 @@ impl CellFormat {
         set_string_from_xml!(self, e, font_id, "fontId");
         set_string_from_xml!(self, e, fill_id, "fillId");
         set_string_from_xml!(self, e, border_id, "borderId");
+        set_string_from_xml!(self, e, format_id, "xfId");
         set_string_from_xml!(self, e, apply_number_format, "applyNumberFormat");
         set_string_from_xml!(self, e, apply_border, "applyBorder");
         set_string_from_xml!(self, e, apply_font, "applyFont");
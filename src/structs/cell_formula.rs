@@ -0,0 +1,373 @@
+// f
+use super::BooleanValue;
+use super::CellFormulaValues;
+use super::EnumValue;
+use super::StringValue;
+use helper::formula::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::Range;
+use writer::driver::*;
+
+/// Formula of a cell. Normal, shared, array (including dynamic array) and data table formulas.
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+pub struct CellFormula {
+    formula_type: EnumValue<CellFormulaValues>,
+    text: String,
+    reference: StringValue,
+    shared_index: StringValue,
+    always_calculate: BooleanValue,
+    always_calculate_array: BooleanValue,
+    assigns_to_name: BooleanValue,
+    data_table_2d: BooleanValue,
+    data_table_row: BooleanValue,
+    input1_deleted: BooleanValue,
+    input2_deleted: BooleanValue,
+    data_table_cell1: StringValue,
+    data_table_cell2: StringValue,
+    dynamic_array: bool,
+}
+impl CellFormula {
+    pub fn get_formula_type(&self) -> &CellFormulaValues {
+        self.formula_type.get_value()
+    }
+
+    pub fn set_formula_type(&mut self, value: CellFormulaValues) -> &mut Self {
+        self.formula_type.set_value(value);
+        self
+    }
+
+    /// Formula without `=`. Empty for the dependent cells of a shared formula.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text = value.into();
+        self
+    }
+
+    /// Range of the array formula or of the cells sharing the formula. ex) `A1:A10`
+    pub fn get_reference(&self) -> &str {
+        self.reference.get_value()
+    }
+
+    pub fn set_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.reference.set_value(value);
+        self
+    }
+
+    /// Index of the shared formula. (si)
+    pub fn get_shared_index(&self) -> Option<u32> {
+        if self.shared_index.has_value() {
+            self.shared_index.get_value().parse().ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn set_shared_index(&mut self, value: u32) -> &mut Self {
+        self.shared_index.set_value(value.to_string());
+        self
+    }
+
+    /// Calculate the cell every time. (ca)
+    pub fn get_always_calculate(&self) -> &bool {
+        self.always_calculate.get_value()
+    }
+
+    pub fn set_always_calculate(&mut self, value: bool) -> &mut Self {
+        self.always_calculate.set_value(value);
+        self
+    }
+
+    /// Calculate the array formula every time. (aca)
+    pub fn get_always_calculate_array(&self) -> &bool {
+        self.always_calculate_array.get_value()
+    }
+
+    pub fn set_always_calculate_array(&mut self, value: bool) -> &mut Self {
+        self.always_calculate_array.set_value(value);
+        self
+    }
+
+    /// The formula assigns a value to a name. (bx)
+    pub fn get_assigns_to_name(&self) -> &bool {
+        self.assigns_to_name.get_value()
+    }
+
+    pub fn set_assigns_to_name(&mut self, value: bool) -> &mut Self {
+        self.assigns_to_name.set_value(value);
+        self
+    }
+
+    /// Two-dimensional data table. (dt2D)
+    pub fn get_data_table_2d(&self) -> &bool {
+        self.data_table_2d.get_value()
+    }
+
+    pub fn set_data_table_2d(&mut self, value: bool) -> &mut Self {
+        self.data_table_2d.set_value(value);
+        self
+    }
+
+    /// One-dimensional data table is a row. (dtr)
+    pub fn get_data_table_row(&self) -> &bool {
+        self.data_table_row.get_value()
+    }
+
+    pub fn set_data_table_row(&mut self, value: bool) -> &mut Self {
+        self.data_table_row.set_value(value);
+        self
+    }
+
+    /// First input cell of the data table is deleted. (del1)
+    pub fn get_input1_deleted(&self) -> &bool {
+        self.input1_deleted.get_value()
+    }
+
+    pub fn set_input1_deleted(&mut self, value: bool) -> &mut Self {
+        self.input1_deleted.set_value(value);
+        self
+    }
+
+    /// Second input cell of the data table is deleted. (del2)
+    pub fn get_input2_deleted(&self) -> &bool {
+        self.input2_deleted.get_value()
+    }
+
+    pub fn set_input2_deleted(&mut self, value: bool) -> &mut Self {
+        self.input2_deleted.set_value(value);
+        self
+    }
+
+    /// First input cell of the data table. (r1)
+    pub fn get_data_table_cell1(&self) -> &str {
+        self.data_table_cell1.get_value()
+    }
+
+    pub fn set_data_table_cell1<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.data_table_cell1.set_value(value);
+        self
+    }
+
+    /// Second input cell of the data table. (r2)
+    pub fn get_data_table_cell2(&self) -> &str {
+        self.data_table_cell2.get_value()
+    }
+
+    pub fn set_data_table_cell2<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.data_table_cell2.set_value(value);
+        self
+    }
+
+    /// The array formula spills its result. ex) `FILTER`, `SORT`, `UNIQUE`
+    pub fn get_dynamic_array(&self) -> &bool {
+        &self.dynamic_array
+    }
+
+    pub fn set_dynamic_array(&mut self, value: bool) -> &mut Self {
+        self.dynamic_array = value;
+        self
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.get_formula_type() == &CellFormulaValues::Shared
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.get_formula_type() == &CellFormulaValues::Array
+    }
+
+    /// Master cell of the shared formula has the formula text and the range.
+    pub fn is_shared_master(&self) -> bool {
+        self.is_shared() && !self.text.is_empty()
+    }
+
+    pub(crate) fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if !self.reference.has_value() {
+            return;
+        }
+        let mut range = Range::default();
+        range.set_range(self.reference.get_value());
+        range.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.reference.set_value(range.get_range());
+    }
+
+    pub(crate) fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if !self.reference.has_value() {
+            return;
+        }
+        let mut range = Range::default();
+        range.set_range(self.reference.get_value());
+        range.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.reference.set_value(range.get_range());
+    }
+
+    /// (This method is crate only.)
+    /// Get the attributes of the `f` element as pairs of the name and the value.
+    pub(crate) fn get_attribute_pairs(&self) -> Vec<(&str, &str)> {
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.formula_type.has_value() {
+            attributes.push(("t", self.formula_type.get_value_string()));
+        }
+        if *self.always_calculate_array.get_value() {
+            attributes.push(("aca", "1"));
+        }
+        if self.reference.has_value() {
+            attributes.push(("ref", self.reference.get_value()));
+        }
+        if *self.data_table_2d.get_value() {
+            attributes.push(("dt2D", "1"));
+        }
+        if *self.data_table_row.get_value() {
+            attributes.push(("dtr", "1"));
+        }
+        if *self.input1_deleted.get_value() {
+            attributes.push(("del1", "1"));
+        }
+        if *self.input2_deleted.get_value() {
+            attributes.push(("del2", "1"));
+        }
+        if self.data_table_cell1.has_value() {
+            attributes.push(("r1", self.data_table_cell1.get_value()));
+        }
+        if self.data_table_cell2.has_value() {
+            attributes.push(("r2", self.data_table_cell2.get_value()));
+        }
+        if *self.always_calculate.get_value() {
+            attributes.push(("ca", "1"));
+        }
+        if self.shared_index.has_value() {
+            attributes.push(("si", self.shared_index.get_value()));
+        }
+        if *self.assigns_to_name.get_value() {
+            attributes.push(("bx", "1"));
+        }
+        attributes
+    }
+
+    /// (This method is crate only.)
+    /// Set the attributes of the `f` element from pairs of the name and the value.
+    /// Unknown names are ignored.
+    pub(crate) fn set_attribute_pairs(&mut self, attributes: &[(String, String)]) -> &mut Self {
+        for (name, value) in attributes {
+            match name.as_str() {
+                "t" => {
+                    self.formula_type.set_value_string(value.as_str());
+                }
+                "aca" => {
+                    self.always_calculate_array.set_value_string(value.as_str());
+                }
+                "ref" => {
+                    self.reference.set_value_string(value.as_str());
+                }
+                "dt2D" => {
+                    self.data_table_2d.set_value_string(value.as_str());
+                }
+                "dtr" => {
+                    self.data_table_row.set_value_string(value.as_str());
+                }
+                "del1" => {
+                    self.input1_deleted.set_value_string(value.as_str());
+                }
+                "del2" => {
+                    self.input2_deleted.set_value_string(value.as_str());
+                }
+                "r1" => {
+                    self.data_table_cell1.set_value_string(value.as_str());
+                }
+                "r2" => {
+                    self.data_table_cell2.set_value_string(value.as_str());
+                }
+                "ca" => {
+                    self.always_calculate.set_value_string(value.as_str());
+                }
+                "si" => {
+                    self.shared_index.set_value_string(value.as_str());
+                }
+                "bx" => {
+                    self.assigns_to_name.set_value_string(value.as_str());
+                }
+                _ => {}
+            }
+        }
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, formula_type, "t");
+        set_string_from_xml!(self, e, reference, "ref");
+        set_string_from_xml!(self, e, shared_index, "si");
+        set_string_from_xml!(self, e, always_calculate, "ca");
+        set_string_from_xml!(self, e, always_calculate_array, "aca");
+        set_string_from_xml!(self, e, assigns_to_name, "bx");
+        set_string_from_xml!(self, e, data_table_2d, "dt2D");
+        set_string_from_xml!(self, e, data_table_row, "dtr");
+        set_string_from_xml!(self, e, input1_deleted, "del1");
+        set_string_from_xml!(self, e, input2_deleted, "del2");
+        set_string_from_xml!(self, e, data_table_cell1, "r1");
+        set_string_from_xml!(self, e, data_table_cell2, "r2");
+
+        if empty_flag {
+            return;
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Text(e)) => {
+                    self.text
+                        .push_str(&remove_future_function_prefix(&e.unescape().unwrap()));
+                }
+                Ok(Event::End(ref e)) if e.name().into_inner() == b"f" => return,
+                Ok(Event::Eof) => panic!("Error not find {} end element", "f"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // f
+        let attributes = self.get_attribute_pairs();
+        if self.text.is_empty() {
+            write_start_tag(writer, "f", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "f", attributes, false);
+        write_text_node(writer, add_future_function_prefix(&self.text));
+        write_end_tag(writer, "f");
+    }
+}
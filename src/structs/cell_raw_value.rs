@@ -1,3 +1,5 @@
+use super::CellErrorType;
+use super::EnumTrait;
 use super::RichText;
 use super::Text;
 use std::fmt;
@@ -11,7 +13,7 @@ pub enum CellRawValue {
     Numeric(f64),
     Bool(bool),
     Inline,
-    Error,
+    Error(CellErrorType),
     Null,
 }
 impl fmt::Display for CellRawValue {
@@ -22,6 +24,7 @@ impl fmt::Display for CellRawValue {
             Self::RichText(v) => write!(f, "{}", v.get_text()),
             Self::Numeric(v) => write!(f, "{}", &v),
             Self::Bool(v) => write!(f, "{}", if v == &true { "TRUE" } else { "FALSE" }),
+            Self::Error(v) => write!(f, "{}", v),
             _ => write!(f, ""),
         }
     }
@@ -39,7 +42,7 @@ impl CellRawValue {
             Self::RichText(_) => "s",
             Self::Numeric(_) => "n",
             Self::Bool(_) => "b",
-            Self::Error => "e",
+            Self::Error(_) => "e",
             _ => "",
         }
     }
@@ -65,6 +68,13 @@ impl CellRawValue {
         }
     }
 
+    pub fn get_error(&self) -> Option<&CellErrorType> {
+        match self {
+            Self::Error(v) => Some(v),
+            _ => None,
+        }
+    }
+
     pub fn get_rich_text(&self) -> Option<RichText> {
         match self {
             Self::RichText(v) => Some(v.clone()),
@@ -34,6 +34,11 @@ impl CellStyle {
         self
     }
 
+    /// Whether it is a built-in style.
+    pub fn has_builtin_id(&self) -> bool {
+        self.builtin_id.has_value()
+    }
+
     pub fn get_format_id(&self) -> &u32 {
         self.format_id.get_value()
     }
@@ -60,7 +65,9 @@ impl CellStyle {
         let format_id = self.format_id.get_value_string();
         attributes.push(("xfId", &format_id));
         let builtin_id = self.builtin_id.get_value_string();
-        attributes.push(("builtinId", &builtin_id));
+        if self.builtin_id.has_value() {
+            attributes.push(("builtinId", &builtin_id));
+        }
         write_start_tag(writer, "cellStyle", attributes, true);
     }
 }
@@ -13,11 +13,11 @@ pub struct CellStyles {
 }
 
 impl CellStyles {
-    pub fn _get_cell_style(&self) -> &Vec<CellStyle> {
+    pub fn get_cell_style(&self) -> &Vec<CellStyle> {
         &self.cell_style
     }
 
-    pub fn _get_cell_style_mut(&mut self) -> &mut Vec<CellStyle> {
+    pub fn get_cell_style_mut(&mut self) -> &mut Vec<CellStyle> {
         &mut self.cell_style
     }
 
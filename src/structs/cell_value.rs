@@ -1,3 +1,5 @@
+use super::CellErrorType;
+use super::CellFormula;
 use super::RichText;
 use super::SharedStringItem;
 use super::Text;
@@ -8,8 +10,7 @@ use structs::CellRawValue;
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct CellValue {
     pub(crate) raw_value: CellRawValue,
-    pub(crate) formula: Option<String>,
-    pub(crate) formula_attributes: Vec<(String, String)>,
+    pub(crate) formula: Option<CellFormula>,
 }
 impl CellValue {
     pub fn get_data_type(&self) -> &CellRawValue {
@@ -24,15 +25,29 @@ impl CellValue {
         self.raw_value.get_data_type()
     }
 
+    /// The attributes are set to the `CellFormula` of the cell.
+    /// An empty list without a formula text removes the formula.
+    #[deprecated(note = "use `CellValue::get_cell_formula_mut()` instead")]
     pub fn set_formula_attributes(&mut self, formula_attributes: Vec<(String, String)>) {
-        self.formula_attributes = formula_attributes;
+        let mut formula = CellFormula::default();
+        if let Some(v) = &self.formula {
+            formula.set_text(v.get_text());
+        }
+        if formula.get_text().is_empty() && formula_attributes.is_empty() {
+            self.formula = None;
+            return;
+        }
+        formula.set_attribute_pairs(&formula_attributes);
+        self.formula = Some(formula);
     }
 
+    /// The attributes are read from the `CellFormula` of the cell.
+    #[deprecated(note = "use `CellValue::get_cell_formula()` instead")]
     pub fn get_formula_attributes(&self) -> Vec<(&str, &str)> {
-        self.formula_attributes
-            .iter()
-            .map(|(a, b)| (a.as_str(), b.as_str()))
-            .collect()
+        match &self.formula {
+            Some(v) => v.get_attribute_pairs(),
+            None => Vec::new(),
+        }
     }
 
     pub fn get_value(&self) -> Cow<'static, str> {
@@ -65,7 +80,7 @@ impl CellValue {
     /// - `Null` - if the string was `"NULL"`
     /// - `Numeric` - if the string can be parsed to an `f64`
     /// - `Bool` - if the string was either `"TRUE"` or `"FALSE"`
-    /// - `Error` - if the string was `"#VALUE!"`
+    /// - `Error` - if the string was an error value. ex) `"#VALUE!"`, `"#N/A"`
     /// - `String` - if the string does not fulfill any of the other conditions
     pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.raw_value = Self::guess_typed_data(&value.into());
@@ -116,21 +131,49 @@ impl CellValue {
     }
 
     pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.formula = Some(value.into());
+        let mut formula = CellFormula::default();
+        formula.set_text(value);
+        self.formula = Some(formula);
+        self
+    }
+
+    pub fn get_cell_formula(&self) -> Option<&CellFormula> {
+        self.formula.as_ref()
+    }
+
+    pub fn get_cell_formula_mut(&mut self) -> Option<&mut CellFormula> {
+        self.formula.as_mut()
+    }
+
+    pub fn set_cell_formula(&mut self, value: CellFormula) -> &mut Self {
+        self.formula = Some(value);
         self
     }
 
     pub fn remove_formula(&mut self) -> &mut Self {
         self.formula = None;
-        self.formula_attributes.clear();
         self
     }
 
     pub fn set_error(&mut self) -> &mut Self {
-        self.set_value_crate("#VALUE!");
+        self.set_value_error_crate(CellErrorType::Value)
+    }
+
+    pub fn set_value_error(&mut self, value: CellErrorType) -> &mut Self {
+        self.raw_value = CellRawValue::Error(value);
+        self.remove_formula();
+        self
+    }
+
+    pub(crate) fn set_value_error_crate(&mut self, value: CellErrorType) -> &mut Self {
+        self.raw_value = CellRawValue::Error(value);
         self
     }
 
+    pub fn get_value_error(&self) -> Option<&CellErrorType> {
+        self.raw_value.get_error()
+    }
+
     pub(crate) fn set_shared_string_item(&mut self, value: SharedStringItem) -> &mut Self {
         match value.get_text() {
             Some(v) => {
@@ -155,7 +198,7 @@ impl CellValue {
     pub fn get_formula(&self) -> &str {
         match &self.formula {
             Some(v) => {
-                return v;
+                return v.get_text();
             }
             None => {}
         }
@@ -182,8 +225,8 @@ impl CellValue {
             return CellRawValue::Bool(false);
         }
 
-        if uppercase_value == "#VALUE!" {
-            return CellRawValue::Error;
+        if let Ok(v) = uppercase_value.parse::<CellErrorType>() {
+            return CellRawValue::Error(v);
         }
 
         CellRawValue::String(value.into())
@@ -202,7 +245,10 @@ impl CellValue {
     }
 
     pub(crate) fn is_formula_attributes_empty(&self) -> bool {
-        self.get_formula_attributes().is_empty()
+        match &self.formula {
+            Some(v) => v.get_attribute_pairs().is_empty(),
+            None => true,
+        }
     }
 
     pub(crate) fn adjustment_insert_formula_coordinate(
@@ -214,10 +260,10 @@ impl CellValue {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        match &self.formula {
+        match &mut self.formula {
             Some(v) => {
                 let formula = adjustment_insert_formula_coordinate(
-                    v,
+                    v.get_text(),
                     root_col_num,
                     offset_col_num,
                     root_row_num,
@@ -225,12 +271,29 @@ impl CellValue {
                     sheet_name,
                     self_sheet_name,
                 );
-                self.formula = Some(formula);
+                v.set_text(formula);
             }
             None => {}
         }
     }
 
+    pub(crate) fn adjustment_insert_formula_reference(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
     pub(crate) fn adjustment_remove_formula_coordinate(
         &mut self,
         self_sheet_name: &str,
@@ -240,10 +303,10 @@ impl CellValue {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        match &self.formula {
+        match &mut self.formula {
             Some(v) => {
                 let formula = adjustment_remove_formula_coordinate(
-                    v,
+                    v.get_text(),
                     root_col_num,
                     offset_col_num,
                     root_row_num,
@@ -251,11 +314,28 @@ impl CellValue {
                     sheet_name,
                     self_sheet_name,
                 );
-                self.formula = Some(formula);
+                v.set_text(formula);
             }
             None => {}
         }
     }
+
+    pub(crate) fn adjustment_remove_formula_reference(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -10,6 +10,7 @@ pub struct Cells {
     map: HashMap<(u32, u32), Cell>,
     default_cell_value: CellValue,
     default_style: Style,
+    date1904: bool,
 }
 impl Cells {
     pub fn get_collection(&self) -> Vec<&Cell> {
@@ -90,12 +91,14 @@ impl Cells {
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
+        let date1904 = self.date1904;
         self.map
             .entry((row.to_owned(), col.to_owned()))
             .or_insert_with(|| {
                 let mut c = Cell::default();
                 c.get_coordinate_mut().set_col_num(col);
                 c.get_coordinate_mut().set_row_num(row);
+                c.set_date1904(date1904);
                 c
             })
     }
@@ -135,13 +138,24 @@ impl Cells {
         self
     }
 
-    pub(crate) fn add(&mut self, cell: Cell) {
+    pub(crate) fn add(&mut self, mut cell: Cell) {
+        cell.set_date1904(self.date1904);
         let col_num = cell.get_coordinate().get_col_num();
         let row_num = cell.get_coordinate().get_row_num();
         let k = (row_num.to_owned(), col_num.to_owned());
         self.map.insert_unique_unchecked(k, cell);
     }
 
+    /// (This method is crate only.)
+    /// Set the date system of the workbook to the cells.
+    pub(crate) fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        for cell in self.map.values_mut() {
+            cell.set_date1904(value);
+        }
+        self
+    }
+
     pub(crate) fn remove(&mut self, col_num: &u32, row_num: &u32) -> bool {
         let k = (*row_num, *col_num);
         self.map.remove(&k).is_some()
@@ -194,6 +208,21 @@ impl Cells {
                 root_row_num,
                 offset_row_num,
             );
+            cell.get_cell_value_mut()
+                .adjustment_insert_formula_reference(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            if let Some(v) = cell.get_hyperlink_crate_mut() {
+                v.adjustment_insert_reference(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
         }
         self.rebuild_map();
     }
@@ -219,6 +248,16 @@ impl Cells {
                     root_row_num,
                     offset_row_num,
                 );
+            if let Some(v) = cell.get_hyperlink_crate_mut() {
+                v.adjustment_insert_location(
+                    self_sheet_name,
+                    sheet_name,
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
         }
     }
 
@@ -247,6 +286,21 @@ impl Cells {
                 root_row_num,
                 offset_row_num,
             );
+            cell.get_cell_value_mut()
+                .adjustment_remove_formula_reference(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            if let Some(v) = cell.get_hyperlink_crate_mut() {
+                v.adjustment_remove_reference(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
         }
         self.rebuild_map();
     }
@@ -272,6 +326,16 @@ impl Cells {
                     root_row_num,
                     offset_row_num,
                 );
+            if let Some(v) = cell.get_hyperlink_crate_mut() {
+                v.adjustment_remove_location(
+                    self_sheet_name,
+                    sheet_name,
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
         }
     }
 
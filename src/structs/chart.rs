@@ -56,8 +56,10 @@ use structs::drawing::spreadsheet::GraphicFrame;
 use structs::drawing::spreadsheet::MarkerType;
 use structs::drawing::spreadsheet::TwoCellAnchor;
 use structs::drawing::Paragraph;
+use structs::drawing::RgbColorModelHex;
 use structs::drawing::Run;
 use structs::drawing::RunProperties;
+use structs::drawing::SolidFill;
 use structs::ChartType;
 
 /// ## Supported chart types
@@ -237,6 +239,128 @@ impl Chart {
         }
         self
     }
+    /// Set the built-in chart style id. (c:style / c14:style)
+    /// # Arguments
+    /// * `value` - Style id. (1 - 48)
+    pub fn set_style(&mut self, value: u8) -> &mut Self {
+        self.get_chart_space_mut().get_style_mut().set_val(value);
+        self
+    }
+
+    /// Set the font of the chart title.
+    /// Has no effect when the chart has no title.
+    /// # Arguments
+    /// * `size` - Font size in points.
+    /// * `bold` - Bold.
+    /// * `color` - RGB hex color. (e.g. "595959")
+    pub fn set_title_font<S: Into<String>>(
+        &mut self,
+        size: f64,
+        bold: bool,
+        color: S,
+    ) -> &mut Self {
+        let color = color.into();
+        let title = match self.get_chart_space_mut().get_chart_mut().get_title_mut() {
+            Some(v) => v,
+            None => return self,
+        };
+        if let Some(chart_text) = title.get_chart_text_mut() {
+            for paragraph in chart_text.get_rich_text_mut().get_paragraph_mut() {
+                Self::set_run_properties_font(
+                    paragraph
+                        .get_paragraph_properties_mut()
+                        .get_default_run_properties_mut()
+                        .get_or_insert(RunProperties::default()),
+                    size,
+                    bold,
+                    &color,
+                );
+                for run in paragraph.get_run_mut() {
+                    Self::set_run_properties_font(run.get_run_properties_mut(), size, bold, &color);
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the font of the legend.
+    /// # Arguments
+    /// * `size` - Font size in points.
+    /// * `bold` - Bold.
+    /// * `color` - RGB hex color. (e.g. "595959")
+    pub fn set_legend_font<S: Into<String>>(
+        &mut self,
+        size: f64,
+        bold: bool,
+        color: S,
+    ) -> &mut Self {
+        let color = color.into();
+        let text_properties = self
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_legend_mut()
+            .get_text_properties_mut();
+        Self::set_text_properties_font(text_properties, size, bold, &color);
+        self
+    }
+
+    /// Set the font of the axis labels.
+    /// Applies to every category and value axis.
+    /// # Arguments
+    /// * `size` - Font size in points.
+    /// * `bold` - Bold.
+    /// * `color` - RGB hex color. (e.g. "595959")
+    pub fn set_axis_font<S: Into<String>>(&mut self, size: f64, bold: bool, color: S) -> &mut Self {
+        let color = color.into();
+        let plot_area = self.get_plot_area_mut();
+        for axis in plot_area.get_category_axis_mut() {
+            Self::set_text_properties_font(axis.get_text_properties_mut(), size, bold, &color);
+        }
+        for axis in plot_area.get_value_axis_mut() {
+            Self::set_text_properties_font(axis.get_text_properties_mut(), size, bold, &color);
+        }
+        self
+    }
+
+    fn set_text_properties_font(
+        text_properties: &mut Option<TextProperties>,
+        size: f64,
+        bold: bool,
+        color: &str,
+    ) {
+        let text_properties = text_properties.get_or_insert(TextProperties::default());
+        if text_properties.get_paragraph().is_empty() {
+            text_properties.add_paragraph(Paragraph::default());
+        }
+        for paragraph in text_properties.get_paragraph_mut() {
+            Self::set_run_properties_font(
+                paragraph
+                    .get_paragraph_properties_mut()
+                    .get_default_run_properties_mut()
+                    .get_or_insert(RunProperties::default()),
+                size,
+                bold,
+                color,
+            );
+        }
+    }
+
+    fn set_run_properties_font(
+        run_properties: &mut RunProperties,
+        size: f64,
+        bold: bool,
+        color: &str,
+    ) {
+        let mut rgb_color_model_hex = RgbColorModelHex::default();
+        rgb_color_model_hex.set_val(color);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+
+        run_properties.set_sz(((size * 100f64).round() as u32).to_string());
+        run_properties.set_bold(if bold { "1" } else { "0" });
+        run_properties.set_solid_fill(solid_fill);
+    }
+
     pub fn get_plot_area_mut(&mut self) -> &mut PlotArea {
         self.get_chart_space_mut()
             .get_chart_mut()
@@ -0,0 +1,296 @@
+use structs::drawing::chart_ex::Axis;
+use structs::drawing::chart_ex::Binning;
+use structs::drawing::chart_ex::ChartSpace;
+use structs::drawing::chart_ex::Data;
+use structs::drawing::chart_ex::Dimension;
+use structs::drawing::chart_ex::Legend;
+use structs::drawing::chart_ex::Series;
+use structs::drawing::chart_ex::SeriesLayoutValues;
+use structs::drawing::chart_ex::Text;
+use structs::drawing::chart_ex::Title;
+use structs::drawing::spreadsheet::GraphicFrame;
+use structs::drawing::spreadsheet::MarkerType;
+use structs::drawing::spreadsheet::TwoCellAnchor;
+use structs::ChartExType;
+
+/// Office 2016 chart. (xl/charts/chartExN.xml)
+/// ## Supported chart types
+/// * Waterfall
+/// * Funnel
+/// * Treemap
+/// * Sunburst
+/// * Histogram
+/// * BoxWhisker
+///
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// let mut book = umya_spreadsheet::new_file();
+///
+/// // Add Waterfall Chart
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("C1");
+/// to_marker.set_coordinate("J15");
+/// let mut chart = umya_spreadsheet::structs::ChartEx::default();
+/// chart.new_chart_ex(
+///     umya_spreadsheet::structs::ChartExType::Waterfall,
+///     from_marker,
+///     to_marker,
+///     vec!["Sheet1!$A$1:$A$5", "Sheet1!$B$1:$B$5"],
+/// );
+/// chart.set_title("Cash Flow").set_subtotals(vec![4]);
+/// book.get_sheet_by_name_mut("Sheet1").unwrap()
+///     .add_chart_ex(chart);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ChartEx {
+    two_cell_anchor: TwoCellAnchor,
+}
+
+impl ChartEx {
+    pub fn get_two_cell_anchor(&self) -> &TwoCellAnchor {
+        &self.two_cell_anchor
+    }
+
+    pub fn get_two_cell_anchor_mut(&mut self) -> &mut TwoCellAnchor {
+        &mut self.two_cell_anchor
+    }
+
+    pub fn set_two_cell_anchor(&mut self, value: TwoCellAnchor) -> &mut Self {
+        self.two_cell_anchor = value;
+        self
+    }
+
+    pub fn get_chart_space(&self) -> &ChartSpace {
+        match self.two_cell_anchor.get_graphic_frame() {
+            Some(v) => match v.get_graphic().get_graphic_data().get_chart_ex_space() {
+                Some(v) => v,
+                None => panic!("Non-ChartSpace."),
+            },
+            None => {
+                panic!("Non-ChartSpace.");
+            }
+        }
+    }
+
+    pub fn get_chart_space_mut(&mut self) -> &mut ChartSpace {
+        match self.two_cell_anchor.get_graphic_frame_mut() {
+            Some(v) => match v
+                .get_graphic_mut()
+                .get_graphic_data_mut()
+                .get_chart_ex_space_mut()
+            {
+                Some(v) => v,
+                None => panic!("Non-ChartSpace."),
+            },
+            None => {
+                panic!("Non-ChartSpace.");
+            }
+        }
+    }
+
+    pub fn get_coordinate(&self) -> String {
+        self.two_cell_anchor.get_from_marker().get_coordinate()
+    }
+
+    pub(crate) fn get_col(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_col()
+    }
+
+    pub(crate) fn get_row(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_row()
+    }
+
+    pub fn get_series_mut(&mut self) -> &mut Vec<Series> {
+        self.get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .get_plot_area_region_mut()
+            .get_series_mut()
+    }
+
+    pub fn set_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut text = Text::default();
+        text.set_value(value);
+        let mut title = Title::default();
+        title
+            .set_position("t")
+            .set_alignment("ctr")
+            .set_overlay(false)
+            .set_text(text);
+        self.get_chart_space_mut().get_chart_mut().set_title(title);
+        self
+    }
+
+    pub fn set_series_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut text = Text::default();
+        text.set_value(value);
+        for series in self.get_series_mut() {
+            series.set_text(text.clone());
+        }
+        self
+    }
+
+    /// Set the points shown as totals. (Waterfall only)
+    /// # Arguments
+    /// * `value` - Zero-based point indexes.
+    pub fn set_subtotals(&mut self, value: Vec<u32>) -> &mut Self {
+        for series in self.get_series_mut() {
+            if series.get_layout_id() == &SeriesLayoutValues::Waterfall {
+                series
+                    .get_layout_properties_mut()
+                    .set_subtotals(value.clone());
+            }
+        }
+        self
+    }
+
+    /// Set the number of bins. (Histogram only)
+    pub fn set_bin_count(&mut self, value: u32) -> &mut Self {
+        for series in self.get_series_mut() {
+            if let Some(binning) = series.get_layout_properties_mut().get_binning_mut() {
+                binning.set_bin_count(value);
+            }
+        }
+        self
+    }
+
+    /// Set the bin width. (Histogram only)
+    pub fn set_bin_size(&mut self, value: f64) -> &mut Self {
+        for series in self.get_series_mut() {
+            if let Some(binning) = series.get_layout_properties_mut().get_binning_mut() {
+                binning.set_bin_size(value);
+            }
+        }
+        self
+    }
+
+    /// Create a new chart.
+    /// # Arguments
+    /// * `chart_type` - Chart type.
+    /// * `from_marker` - Top left of the chart.
+    /// * `to_marker` - Bottom right of the chart.
+    /// * `data_list` - Reference list.
+    ///   Waterfall, Funnel: category, value.
+    ///   Treemap, Sunburst: category, size. The category may span several columns (outermost level first).
+    ///   Histogram, BoxWhisker: value.
+    pub fn new_chart_ex(
+        &mut self,
+        chart_type: ChartExType,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+        data_list: Vec<&str>,
+    ) -> &mut Self {
+        self.two_cell_anchor.set_from_marker(from_marker);
+        self.two_cell_anchor.set_to_marker(to_marker);
+
+        let mut chart_space = ChartSpace::default();
+        let mut series = Series::default();
+        series.set_unique_id("{00000000-0001-0000-0000-000000000000}");
+        series.set_data_id(0);
+
+        let mut data = Data::default();
+        data.set_id(0);
+
+        let chart = chart_space.get_chart_mut();
+        match chart_type {
+            ChartExType::Waterfall => {
+                data.add_dimension(Self::make_dimension(false, "cat", data_list.first()));
+                data.add_dimension(Self::make_dimension(true, "val", data_list.get(1)));
+                series.set_layout_id(SeriesLayoutValues::Waterfall);
+                series.get_layout_properties_mut();
+                let plot_area = chart.get_plot_area_mut();
+                plot_area.add_axis(Axis::make_category_axis(0, "0.5"));
+                plot_area.add_axis(Axis::make_value_axis(1));
+                chart.set_legend(Self::make_legend());
+            }
+            ChartExType::Funnel => {
+                data.add_dimension(Self::make_dimension(false, "cat", data_list.first()));
+                data.add_dimension(Self::make_dimension(true, "val", data_list.get(1)));
+                series.set_layout_id(SeriesLayoutValues::Funnel);
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Axis::make_category_axis(0, "0.06"));
+            }
+            ChartExType::Treemap => {
+                data.add_dimension(Self::make_dimension(false, "cat", data_list.first()));
+                data.add_dimension(Self::make_dimension(true, "size", data_list.get(1)));
+                series.set_layout_id(SeriesLayoutValues::Treemap);
+                series
+                    .get_layout_properties_mut()
+                    .set_parent_label_layout("overlapping");
+                chart.set_legend(Self::make_legend());
+            }
+            ChartExType::Sunburst => {
+                data.add_dimension(Self::make_dimension(false, "cat", data_list.first()));
+                data.add_dimension(Self::make_dimension(true, "size", data_list.get(1)));
+                series.set_layout_id(SeriesLayoutValues::Sunburst);
+            }
+            ChartExType::Histogram => {
+                data.add_dimension(Self::make_dimension(true, "val", data_list.first()));
+                series.set_layout_id(SeriesLayoutValues::ClusteredColumn);
+                let mut binning = Binning::default();
+                binning.set_interval_closed("r");
+                series.get_layout_properties_mut().set_binning(binning);
+                let plot_area = chart.get_plot_area_mut();
+                plot_area.add_axis(Axis::make_category_axis(0, "0"));
+                plot_area.add_axis(Axis::make_value_axis(1));
+            }
+            ChartExType::BoxWhisker => {
+                data.add_dimension(Self::make_dimension(true, "val", data_list.first()));
+                series.set_layout_id(SeriesLayoutValues::BoxWhisker);
+                series
+                    .get_layout_properties_mut()
+                    .set_visibility_xml(r#"<cx:visibility meanLine="0" meanMarker="1" nonoutliers="0" outliers="1"/>"#)
+                    .set_statistics_xml(r#"<cx:statistics quartileMethod="exclusive"/>"#);
+                let plot_area = chart.get_plot_area_mut();
+                plot_area.add_axis(Axis::make_category_axis(0, "1"));
+                plot_area.add_axis(Axis::make_value_axis(1));
+                chart.set_legend(Self::make_legend());
+            }
+        }
+        if !chart.get_plot_area().get_axis().is_empty() {
+            let axis_id = chart
+                .get_plot_area()
+                .get_axis()
+                .iter()
+                .map(|v| *v.get_id())
+                .collect();
+            series.set_axis_id(axis_id);
+        }
+        chart
+            .get_plot_area_mut()
+            .get_plot_area_region_mut()
+            .add_series(series);
+        chart_space.get_chart_data_mut().add_data(data);
+
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .set_chart_ex_space(chart_space);
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+        self
+    }
+
+    fn make_dimension(is_numeric: bool, dimension_type: &str, formula: Option<&&str>) -> Dimension {
+        let mut dimension = Dimension::default();
+        dimension
+            .set_is_numeric(is_numeric)
+            .set_dimension_type(dimension_type);
+        if let Some(v) = formula {
+            dimension.set_formula(*v);
+        }
+        dimension
+    }
+
+    fn make_legend() -> Legend {
+        let mut legend = Legend::default();
+        legend
+            .set_position("t")
+            .set_alignment("ctr")
+            .set_overlay(false);
+        legend
+    }
+}
@@ -0,0 +1,42 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum ChartExType {
+    Waterfall,
+    Funnel,
+    Treemap,
+    Sunburst,
+    Histogram,
+    BoxWhisker,
+}
+impl Default for ChartExType {
+    fn default() -> Self {
+        Self::Waterfall
+    }
+}
+impl EnumTrait for ChartExType {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Waterfall => "waterfall",
+            Self::Funnel => "funnel",
+            Self::Treemap => "treemap",
+            Self::Sunburst => "sunburst",
+            Self::Histogram => "histogram",
+            Self::BoxWhisker => "box_whisker",
+        }
+    }
+}
+impl FromStr for ChartExType {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "waterfall" => Ok(Self::Waterfall),
+            "funnel" => Ok(Self::Funnel),
+            "treemap" => Ok(Self::Treemap),
+            "sunburst" => Ok(Self::Sunburst),
+            "histogram" => Ok(Self::Histogram),
+            "box_whisker" => Ok(Self::BoxWhisker),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use structs::drawing::charts::ChartSpace;
+use structs::drawing::spreadsheet::AbsoluteAnchor;
+use structs::BooleanValue;
+use structs::Chart;
+use structs::Color;
+use structs::PageMargins;
+use structs::UInt32Value;
+
+/// A Chart Sheet Object.
+/// A sheet tab that shows a single full-page chart.
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// let mut book = umya_spreadsheet::new_file();
+///
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("C1");
+/// to_marker.set_coordinate("D11");
+/// let mut chart = umya_spreadsheet::structs::Chart::default();
+/// chart.new_chart(
+///     umya_spreadsheet::structs::ChartType::LineChart,
+///     from_marker,
+///     to_marker,
+///     vec!["Sheet1!$A$1:$A$10"],
+/// );
+///
+/// // Add Chart Sheet
+/// let _ = book.new_chart_sheet("Chart1", &chart);
+///
+/// // Get Chart Sheet
+/// let chart_sheet = book.get_chart_sheet_by_name_mut("Chart1").unwrap();
+/// chart_sheet.set_zoom_to_fit(true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chartsheet {
+    r_id: String,
+    sheet_id: String,
+    title: String,
+    position: Option<usize>,
+    tab_color: Option<Color>,
+    zoom_scale: UInt32Value,
+    zoom_to_fit: BooleanValue,
+    page_margins: PageMargins,
+    absolute_anchor: AbsoluteAnchor,
+}
+
+impl Default for Chartsheet {
+    fn default() -> Self {
+        let mut page_margins = PageMargins::default();
+        page_margins
+            .set_left(0.7)
+            .set_right(0.7)
+            .set_top(0.75)
+            .set_bottom(0.75)
+            .set_header(0.3)
+            .set_footer(0.3);
+        let mut absolute_anchor = AbsoluteAnchor::default();
+        absolute_anchor
+            .get_extent_mut()
+            .set_cx(8671560)
+            .set_cy(6306185);
+        Self {
+            r_id: String::from(""),
+            sheet_id: String::from(""),
+            title: String::from(""),
+            position: None,
+            tab_color: None,
+            zoom_scale: UInt32Value::default(),
+            zoom_to_fit: BooleanValue::default(),
+            page_margins,
+            absolute_anchor,
+        }
+    }
+}
+
+impl Chartsheet {
+    /// Get Chart Sheet Name.
+    pub fn get_name(&self) -> &str {
+        &self.title
+    }
+
+    /// Set Chart Sheet Name.
+    /// # Arguments
+    /// * `sheet_name` - Sheet Name. [Caution] Duplicate names cannot be set.
+    pub fn set_name<S: Into<String>>(&mut self, sheet_name: S) -> &mut Self {
+        self.title = sheet_name.into();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get r:id.
+    pub(crate) fn get_r_id(&self) -> &String {
+        &self.r_id
+    }
+
+    /// (This method is crate only.)
+    /// Set r:id.
+    pub(crate) fn set_r_id<S: Into<String>>(&mut self, value: S) {
+        self.r_id = value.into();
+    }
+
+    /// (This method is crate only.)
+    /// Get the number of worksheets placed before this chart sheet in the tab order.
+    /// None places it after all the worksheets.
+    pub(crate) fn get_position(&self) -> &Option<usize> {
+        &self.position
+    }
+
+    /// (This method is crate only.)
+    /// Set the number of worksheets placed before this chart sheet in the tab order.
+    pub(crate) fn set_position(&mut self, value: Option<usize>) {
+        self.position = value;
+    }
+
+    /// Get Sheet Id.
+    pub fn get_sheet_id(&self) -> &String {
+        &self.sheet_id
+    }
+
+    /// (This method is crate only.)
+    /// Set Sheet Id.
+    pub(crate) fn set_sheet_id<S: Into<String>>(&mut self, value: S) {
+        self.sheet_id = value.into();
+    }
+
+    /// Get Tab Color.
+    pub fn get_tab_color(&self) -> &Option<Color> {
+        &self.tab_color
+    }
+
+    /// Get Tab Color in mutable.
+    pub fn get_tab_color_mut(&mut self) -> &mut Color {
+        self.tab_color.get_or_insert(Color::default())
+    }
+
+    /// Set Tab Color.
+    /// # Arguments
+    /// * `value` - Color
+    pub fn set_tab_color(&mut self, value: Color) -> &mut Self {
+        self.tab_color = Some(value);
+        self
+    }
+
+    /// Remove Tab Color.
+    pub fn remove_tab_color(&mut self) -> &mut Self {
+        self.tab_color = None;
+        self
+    }
+
+    pub fn get_zoom_scale(&self) -> &u32 {
+        self.zoom_scale.get_value()
+    }
+
+    pub fn set_zoom_scale(&mut self, value: u32) -> &mut Self {
+        self.zoom_scale.set_value(value);
+        self
+    }
+
+    pub fn get_zoom_to_fit(&self) -> &bool {
+        self.zoom_to_fit.get_value()
+    }
+
+    pub fn set_zoom_to_fit(&mut self, value: bool) -> &mut Self {
+        self.zoom_to_fit.set_value(value);
+        self
+    }
+
+    /// Get Page Margins.
+    pub fn get_page_margins(&self) -> &PageMargins {
+        &self.page_margins
+    }
+
+    /// Get Page Margins in mutable.
+    pub fn get_page_margins_mut(&mut self) -> &mut PageMargins {
+        &mut self.page_margins
+    }
+
+    /// Set Page Margins.
+    /// # Arguments
+    /// * `value` - PageMargins
+    pub fn set_page_margins(&mut self, value: PageMargins) -> &mut Self {
+        self.page_margins = value;
+        self
+    }
+
+    /// Get Absolute Anchor.
+    /// Holds the position, size and graphic frame of the chart.
+    pub fn get_absolute_anchor(&self) -> &AbsoluteAnchor {
+        &self.absolute_anchor
+    }
+
+    /// Get Absolute Anchor in mutable.
+    pub fn get_absolute_anchor_mut(&mut self) -> &mut AbsoluteAnchor {
+        &mut self.absolute_anchor
+    }
+
+    /// Set Absolute Anchor.
+    /// # Arguments
+    /// * `value` - AbsoluteAnchor
+    pub fn set_absolute_anchor(&mut self, value: AbsoluteAnchor) -> &mut Self {
+        self.absolute_anchor = value;
+        self
+    }
+
+    /// Has Chart.
+    pub fn has_chart(&self) -> bool {
+        self.absolute_anchor.get_graphic_frame().is_some()
+    }
+
+    /// Get Chart Space.
+    pub fn get_chart_space(&self) -> Option<&ChartSpace> {
+        self.absolute_anchor
+            .get_graphic_frame()
+            .as_ref()
+            .map(|v| v.get_graphic().get_graphic_data().get_chart_space())
+    }
+
+    /// Get Chart Space in mutable.
+    pub fn get_chart_space_mut(&mut self) -> Option<&mut ChartSpace> {
+        self.absolute_anchor
+            .get_graphic_frame_mut()
+            .as_mut()
+            .map(|v| {
+                v.get_graphic_mut()
+                    .get_graphic_data_mut()
+                    .get_chart_space_mut()
+            })
+    }
+
+    /// Set Chart.
+    /// Copies the chart of an existing Chart object onto this sheet.
+    /// The anchor of the source Chart is not used; the chart fills the sheet.
+    /// # Arguments
+    /// * `chart` - Chart
+    pub fn set_chart(&mut self, chart: &Chart) -> &mut Self {
+        if let Some(v) = chart.get_two_cell_anchor().get_graphic_frame() {
+            self.absolute_anchor.set_graphic_frame(v.clone());
+        }
+        self
+    }
+}
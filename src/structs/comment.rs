@@ -1,6 +1,19 @@
 use crate::xml_read_loop;
 
+use super::vml::office::ConnectValues;
+use super::vml::office::InsetMarginValues;
 use super::vml::spreadsheet::Anchor;
+use super::vml::spreadsheet::AutoFill;
+use super::vml::spreadsheet::CommentColumnTarget;
+use super::vml::spreadsheet::CommentRowTarget;
+use super::vml::spreadsheet::MoveWithCells;
+use super::vml::spreadsheet::ObjectValues;
+use super::vml::spreadsheet::ResizeWithCells;
+use super::vml::spreadsheet::Visible;
+use super::vml::Fill;
+use super::vml::Path;
+use super::vml::Shadow;
+use super::vml::TextBox;
 use super::Coordinate;
 use super::RichText;
 use quick_xml::events::{BytesStart, Event};
@@ -73,6 +86,148 @@ impl Comment {
         self
     }
 
+    /// Get the visibility of the note.
+    pub fn get_visible(&self) -> bool {
+        self.shape
+            .get_client_data()
+            .get_visible()
+            .as_ref()
+            .map(|v| v.get_value().unwrap_or(true))
+            .unwrap_or(false)
+    }
+
+    /// Show the note always, or only when the cell is hovered.
+    pub fn set_visible(&mut self, value: bool) -> &mut Self {
+        let visibility = if value { "visible" } else { "hidden" };
+        self.set_style_property("visibility", visibility);
+        let client_data = self.shape.get_client_data_mut();
+        if value {
+            client_data.set_visible(Visible::default());
+        } else {
+            *client_data.get_visible_mut() = None;
+        }
+        self
+    }
+
+    pub fn get_fill_color(&self) -> &str {
+        self.shape.get_fill_color()
+    }
+
+    /// Set the fill color of the note. ex) "#ffffe1"
+    pub fn set_fill_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        if let Some(v) = self.shape.get_fill_mut() {
+            v.set_color_2(value.as_str());
+        }
+        self.shape.set_fill_color(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Set the position and the size of the note box.
+    /// The margin and the size are in pixels from the top left of the sheet.
+    pub(crate) fn set_box(
+        &mut self,
+        anchor: Anchor,
+        margin_left: u32,
+        margin_top: u32,
+        width: u32,
+        height: u32,
+    ) -> &mut Self {
+        // 1px = 0.75pt
+        let to_pt = |v: u32| format!("{}pt", v as f64 * 0.75);
+        self.set_style_property("margin-left", &to_pt(margin_left));
+        self.set_style_property("margin-top", &to_pt(margin_top));
+        self.set_style_property("width", &to_pt(width));
+        self.set_style_property("height", &to_pt(height));
+        self.set_anchor(anchor);
+        self
+    }
+
+    fn set_style_property(&mut self, key: &str, value: &str) {
+        let mut properties: Vec<String> = self
+            .shape
+            .get_style()
+            .split(';')
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .collect();
+        let property = format!("{}:{}", key, value);
+        match properties
+            .iter_mut()
+            .find(|v| v.split(':').next().map(|k| k.trim()) == Some(key))
+        {
+            Some(v) => *v = property,
+            None => properties.push(property),
+        }
+        self.shape.set_style(properties.join(";"));
+    }
+
+    /// (This method is crate only.)
+    /// Set the shape of a hidden note next to the cell, as Excel makes it.
+    pub(crate) fn set_default_shape(&mut self) -> &mut Self {
+        let col = *self.coordinate.get_col_num();
+        let row = *self.coordinate.get_row_num();
+
+        let mut shape = Shape::default();
+        shape
+            .set_type("#_x0000_t202")
+            .set_style("position:absolute;margin-left:59.25pt;margin-top:1.5pt;width:108pt;height:59.25pt;z-index:1;visibility:hidden")
+            .set_fill_color("#ffffe1")
+            .set_inset_mode(InsetMarginValues::Auto);
+
+        let mut fill = Fill::default();
+        fill.set_color_2("#ffffe1");
+        shape.set_fill(fill);
+
+        let mut shadow = Shadow::default();
+        shadow.set_on(true).set_color("black").set_obscured(true);
+        shape.set_shadow(shadow);
+
+        let mut path = Path::default();
+        path.set_connection_point_type(ConnectValues::None);
+        shape.set_path(path);
+
+        let mut text_box = TextBox::default();
+        text_box
+            .set_style("mso-direction-alt:auto")
+            .set_innder("<div style='text-align:left'></div>");
+        shape.set_text_box(text_box);
+
+        // the box is on the right of the cell. (zero-based)
+        let top_row = row.saturating_sub(2);
+        let top_offset = if row == 1 { 2 } else { 10 };
+        let mut anchor = Anchor::default();
+        anchor.set_left_column(col);
+        anchor.set_left_offset(15);
+        anchor.set_top_row(top_row);
+        anchor.set_top_offset(top_offset);
+        anchor.set_right_column(col + 2);
+        anchor.set_right_offset(15);
+        anchor.set_bottom_row(top_row + 4);
+        anchor.set_bottom_offset(if row == 1 { 4 } else { 10 });
+
+        let mut auto_fill = AutoFill::default();
+        auto_fill.set_value(false);
+        let mut comment_row_target = CommentRowTarget::default();
+        comment_row_target.set_value(row - 1);
+        let mut comment_column_target = CommentColumnTarget::default();
+        comment_column_target.set_value(col - 1);
+
+        shape
+            .get_client_data_mut()
+            .set_object_type(ObjectValues::Note)
+            .set_move_with_cells(MoveWithCells::default())
+            .set_resize_with_cells(ResizeWithCells::default())
+            .set_anchor(anchor)
+            .set_auto_fill(auto_fill)
+            .set_comment_row_target(comment_row_target)
+            .set_comment_column_target(comment_column_target);
+
+        self.shape = shape;
+        self
+    }
+
     pub(crate) fn adjustment_insert_coordinate(
         &mut self,
         root_col_num: &u32,
@@ -1,3 +1,4 @@
+use super::BooleanValue;
 use super::ConditionalFormatValueObjectValues;
 use super::EnumValue;
 use super::StringValue;
@@ -12,6 +13,7 @@ use writer::driver::*;
 pub struct ConditionalFormatValueObject {
     r#type: EnumValue<ConditionalFormatValueObjectValues>,
     val: StringValue,
+    gte: BooleanValue,
 }
 
 impl ConditionalFormatValueObject {
@@ -33,6 +35,19 @@ impl ConditionalFormatValueObject {
         self
     }
 
+    /// Get whether the threshold is inclusive. (icon sets only, default true)
+    pub fn get_gte(&self) -> &bool {
+        if self.gte.has_value() {
+            return self.gte.get_value();
+        }
+        &true
+    }
+
+    pub fn set_gte(&mut self, value: bool) -> &mut Self {
+        self.gte.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -41,16 +56,28 @@ impl ConditionalFormatValueObject {
     ) {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, gte, "gte");
 
         if empty_flg {
             return;
         }
 
+        let mut tag = Vec::new();
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                tag = e.name().into_inner().to_vec();
+            },
+            Event::Text(e) => {
+                if tag.as_slice() == b"xm:f" {
+                    self.val.set_value(e.unescape().unwrap().to_string());
+                }
+            },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"cfvo" {
-                    return
+                tag.clear();
+                match e.name().into_inner() {
+                    b"cfvo" | b"x14:cfvo" => return,
+                    _ => (),
                 }
             },
             Event::Eof => panic!("Error not find {} end element", "cfvo")
@@ -69,6 +96,37 @@ impl ConditionalFormatValueObject {
             attributes.push(("val", val));
         }
 
+        let gte = self.gte.get_value_string();
+        if self.gte.has_value() {
+            attributes.push(("gte", gte));
+        }
+
         write_start_tag(writer, "cfvo", attributes, true);
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfvo
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let ctype = self.r#type.get_value_string();
+        if self.r#type.has_value() {
+            attributes.push(("type", ctype));
+        }
+        let gte = self.gte.get_value_string();
+        if self.gte.has_value() {
+            attributes.push(("gte", gte));
+        }
+
+        if self.val.has_value() {
+            write_start_tag(writer, "x14:cfvo", attributes, false);
+
+            // xm:f
+            write_start_tag(writer, "xm:f", vec![], false);
+            write_text_node(writer, self.val.get_value_string());
+            write_end_tag(writer, "xm:f");
+
+            write_end_tag(writer, "x14:cfvo");
+        } else {
+            write_start_tag(writer, "x14:cfvo", attributes, true);
+        }
+    }
 }
@@ -2,6 +2,8 @@ use super::EnumTrait;
 use std::str::FromStr;
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum ConditionalFormatValueObjectValues {
+    AutoMax,
+    AutoMin,
     Formula,
     Max,
     Min,
@@ -17,6 +19,8 @@ impl Default for ConditionalFormatValueObjectValues {
 impl EnumTrait for ConditionalFormatValueObjectValues {
     fn get_value_string(&self) -> &str {
         match &self {
+            Self::AutoMax => "autoMax",
+            Self::AutoMin => "autoMin",
             Self::Formula => "formula",
             Self::Max => "max",
             Self::Min => "min",
@@ -30,6 +34,8 @@ impl FromStr for ConditionalFormatValueObjectValues {
     type Err = ();
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
+            "autoMax" => Ok(Self::AutoMax),
+            "autoMin" => Ok(Self::AutoMin),
             "formula" => Ok(Self::Formula),
             "max" => Ok(Self::Max),
             "min" => Ok(Self::Min),
@@ -85,11 +85,67 @@ impl ConditionalFormatting {
         );
     }
 
+    /// (This method is crate only.)
+    /// Read x14:conditionalFormatting. Returns false if any rule is not supported.
+    pub(crate) fn set_attributes_x14<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> bool {
+        let mut tag = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                tag = e.name().into_inner().to_vec();
+                if e.name().into_inner() == b"x14:cfRule" {
+                    let mut obj = ConditionalFormattingRule::default();
+                    if !obj.set_attributes_x14(reader, e) {
+                        return false;
+                    }
+                    self.conditional_collection.push(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"x14:cfRule" {
+                    return false;
+                }
+            },
+            Event::Text(e) => {
+                if tag.as_slice() == b"xm:sqref" {
+                    self.sequence_of_references
+                        .set_sqref(e.unescape().unwrap().to_string());
+                }
+            },
+            Event::End(ref e) => {
+                tag.clear();
+                if e.name().into_inner() == b"x14:conditionalFormatting" {
+                    return true;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "x14:conditionalFormatting")
+        );
+    }
+
+    /// (This method is crate only.)
+    /// Whether some rules are written in the x14 extension.
+    pub(crate) fn has_x14_rule(&self) -> bool {
+        self.conditional_collection
+            .iter()
+            .any(|v| v.is_x14() || v.has_x14_extension())
+    }
+
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        index: &mut usize,
     ) {
+        if !self.conditional_collection.is_empty()
+            && self.conditional_collection.iter().all(|v| v.is_x14())
+        {
+            *index += self.conditional_collection.len();
+            return;
+        }
         let is_inner = !self.conditional_collection.is_empty();
 
         // conditionalFormatting
@@ -103,10 +159,46 @@ impl ConditionalFormatting {
         if is_inner {
             // cfRule
             for v in &self.conditional_collection {
-                v.write_to(writer, differential_formats);
+                if !v.is_x14() {
+                    v.write_to(writer, differential_formats, *index);
+                }
+                *index += 1;
             }
 
             write_end_tag(writer, "conditionalFormatting");
         }
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>, index: &mut usize) {
+        if !self.has_x14_rule() {
+            *index += self.conditional_collection.len();
+            return;
+        }
+
+        // x14:conditionalFormatting
+        write_start_tag(
+            writer,
+            "x14:conditionalFormatting",
+            vec![(
+                "xmlns:xm",
+                "http://schemas.microsoft.com/office/excel/2006/main",
+            )],
+            false,
+        );
+
+        // x14:cfRule
+        for v in &self.conditional_collection {
+            if v.is_x14() || v.has_x14_extension() {
+                v.write_to_x14(writer, *index);
+            }
+            *index += 1;
+        }
+
+        // xm:sqref
+        write_start_tag(writer, "xm:sqref", vec![], false);
+        write_text_node(writer, self.sequence_of_references.get_sqref());
+        write_end_tag(writer, "xm:sqref");
+
+        write_end_tag(writer, "x14:conditionalFormatting");
+    }
 }
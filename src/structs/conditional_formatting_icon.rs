@@ -0,0 +1,56 @@
+// x14:cfIcon
+use super::EnumValue;
+use super::IconSetValues;
+use super::UInt32Value;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct ConditionalFormattingIcon {
+    icon_set: EnumValue<IconSetValues>,
+    icon_id: UInt32Value,
+}
+
+impl ConditionalFormattingIcon {
+    /// Get the icon set that the icon is taken from.
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    /// Get the zero-based position of the icon in the icon set.
+    pub fn get_icon_id(&self) -> &u32 {
+        self.icon_id.get_value()
+    }
+
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, icon_id, "iconId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfIcon
+        let icon_id = self.icon_id.get_value_string();
+        write_start_tag(
+            writer,
+            "x14:cfIcon",
+            vec![
+                ("iconSet", self.icon_set.get_value_string()),
+                ("iconId", &icon_id),
+            ],
+            true,
+        );
+    }
+}
@@ -39,6 +39,8 @@ pub struct ConditionalFormattingRule {
     data_bar: Option<DataBar>,
     icon_set: Option<IconSet>,
     formula: Option<Formula>,
+    formula2: Option<Formula>,
+    id: StringValue,
 }
 
 impl ConditionalFormattingRule {
@@ -124,7 +126,10 @@ impl ConditionalFormattingRule {
     }
 
     pub fn get_above_average(&self) -> &bool {
-        self.above_average.get_value()
+        if self.above_average.has_value() {
+            return self.above_average.get_value();
+        }
+        &true
     }
 
     pub fn set_above_average(&mut self, value: bool) -> &mut Self {
@@ -187,6 +192,10 @@ impl ConditionalFormattingRule {
         self
     }
 
+    pub fn get_data_bar_mut(&mut self) -> Option<&mut DataBar> {
+        self.data_bar.as_mut()
+    }
+
     pub fn remove_data_bar(&mut self) -> &mut Self {
         self.data_bar = None;
         self
@@ -220,6 +229,59 @@ impl ConditionalFormattingRule {
         self
     }
 
+    /// Get the second formula. (the upper bound of `between` and `notBetween`)
+    pub fn get_formula2(&self) -> &Option<Formula> {
+        &self.formula2
+    }
+
+    pub fn set_formula2(&mut self, value: Formula) -> &mut Self {
+        self.formula2 = Some(value);
+        self
+    }
+
+    pub fn remove_formula2(&mut self) -> &mut Self {
+        self.formula2 = None;
+        self
+    }
+
+    /// Get the id that links the rule to the x14 extension.
+    pub fn get_id(&self) -> &str {
+        self.id.get_value()
+    }
+
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value.into());
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Whether the rule can only be written in the x14 extension.
+    pub(crate) fn is_x14(&self) -> bool {
+        match &self.icon_set {
+            Some(v) => v.is_x14(),
+            None => false,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Whether the rule has additional settings in the x14 extension.
+    pub(crate) fn has_x14_extension(&self) -> bool {
+        match &self.data_bar {
+            Some(v) => v.has_x14(),
+            None => false,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Get the id of the rule in the x14 extension.
+    /// A rule without id is given one from its position in the worksheet.
+    pub(crate) fn get_x14_id(&self, index: usize) -> String {
+        if self.id.has_value() {
+            return self.id.get_value_string().to_string();
+        }
+        format!("{{00000000-0000-0000-0000-{:012X}}}", index + 1)
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -229,6 +291,7 @@ impl ConditionalFormattingRule {
     ) {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, operator, "operator");
+        set_string_from_xml!(self, e, text, "text");
 
         if let Some(v) = get_attribute(e, b"dxfId") {
             let dxf_id = v.parse::<usize>().unwrap();
@@ -250,9 +313,11 @@ impl ConditionalFormattingRule {
             return;
         }
 
+        let mut tag = Vec::new();
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
+                tag = e.name().into_inner().to_vec();
                 match e.name().into_inner() {
                     b"colorScale" => {
                         let mut obj = ColorScale::default();
@@ -261,23 +326,32 @@ impl ConditionalFormattingRule {
                     }
                     b"dataBar" => {
                         let mut obj = DataBar::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e, false);
                         self.data_bar = Some(obj);
                     }
                     b"iconSet" => {
                         let mut obj = IconSet::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e, false);
                         self.icon_set = Some(obj);
                     }
                     b"formula" => {
                         let mut obj = Formula::default();
                         obj.set_attributes(reader, e);
-                        self.formula = Some(obj);
+                        match self.formula {
+                            Some(_) => self.formula2 = Some(obj),
+                            None => self.formula = Some(obj),
+                        }
                     }
                     _ => (),
                 }
             },
+            Event::Text(e) => {
+                if tag.as_slice() == b"x14:id" {
+                    self.id.set_value(e.unescape().unwrap().to_string());
+                }
+            },
             Event::End(ref e) => {
+                tag.clear();
                 if e.name().into_inner() == b"cfRule" {
                     return
                 }
@@ -286,15 +360,74 @@ impl ConditionalFormattingRule {
         );
     }
 
+    /// (This method is crate only.)
+    /// Read x14:cfRule. Returns false if the rule is not supported.
+    pub(crate) fn set_attributes_x14<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> bool {
+        set_string_from_xml!(self, e, r#type, "type");
+        match self.r#type.get_value() {
+            ConditionalFormatValues::IconSet | ConditionalFormatValues::DataBar => {}
+            _ => return false,
+        }
+        set_string_from_xml!(self, e, priority, "priority");
+        set_string_from_xml!(self, e, stop_if_true, "stopIfTrue");
+        set_string_from_xml!(self, e, id, "id");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:iconSet" => {
+                        let mut obj = IconSet::default();
+                        obj.set_attributes(reader, e, false);
+                        self.icon_set = Some(obj);
+                    }
+                    b"x14:dataBar" => {
+                        let mut obj = DataBar::default();
+                        obj.set_attributes(reader, e, false);
+                        self.data_bar = Some(obj);
+                    }
+                    _ => return false,
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:iconSet" => {
+                        let mut obj = IconSet::default();
+                        obj.set_attributes(reader, e, true);
+                        self.icon_set = Some(obj);
+                    }
+                    b"x14:dataBar" => {
+                        let mut obj = DataBar::default();
+                        obj.set_attributes(reader, e, true);
+                        self.data_bar = Some(obj);
+                    }
+                    _ => return false,
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:cfRule" {
+                    return true;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "x14:cfRule")
+        );
+    }
+
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        index: usize,
     ) {
         let is_inner = self.color_scale.is_some()
             || self.data_bar.is_some()
             || self.icon_set.is_some()
             || self.formula.is_some();
+        let has_x14_extension = self.has_x14_extension();
 
         // cfRule
         let mut attributes: Vec<(&str, &str)> = Vec::new();
@@ -309,6 +442,11 @@ impl ConditionalFormattingRule {
             attributes.push(("operator", operator));
         }
 
+        let text = self.text.get_value_string();
+        if self.text.has_value() {
+            attributes.push(("text", text));
+        }
+
         let dxf_id_str: String;
         match &self.style {
             Some(v) => {
@@ -390,8 +528,77 @@ impl ConditionalFormattingRule {
                 Some(v) => v.write_to(writer),
                 None => {}
             }
+            match &self.formula2 {
+                Some(v) => v.write_to(writer),
+                None => {}
+            }
+
+            // extLst
+            if has_x14_extension {
+                write_start_tag(writer, "extLst", vec![], false);
+                write_start_tag(
+                    writer,
+                    "ext",
+                    vec![
+                        ("uri", "{B025F937-C7B1-47D3-B67F-A62EFF666E3E}"),
+                        (
+                            "xmlns:x14",
+                            "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+                        ),
+                    ],
+                    false,
+                );
+                write_start_tag(writer, "x14:id", vec![], false);
+                write_text_node(writer, self.get_x14_id(index));
+                write_end_tag(writer, "x14:id");
+                write_end_tag(writer, "ext");
+                write_end_tag(writer, "extLst");
+            }
 
             write_end_tag(writer, "cfRule");
         }
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>, index: usize) {
+        // x14:cfRule
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+
+        let r#type = self.r#type.get_value_string();
+        if self.r#type.has_value() {
+            attributes.push(("type", r#type));
+        }
+
+        // the priority of a rule linked to the main part is written there.
+        let priority = self.priority.get_value_string();
+        let stop_if_true = self.stop_if_true.get_value_string();
+        if self.is_x14() {
+            if self.priority.has_value() {
+                attributes.push(("priority", &priority));
+            }
+            if self.stop_if_true.has_value() {
+                attributes.push(("stopIfTrue", stop_if_true));
+            }
+        }
+
+        let id = self.get_x14_id(index);
+        attributes.push(("id", &id));
+
+        write_start_tag(writer, "x14:cfRule", attributes, false);
+
+        if self.is_x14() {
+            // x14:iconSet
+            match &self.icon_set {
+                Some(v) => v.write_to_x14(writer),
+                None => {}
+            }
+        } else {
+            // x14:dataBar
+            match &self.data_bar {
+                Some(v) => v.write_to_x14(writer),
+                None => {}
+            }
+        }
+
+        write_end_tag(writer, "x14:cfRule");
+    }
 }
@@ -0,0 +1,133 @@
+// property
+use super::CustomPropertyValue;
+use super::StringValue;
+use super::UInt32Value;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Format id of the user defined custom properties.
+pub(crate) const CUSTOM_PROPERTY_FMTID: &str = "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}";
+
+/// Custom document property. (docProps/custom.xml)
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+pub struct CustomProperty {
+    name: StringValue,
+    fmtid: StringValue,
+    pid: UInt32Value,
+    link_target: StringValue,
+    value: CustomPropertyValue,
+}
+impl CustomProperty {
+    pub fn get_name(&self) -> &str {
+        self.name.get_value()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Get the format id. The user defined properties use `{D5CDD505-2E9C-101B-9397-08002B2CF9AE}`.
+    pub fn get_fmtid(&self) -> &str {
+        match self.fmtid.has_value() {
+            true => self.fmtid.get_value(),
+            false => CUSTOM_PROPERTY_FMTID,
+        }
+    }
+
+    pub fn set_fmtid<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.fmtid.set_value(value);
+        self
+    }
+
+    /// Get the property id. It starts at 2 and is set when the property is added to `Properties`.
+    pub fn get_pid(&self) -> &u32 {
+        self.pid.get_value()
+    }
+
+    pub fn set_pid(&mut self, value: u32) -> &mut Self {
+        self.pid.set_value(value);
+        self
+    }
+
+    /// Get the name of the bookmark or defined name linked to the property.
+    pub fn get_link_target(&self) -> &str {
+        self.link_target.get_value()
+    }
+
+    pub fn set_link_target<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.link_target.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &CustomPropertyValue {
+        &self.value
+    }
+
+    pub fn set_value<V: Into<CustomPropertyValue>>(&mut self, value: V) -> &mut Self {
+        self.value = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, fmtid, "fmtid");
+        set_string_from_xml!(self, e, pid, "pid");
+        set_string_from_xml!(self, e, link_target, "linkTarget");
+
+        if empty_flag {
+            return;
+        }
+
+        let mut tag = String::new();
+        let mut text = String::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                tag = String::from_utf8_lossy(e.name().into_inner()).to_string();
+                text.clear();
+            },
+            Event::Text(e) => text.push_str(&e.unescape().unwrap()),
+            Event::End(ref e) => {
+                let name = e.name().into_inner();
+                if name == b"property" {
+                    return;
+                }
+                if name == tag.as_bytes() {
+                    self.value = CustomPropertyValue::from_variant(&tag, &text);
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "property")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, pid: &u32) {
+        // property
+        let pid_str = pid.to_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("fmtid", self.get_fmtid()));
+        attributes.push(("pid", &pid_str));
+        attributes.push(("name", self.name.get_value_string()));
+        if self.link_target.has_value() {
+            attributes.push(("linkTarget", self.link_target.get_value_string()));
+        }
+        write_start_tag(writer, "property", attributes, false);
+
+        // vt:*
+        let (tag, text) = self.value.to_variant();
+        write_start_tag(writer, tag, vec![], false);
+        write_text_node(writer, text);
+        write_end_tag(writer, tag);
+
+        write_end_tag(writer, "property");
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDateTime;
+
+/// Value of a custom document property.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub enum CustomPropertyValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Date(NaiveDateTime),
+}
+impl Default for CustomPropertyValue {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+impl CustomPropertyValue {
+    pub fn get_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_date(&self) -> Option<&NaiveDateTime> {
+        match self {
+            Self::Date(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Read the value from the variant type element. ex) `vt:lpwstr`, `vt:i4`, `vt:filetime`
+    pub(crate) fn from_variant(tag: &str, value: &str) -> Self {
+        match tag {
+            "vt:bool" => Self::Bool(value == "true" || value == "1"),
+            "vt:filetime" | "vt:date" => {
+                match NaiveDateTime::parse_from_str(
+                    value.trim_end_matches('Z'),
+                    "%Y-%m-%dT%H:%M:%S%.f",
+                ) {
+                    Ok(v) => Self::Date(v),
+                    Err(_) => Self::Text(value.to_string()),
+                }
+            }
+            "vt:i1" | "vt:i2" | "vt:i4" | "vt:i8" | "vt:int" | "vt:ui1" | "vt:ui2" | "vt:ui4"
+            | "vt:ui8" | "vt:uint" | "vt:r4" | "vt:r8" | "vt:decimal" => match value.parse() {
+                Ok(v) => Self::Number(v),
+                Err(_) => Self::Text(value.to_string()),
+            },
+            _ => Self::Text(value.to_string()),
+        }
+    }
+
+    /// (This method is crate only.)
+    /// `(variant type element, text)` to write the value.
+    pub(crate) fn to_variant(&self) -> (&str, String) {
+        match self {
+            Self::Text(v) => ("vt:lpwstr", v.clone()),
+            Self::Number(v) if v.fract() == 0f64 && v.abs() <= i32::MAX as f64 => {
+                ("vt:i4", v.to_string())
+            }
+            Self::Number(v) => ("vt:r8", v.to_string()),
+            Self::Bool(v) => ("vt:bool", v.to_string()),
+            Self::Date(v) => ("vt:filetime", v.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+        }
+    }
+}
+impl From<&str> for CustomPropertyValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+impl From<String> for CustomPropertyValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<f64> for CustomPropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+impl From<i32> for CustomPropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Number(value as f64)
+    }
+}
+impl From<bool> for CustomPropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+impl From<NaiveDateTime> for CustomPropertyValue {
+    fn from(value: NaiveDateTime) -> Self {
+        Self::Date(value)
+    }
+}
@@ -1,5 +1,12 @@
+// dataBar, x14:dataBar
+use super::BooleanValue;
 use super::Color;
 use super::ConditionalFormatValueObject;
+use super::ConditionalFormatValueObjectValues;
+use super::DataBarAxisPositionValues;
+use super::DataBarDirectionValues;
+use super::EnumValue;
+use super::UInt32Value;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -10,11 +17,156 @@ use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
 pub struct DataBar {
+    min_length: UInt32Value,
+    max_length: UInt32Value,
+    x14_min_length: UInt32Value,
+    x14_max_length: UInt32Value,
+    show_value: BooleanValue,
+    border: BooleanValue,
+    gradient: BooleanValue,
+    direction: EnumValue<DataBarDirectionValues>,
+    negative_bar_color_same_as_positive: BooleanValue,
+    negative_bar_border_color_same_as_positive: BooleanValue,
+    axis_position: EnumValue<DataBarAxisPositionValues>,
     cfvo_collection: Vec<ConditionalFormatValueObject>,
     color_collection: Vec<Color>,
+    x14_cfvo_collection: Vec<ConditionalFormatValueObject>,
+    fill_color: Option<Color>,
+    border_color: Option<Color>,
+    negative_fill_color: Option<Color>,
+    negative_border_color: Option<Color>,
+    axis_color: Option<Color>,
 }
 
 impl DataBar {
+    /// Get the length of the shortest bar in percent of the cell width. (default 10)
+    pub fn get_min_length(&self) -> &u32 {
+        if self.min_length.has_value() {
+            return self.min_length.get_value();
+        }
+        &10
+    }
+
+    pub fn set_min_length(&mut self, value: u32) -> &mut Self {
+        self.min_length.set_value(value);
+        self
+    }
+
+    /// Get the length of the longest bar in percent of the cell width. (default 90)
+    pub fn get_max_length(&self) -> &u32 {
+        if self.max_length.has_value() {
+            return self.max_length.get_value();
+        }
+        &90
+    }
+
+    pub fn set_max_length(&mut self, value: u32) -> &mut Self {
+        self.max_length.set_value(value);
+        self
+    }
+
+    /// Get the length of the shortest bar of the x14 extension.
+    /// If not set, the length of the data bar is used.
+    pub fn get_x14_min_length(&self) -> &u32 {
+        if self.x14_min_length.has_value() {
+            return self.x14_min_length.get_value();
+        }
+        self.get_min_length()
+    }
+
+    pub fn set_x14_min_length(&mut self, value: u32) -> &mut Self {
+        self.x14_min_length.set_value(value);
+        self
+    }
+
+    /// Get the length of the longest bar of the x14 extension.
+    /// If not set, the length of the data bar is used.
+    pub fn get_x14_max_length(&self) -> &u32 {
+        if self.x14_max_length.has_value() {
+            return self.x14_max_length.get_value();
+        }
+        self.get_max_length()
+    }
+
+    pub fn set_x14_max_length(&mut self, value: u32) -> &mut Self {
+        self.x14_max_length.set_value(value);
+        self
+    }
+
+    pub fn get_show_value(&self) -> &bool {
+        if self.show_value.has_value() {
+            return self.show_value.get_value();
+        }
+        &true
+    }
+
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    pub fn get_border(&self) -> &bool {
+        self.border.get_value()
+    }
+
+    pub fn set_border(&mut self, value: bool) -> &mut Self {
+        self.border.set_value(value);
+        self
+    }
+
+    /// Get whether the bar has a gradient fill. (default true)
+    pub fn get_gradient(&self) -> &bool {
+        if self.gradient.has_value() {
+            return self.gradient.get_value();
+        }
+        &true
+    }
+
+    pub fn set_gradient(&mut self, value: bool) -> &mut Self {
+        self.gradient.set_value(value);
+        self
+    }
+
+    pub fn get_direction(&self) -> &DataBarDirectionValues {
+        self.direction.get_value()
+    }
+
+    pub fn set_direction(&mut self, value: DataBarDirectionValues) -> &mut Self {
+        self.direction.set_value(value);
+        self
+    }
+
+    pub fn get_negative_bar_color_same_as_positive(&self) -> &bool {
+        self.negative_bar_color_same_as_positive.get_value()
+    }
+
+    pub fn set_negative_bar_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_color_same_as_positive.set_value(value);
+        self
+    }
+
+    pub fn get_negative_bar_border_color_same_as_positive(&self) -> &bool {
+        if self.negative_bar_border_color_same_as_positive.has_value() {
+            return self.negative_bar_border_color_same_as_positive.get_value();
+        }
+        &true
+    }
+
+    pub fn set_negative_bar_border_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_border_color_same_as_positive
+            .set_value(value);
+        self
+    }
+
+    pub fn get_axis_position(&self) -> &DataBarAxisPositionValues {
+        self.axis_position.get_value()
+    }
+
+    pub fn set_axis_position(&mut self, value: DataBarAxisPositionValues) -> &mut Self {
+        self.axis_position.set_value(value);
+        self
+    }
+
     pub fn get_cfvo_collection(&self) -> &Vec<ConditionalFormatValueObject> {
         &self.cfvo_collection
     }
@@ -43,11 +195,165 @@ impl DataBar {
         self
     }
 
+    /// Get the thresholds of the x14 extension.
+    /// If empty, they are made from the cfvo collection. (min and max become autoMin and autoMax)
+    pub fn get_x14_cfvo_collection(&self) -> &Vec<ConditionalFormatValueObject> {
+        &self.x14_cfvo_collection
+    }
+
+    pub fn set_x14_cfvo_collection(
+        &mut self,
+        value: Vec<ConditionalFormatValueObject>,
+    ) -> &mut Self {
+        self.x14_cfvo_collection = value;
+        self
+    }
+
+    pub fn add_x14_cfvo_collection(&mut self, value: ConditionalFormatValueObject) -> &mut Self {
+        self.x14_cfvo_collection.push(value);
+        self
+    }
+
+    pub fn get_fill_color(&self) -> &Option<Color> {
+        &self.fill_color
+    }
+
+    pub fn set_fill_color(&mut self, value: Color) -> &mut Self {
+        self.fill_color = Some(value);
+        self
+    }
+
+    pub fn remove_fill_color(&mut self) -> &mut Self {
+        self.fill_color = None;
+        self
+    }
+
+    pub fn get_border_color(&self) -> &Option<Color> {
+        &self.border_color
+    }
+
+    pub fn set_border_color(&mut self, value: Color) -> &mut Self {
+        self.border_color = Some(value);
+        self
+    }
+
+    pub fn remove_border_color(&mut self) -> &mut Self {
+        self.border_color = None;
+        self
+    }
+
+    pub fn get_negative_fill_color(&self) -> &Option<Color> {
+        &self.negative_fill_color
+    }
+
+    pub fn set_negative_fill_color(&mut self, value: Color) -> &mut Self {
+        self.negative_fill_color = Some(value);
+        self
+    }
+
+    pub fn remove_negative_fill_color(&mut self) -> &mut Self {
+        self.negative_fill_color = None;
+        self
+    }
+
+    pub fn get_negative_border_color(&self) -> &Option<Color> {
+        &self.negative_border_color
+    }
+
+    pub fn set_negative_border_color(&mut self, value: Color) -> &mut Self {
+        self.negative_border_color = Some(value);
+        self
+    }
+
+    pub fn remove_negative_border_color(&mut self) -> &mut Self {
+        self.negative_border_color = None;
+        self
+    }
+
+    pub fn get_axis_color(&self) -> &Option<Color> {
+        &self.axis_color
+    }
+
+    pub fn set_axis_color(&mut self, value: Color) -> &mut Self {
+        self.axis_color = Some(value);
+        self
+    }
+
+    pub fn remove_axis_color(&mut self) -> &mut Self {
+        self.axis_color = None;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Whether the data bar has settings of the x14 extension.
+    pub(crate) fn has_x14(&self) -> bool {
+        self.x14_min_length.has_value()
+            || self.x14_max_length.has_value()
+            || self.border.has_value()
+            || self.gradient.has_value()
+            || self.direction.has_value()
+            || self.negative_bar_color_same_as_positive.has_value()
+            || self.negative_bar_border_color_same_as_positive.has_value()
+            || self.axis_position.has_value()
+            || !self.x14_cfvo_collection.is_empty()
+            || self.fill_color.is_some()
+            || self.border_color.is_some()
+            || self.negative_fill_color.is_some()
+            || self.negative_border_color.is_some()
+            || self.axis_color.is_some()
+    }
+
+    /// (This method is crate only.)
+    /// Take the settings of the x14 extension from a data bar read from x14:dataBar.
+    /// The lengths are kept apart from the lengths of the data bar.
+    pub(crate) fn merge_x14(&mut self, x14: DataBar) {
+        self.x14_min_length = x14.min_length;
+        self.x14_max_length = x14.max_length;
+        self.border = x14.border;
+        self.gradient = x14.gradient;
+        self.direction = x14.direction;
+        self.negative_bar_color_same_as_positive = x14.negative_bar_color_same_as_positive;
+        self.negative_bar_border_color_same_as_positive =
+            x14.negative_bar_border_color_same_as_positive;
+        self.axis_position = x14.axis_position;
+        self.x14_cfvo_collection = x14.x14_cfvo_collection;
+        self.fill_color = x14.fill_color;
+        self.border_color = x14.border_color;
+        self.negative_fill_color = x14.negative_fill_color;
+        self.negative_border_color = x14.negative_border_color;
+        self.axis_color = x14.axis_color;
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
+        e: &BytesStart,
+        empty_flag: bool,
     ) {
+        set_string_from_xml!(self, e, min_length, "minLength");
+        set_string_from_xml!(self, e, max_length, "maxLength");
+        set_string_from_xml!(self, e, show_value, "showValue");
+        set_string_from_xml!(self, e, border, "border");
+        set_string_from_xml!(self, e, gradient, "gradient");
+        set_string_from_xml!(self, e, direction, "direction");
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_color_same_as_positive,
+            "negativeBarColorSameAsPositive"
+        );
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_border_color_same_as_positive,
+            "negativeBarBorderColorSameAsPositive"
+        );
+        set_string_from_xml!(self, e, axis_position, "axisPosition");
+
+        if empty_flag {
+            return;
+        }
+
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -57,17 +363,55 @@ impl DataBar {
                         obj.set_attributes(reader, e, true);
                         self.cfvo_collection.push(obj);
                     }
+                    b"x14:cfvo" => {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_attributes(reader, e, true);
+                        self.x14_cfvo_collection.push(obj);
+                    }
                     b"color" => {
                         let mut obj = Color::default();
                         obj.set_attributes(reader, e, true);
                         self.color_collection.push(obj);
                     }
+                    b"x14:fillColor" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, true);
+                        self.fill_color = Some(obj);
+                    }
+                    b"x14:borderColor" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, true);
+                        self.border_color = Some(obj);
+                    }
+                    b"x14:negativeFillColor" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, true);
+                        self.negative_fill_color = Some(obj);
+                    }
+                    b"x14:negativeBorderColor" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, true);
+                        self.negative_border_color = Some(obj);
+                    }
+                    b"x14:axisColor" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, true);
+                        self.axis_color = Some(obj);
+                    }
                     _ => (),
                 }
             },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"x14:cfvo" {
+                    let mut obj = ConditionalFormatValueObject::default();
+                    obj.set_attributes(reader, e, false);
+                    self.x14_cfvo_collection.push(obj);
+                }
+            },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"dataBar" {
-                    return
+                match e.name().into_inner() {
+                    b"dataBar" | b"x14:dataBar" => return,
+                    _ => (),
                 }
             },
             Event::Eof => panic!("Error not find {} end element", "dataBar")
@@ -76,7 +420,19 @@ impl DataBar {
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let min_length = self.min_length.get_value_string();
+        if self.min_length.has_value() {
+            attributes.push(("minLength", &min_length));
+        }
+        let max_length = self.max_length.get_value_string();
+        if self.max_length.has_value() {
+            attributes.push(("maxLength", &max_length));
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.get_value_string()));
+        }
+        write_start_tag(writer, "dataBar", attributes, false);
 
         // cfvo
         for v in &self.cfvo_collection {
@@ -90,4 +446,94 @@ impl DataBar {
 
         write_end_tag(writer, "dataBar");
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:dataBar
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let min_length = self.get_x14_min_length().to_string();
+        if self.x14_min_length.has_value() || self.min_length.has_value() {
+            attributes.push(("minLength", &min_length));
+        }
+        let max_length = self.get_x14_max_length().to_string();
+        if self.x14_max_length.has_value() || self.max_length.has_value() {
+            attributes.push(("maxLength", &max_length));
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.get_value_string()));
+        }
+        if self.border.has_value() {
+            attributes.push(("border", self.border.get_value_string()));
+        }
+        if self.gradient.has_value() {
+            attributes.push(("gradient", self.gradient.get_value_string()));
+        }
+        if self.direction.has_value() {
+            attributes.push(("direction", self.direction.get_value_string()));
+        }
+        if self.negative_bar_color_same_as_positive.has_value() {
+            attributes.push((
+                "negativeBarColorSameAsPositive",
+                self.negative_bar_color_same_as_positive.get_value_string(),
+            ));
+        }
+        if self.negative_bar_border_color_same_as_positive.has_value() {
+            attributes.push((
+                "negativeBarBorderColorSameAsPositive",
+                self.negative_bar_border_color_same_as_positive
+                    .get_value_string(),
+            ));
+        }
+        if self.axis_position.has_value() {
+            attributes.push(("axisPosition", self.axis_position.get_value_string()));
+        }
+        write_start_tag(writer, "x14:dataBar", attributes, false);
+
+        // x14:cfvo
+        if self.x14_cfvo_collection.is_empty() {
+            for v in &self.cfvo_collection {
+                let mut obj = v.clone();
+                match v.get_type() {
+                    ConditionalFormatValueObjectValues::Min => {
+                        obj.set_type(ConditionalFormatValueObjectValues::AutoMin);
+                    }
+                    ConditionalFormatValueObjectValues::Max => {
+                        obj.set_type(ConditionalFormatValueObjectValues::AutoMax);
+                    }
+                    _ => {}
+                }
+                obj.write_to_x14(writer);
+            }
+        } else {
+            for v in &self.x14_cfvo_collection {
+                v.write_to_x14(writer);
+            }
+        }
+
+        // x14:fillColor
+        if let Some(v) = &self.fill_color {
+            v.write_to(writer, "x14:fillColor");
+        }
+
+        // x14:borderColor
+        if let Some(v) = &self.border_color {
+            v.write_to(writer, "x14:borderColor");
+        }
+
+        // x14:negativeFillColor
+        if let Some(v) = &self.negative_fill_color {
+            v.write_to(writer, "x14:negativeFillColor");
+        }
+
+        // x14:negativeBorderColor
+        if let Some(v) = &self.negative_border_color {
+            v.write_to(writer, "x14:negativeBorderColor");
+        }
+
+        // x14:axisColor
+        if let Some(v) = &self.axis_color {
+            v.write_to(writer, "x14:axisColor");
+        }
+
+        write_end_tag(writer, "x14:dataBar");
+    }
 }
@@ -1,5 +1,7 @@
-// dataValidation
+// dataValidation, x14:dataValidation
 use super::BooleanValue;
+use super::DataValidationErrorStyleValues;
+use super::DataValidationImeModeValues;
 use super::DataValidationOperatorValues;
 use super::DataValidationValues;
 use super::EnumValue;
@@ -16,10 +18,15 @@ use writer::driver::*;
 #[derive(Default, Debug, Clone)]
 pub struct DataValidation {
     r#type: EnumValue<DataValidationValues>,
+    error_style: EnumValue<DataValidationErrorStyleValues>,
+    ime_mode: EnumValue<DataValidationImeModeValues>,
     operator: EnumValue<DataValidationOperatorValues>,
     allow_blank: BooleanValue,
+    show_drop_down: BooleanValue,
     show_input_message: BooleanValue,
     show_error_message: BooleanValue,
+    error_title: StringValue,
+    error: StringValue,
     prompt_title: StringValue,
     prompt: StringValue,
     sequence_of_references: SequenceOfReferences,
@@ -36,6 +43,24 @@ impl DataValidation {
         self
     }
 
+    pub fn get_error_style(&self) -> &DataValidationErrorStyleValues {
+        self.error_style.get_value()
+    }
+
+    pub fn set_error_style(&mut self, value: DataValidationErrorStyleValues) -> &mut Self {
+        self.error_style.set_value(value);
+        self
+    }
+
+    pub fn get_ime_mode(&self) -> &DataValidationImeModeValues {
+        self.ime_mode.get_value()
+    }
+
+    pub fn set_ime_mode(&mut self, value: DataValidationImeModeValues) -> &mut Self {
+        self.ime_mode.set_value(value);
+        self
+    }
+
     pub fn get_operator(&self) -> &DataValidationOperatorValues {
         self.operator.get_value()
     }
@@ -54,6 +79,17 @@ impl DataValidation {
         self
     }
 
+    /// Get the flag to hide the in-cell drop down of a list.
+    /// (Despite the name, `true` means the drop down is not shown.)
+    pub fn get_show_drop_down(&self) -> &bool {
+        self.show_drop_down.get_value()
+    }
+
+    pub fn set_show_drop_down(&mut self, value: bool) -> &mut Self {
+        self.show_drop_down.set_value(value);
+        self
+    }
+
     pub fn get_show_input_message(&self) -> &bool {
         self.show_input_message.get_value()
     }
@@ -72,6 +108,24 @@ impl DataValidation {
         self
     }
 
+    pub fn get_error_title(&self) -> &str {
+        self.error_title.get_value()
+    }
+
+    pub fn set_error_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.error_title.set_value(value);
+        self
+    }
+
+    pub fn get_error(&self) -> &str {
+        self.error.get_value()
+    }
+
+    pub fn set_error<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.error.set_value(value);
+        self
+    }
+
     pub fn get_prompt_title(&self) -> &str {
         self.prompt_title.get_value()
     }
@@ -131,6 +185,14 @@ impl DataValidation {
             self.r#type.set_value_string(v);
         }
 
+        if let Some(v) = get_attribute(e, b"errorStyle") {
+            self.error_style.set_value_string(v);
+        }
+
+        if let Some(v) = get_attribute(e, b"imeMode") {
+            self.ime_mode.set_value_string(v);
+        }
+
         if let Some(v) = get_attribute(e, b"operator") {
             self.operator.set_value_string(v);
         }
@@ -139,6 +201,10 @@ impl DataValidation {
             self.allow_blank.set_value_string(v);
         }
 
+        if let Some(v) = get_attribute(e, b"showDropDown") {
+            self.show_drop_down.set_value_string(v);
+        }
+
         if let Some(v) = get_attribute(e, b"showInputMessage") {
             self.show_input_message.set_value_string(v);
         }
@@ -147,6 +213,14 @@ impl DataValidation {
             self.show_error_message.set_value_string(v);
         }
 
+        if let Some(v) = get_attribute(e, b"errorTitle") {
+            self.error_title.set_value_string(v);
+        }
+
+        if let Some(v) = get_attribute(e, b"error") {
+            self.error.set_value_string(v);
+        }
+
         if let Some(v) = get_attribute(e, b"promptTitle") {
             self.prompt_title.set_value_string(v);
         }
@@ -171,15 +245,19 @@ impl DataValidation {
                     value = e.unescape().unwrap().to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"formula1" => {
+                    b"formula1" | b"x14:formula1" => {
                         self.formula1.set_value_string(value);
                         value = String::from("");
                     }
-                    b"formula2" => {
+                    b"formula2" | b"x14:formula2" => {
                         self.formula2.set_value_string(value);
                         value = String::from("");
                     }
-                    b"dataValidation" => return,
+                    b"xm:sqref" => {
+                        self.sequence_of_references.set_sqref(value);
+                        value = String::from("");
+                    }
+                    b"dataValidation" | b"x14:dataValidation" => return,
                     _ => (),
                 },
                 Ok(Event::Eof) => panic!("Error not find {} end element", "dataValidation"),
@@ -190,20 +268,98 @@ impl DataValidation {
         }
     }
 
+    /// (This method is crate only.)
+    /// Whether the formulas refer to another sheet.
+    /// Such data validations are written in the x14 extension.
+    pub(crate) fn is_x14(&self) -> bool {
+        // ignore the exclamation marks in a string.
+        let has_sheet = |formula: &str| formula.split('"').step_by(2).any(|v| v.contains('!'));
+        has_sheet(self.formula1.get_value()) || has_sheet(self.formula2.get_value())
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         let is_inner = self.formula1.has_value() || self.formula2.has_value();
 
         // dataValidation
+        let mut attributes = self.get_attributes();
+        let sequence_of_references = &self.sequence_of_references.get_sqref();
+        if !sequence_of_references.is_empty() {
+            attributes.push(("sqref", sequence_of_references));
+        }
+
+        write_start_tag(writer, "dataValidation", attributes, !is_inner);
+        if is_inner {
+            if self.formula1.has_value() {
+                write_start_tag(writer, "formula1", vec![], false);
+                write_text_node(writer, self.formula1.get_value_string());
+                write_end_tag(writer, "formula1");
+            }
+            if self.formula2.has_value() {
+                write_start_tag(writer, "formula2", vec![], false);
+                write_text_node(writer, self.formula2.get_value_string());
+                write_end_tag(writer, "formula2");
+            }
+            write_end_tag(writer, "dataValidation");
+        }
+    }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:dataValidation
+        write_start_tag(writer, "x14:dataValidation", self.get_attributes(), false);
+
+        // x14:formula1
+        if self.formula1.has_value() {
+            write_start_tag(writer, "x14:formula1", vec![], false);
+            write_start_tag(writer, "xm:f", vec![], false);
+            write_text_node(writer, self.formula1.get_value_string());
+            write_end_tag(writer, "xm:f");
+            write_end_tag(writer, "x14:formula1");
+        }
+
+        // x14:formula2
+        if self.formula2.has_value() {
+            write_start_tag(writer, "x14:formula2", vec![], false);
+            write_start_tag(writer, "xm:f", vec![], false);
+            write_text_node(writer, self.formula2.get_value_string());
+            write_end_tag(writer, "xm:f");
+            write_end_tag(writer, "x14:formula2");
+        }
+
+        // xm:sqref
+        write_start_tag(writer, "xm:sqref", vec![], false);
+        write_text_node(writer, self.sequence_of_references.get_sqref());
+        write_end_tag(writer, "xm:sqref");
+
+        write_end_tag(writer, "x14:dataValidation");
+    }
+
+    fn get_attributes(&self) -> Vec<(&str, &str)> {
         let mut attributes: Vec<(&str, &str)> = Vec::new();
 
         if self.r#type.has_value() {
             attributes.push(("type", self.r#type.get_value_string()));
         }
 
+        if self.error_style.has_value() {
+            attributes.push(("errorStyle", self.error_style.get_value_string()));
+        }
+
+        if self.ime_mode.has_value() {
+            attributes.push(("imeMode", self.ime_mode.get_value_string()));
+        }
+
+        if self.operator.has_value() {
+            attributes.push(("operator", self.operator.get_value_string()));
+        }
+
         if self.allow_blank.has_value() {
             attributes.push(("allowBlank", self.allow_blank.get_value_string()));
         }
 
+        if self.show_drop_down.has_value() {
+            attributes.push(("showDropDown", self.show_drop_down.get_value_string()));
+        }
+
         if self.show_input_message.has_value() {
             attributes.push((
                 "showInputMessage",
@@ -211,10 +367,6 @@ impl DataValidation {
             ));
         }
 
-        if self.operator.has_value() {
-            attributes.push(("operator", self.operator.get_value_string()));
-        }
-
         if self.show_error_message.has_value() {
             attributes.push((
                 "showErrorMessage",
@@ -222,6 +374,14 @@ impl DataValidation {
             ));
         }
 
+        if self.error_title.has_value() {
+            attributes.push(("errorTitle", self.error_title.get_value_string()));
+        }
+
+        if self.error.has_value() {
+            attributes.push(("error", self.error.get_value_string()));
+        }
+
         if self.prompt_title.has_value() {
             attributes.push(("promptTitle", self.prompt_title.get_value_string()));
         }
@@ -230,24 +390,6 @@ impl DataValidation {
             attributes.push(("prompt", self.prompt.get_value_string()));
         }
 
-        let sequence_of_references = &self.sequence_of_references.get_sqref();
-        if !sequence_of_references.is_empty() {
-            attributes.push(("sqref", sequence_of_references));
-        }
-
-        write_start_tag(writer, "dataValidation", attributes, !is_inner);
-        if is_inner {
-            if self.formula1.has_value() {
-                write_start_tag(writer, "formula1", vec![], false);
-                write_text_node(writer, self.formula1.get_value_string());
-                write_end_tag(writer, "formula1");
-            }
-            if self.formula2.has_value() {
-                write_start_tag(writer, "formula2", vec![], false);
-                write_text_node(writer, self.formula2.get_value_string());
-                write_end_tag(writer, "formula2");
-            }
-            write_end_tag(writer, "dataValidation");
-        }
+        attributes
     }
 }
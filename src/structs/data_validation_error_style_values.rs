@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DataValidationErrorStyleValues {
+    Information,
+    Stop,
+    Warning,
+}
+impl Default for DataValidationErrorStyleValues {
+    fn default() -> Self {
+        Self::Stop
+    }
+}
+impl EnumTrait for DataValidationErrorStyleValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Information => "information",
+            Self::Stop => "stop",
+            Self::Warning => "warning",
+        }
+    }
+}
+impl FromStr for DataValidationErrorStyleValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "information" => Ok(Self::Information),
+            "stop" => Ok(Self::Stop),
+            "warning" => Ok(Self::Warning),
+            _ => Err(()),
+        }
+    }
+}
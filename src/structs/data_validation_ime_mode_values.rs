@@ -0,0 +1,57 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DataValidationImeModeValues {
+    Disabled,
+    FullAlpha,
+    FullHangul,
+    FullKatakana,
+    HalfAlpha,
+    HalfHangul,
+    HalfKatakana,
+    Hiragana,
+    NoControl,
+    Off,
+    On,
+}
+impl Default for DataValidationImeModeValues {
+    fn default() -> Self {
+        Self::NoControl
+    }
+}
+impl EnumTrait for DataValidationImeModeValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Disabled => "disabled",
+            Self::FullAlpha => "fullAlpha",
+            Self::FullHangul => "fullHangul",
+            Self::FullKatakana => "fullKatakana",
+            Self::HalfAlpha => "halfAlpha",
+            Self::HalfHangul => "halfHangul",
+            Self::HalfKatakana => "halfKatakana",
+            Self::Hiragana => "hiragana",
+            Self::NoControl => "noControl",
+            Self::Off => "off",
+            Self::On => "on",
+        }
+    }
+}
+impl FromStr for DataValidationImeModeValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "disabled" => Ok(Self::Disabled),
+            "fullAlpha" => Ok(Self::FullAlpha),
+            "fullHangul" => Ok(Self::FullHangul),
+            "fullKatakana" => Ok(Self::FullKatakana),
+            "halfAlpha" => Ok(Self::HalfAlpha),
+            "halfHangul" => Ok(Self::HalfHangul),
+            "halfKatakana" => Ok(Self::HalfKatakana),
+            "hiragana" => Ok(Self::Hiragana),
+            "noControl" => Ok(Self::NoControl),
+            "off" => Ok(Self::Off),
+            "on" => Ok(Self::On),
+            _ => Err(()),
+        }
+    }
+}
@@ -30,7 +30,7 @@ impl EnumTrait for DataValidationOperatorValues {
 
 impl Default for DataValidationOperatorValues {
     fn default() -> Self {
-        Self::LessThan
+        Self::Between
     }
 }
 
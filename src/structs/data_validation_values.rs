@@ -23,7 +23,7 @@ impl EnumTrait for DataValidationValues {
             Self::Date => "date",
             Self::Decimal => "decimal",
             Self::List => "list",
-            Self::None => "iso_8859_8_i",
+            Self::None => "none",
             Self::TextLength => "textLength",
             Self::Time => "time",
             Self::Whole => "whole",
@@ -0,0 +1,55 @@
+use super::Coordinate;
+use super::DataValidation;
+
+/// Cell value which does not satisfy a data validation, or cannot be checked against it.
+/// See `Worksheet::validate_cells`.
+#[derive(Clone, Default, Debug)]
+pub struct DataValidationViolation {
+    coordinate: Coordinate,
+    value: String,
+    data_validation: DataValidation,
+    unevaluated: bool,
+}
+
+impl DataValidationViolation {
+    pub fn get_coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+
+    pub(crate) fn set_coordinate(&mut self, col: u32, row: u32) -> &mut Self {
+        self.coordinate.set_col_num(col).set_row_num(row);
+        self
+    }
+
+    /// Get the value of the cell. (empty for a blank cell)
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    pub(crate) fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Get the data validation which the value violates.
+    /// The message for the user is given by `get_error_title` and `get_error`.
+    pub fn get_data_validation(&self) -> &DataValidation {
+        &self.data_validation
+    }
+
+    pub(crate) fn set_data_validation(&mut self, value: DataValidation) -> &mut Self {
+        self.data_validation = value;
+        self
+    }
+
+    /// Whether the data validation could not be evaluated, so the value is not checked.
+    /// ex) a formula with a function this crate does not support.
+    pub fn is_unevaluated(&self) -> bool {
+        self.unevaluated
+    }
+
+    pub(crate) fn set_unevaluated(&mut self, value: bool) -> &mut Self {
+        self.unevaluated = value;
+        self
+    }
+}
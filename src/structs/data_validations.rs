@@ -1,4 +1,4 @@
-// dataValidations
+// dataValidations, x14:dataValidations
 use super::DataValidation;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -39,21 +39,21 @@ impl DataValidations {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
-                if e.name().into_inner() == b"dataValidation" {
+                if e.name().into_inner() == b"dataValidation" || e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
                     obj.set_attributes(reader, e, true);
                     self.add_data_validation_list(obj);
                 }
             },
             Event::Start(ref e) => {
-                if e.name().into_inner() == b"dataValidation" {
+                if e.name().into_inner() == b"dataValidation" || e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
                     obj.set_attributes(reader, e, false);
                     self.add_data_validation_list(obj);
                 }
             },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"dataValidations" {
+                if e.name().into_inner() == b"dataValidations" || e.name().into_inner() == b"x14:dataValidations" {
                     return
                 }
             },
@@ -61,19 +61,63 @@ impl DataValidations {
         );
     }
 
+    /// (This method is crate only.)
+    /// Whether some data validations are written in the x14 extension.
+    pub(crate) fn has_x14(&self) -> bool {
+        self.data_validation_list.iter().any(|v| v.is_x14())
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let list: Vec<&DataValidation> = self
+            .data_validation_list
+            .iter()
+            .filter(|v| !v.is_x14())
+            .collect();
+        if list.is_empty() {
+            return;
+        }
+
         // dataValidations
         let mut attributes: Vec<(&str, &str)> = Vec::new();
 
-        let count = self.data_validation_list.len().to_string();
+        let count = list.len().to_string();
         attributes.push(("count", &count));
 
         write_start_tag(writer, "dataValidations", attributes, false);
 
-        for obj in &self.data_validation_list {
+        for obj in list {
             obj.write_to(writer);
         }
 
         write_end_tag(writer, "dataValidations");
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let list: Vec<&DataValidation> = self
+            .data_validation_list
+            .iter()
+            .filter(|v| v.is_x14())
+            .collect();
+
+        // x14:dataValidations
+        let count = list.len().to_string();
+        write_start_tag(
+            writer,
+            "x14:dataValidations",
+            vec![
+                ("count", &count),
+                (
+                    "xmlns:xm",
+                    "http://schemas.microsoft.com/office/excel/2006/main",
+                ),
+            ],
+            false,
+        );
+
+        for obj in list {
+            obj.write_to_x14(writer);
+        }
+
+        write_end_tag(writer, "x14:dataValidations");
+    }
 }
@@ -1,5 +1,7 @@
 use super::Address;
+use super::BooleanValue;
 use super::StringValue;
+use super::UInt32Value;
 use helper::address::*;
 
 #[derive(Clone, Default, Debug)]
@@ -7,14 +9,16 @@ pub struct DefinedName {
     name: String,
     address: Address,
     string_value: StringValue,
-    is_local_only: bool,
+    local_sheet_id: UInt32Value,
+    hidden: BooleanValue,
+    comment: StringValue,
 }
 impl DefinedName {
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
-    pub(crate) fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.name = value.into();
         self
     }
@@ -31,9 +35,15 @@ impl DefinedName {
         &mut self.address
     }
 
-    pub(crate) fn set_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
+    /// Set the body of the name.
+    /// # Arguments
+    /// * `value` - An address, a constant or a formula. ex) "Sheet1!$A$1:$B$2", "0.2", "OFFSET(Sheet1!$A$1,0,0,3,1)"
+    pub fn set_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let value = value.into();
-        if is_address(&value) {
+        let value = value.trim_start_matches('=');
+        // multiple areas are kept as a formula.
+        if is_address(value) && !value.contains(',') {
+            self.string_value = StringValue::default();
             self.address.set_address(value);
         } else {
             self.set_string_value(value);
@@ -54,11 +64,58 @@ impl DefinedName {
         self
     }
 
+    /// Whether the body is a single address (not a constant or a formula).
+    pub fn is_address(&self) -> bool {
+        !self.string_value.has_value()
+    }
+
     pub fn get_is_local_only(&self) -> &bool {
-        &self.is_local_only
+        if self.local_sheet_id.has_value() {
+            &true
+        } else {
+            &false
+        }
+    }
+
+    /// Get the index of the sheet the name is scoped to. None if the name is workbook scoped.
+    pub fn get_local_sheet_id(&self) -> Option<u32> {
+        if self.local_sheet_id.has_value() {
+            Some(*self.local_sheet_id.get_value())
+        } else {
+            None
+        }
+    }
+
+    pub fn set_local_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.local_sheet_id.set_value(value);
+        self
+    }
+
+    pub fn remove_local_sheet_id(&mut self) -> &mut Self {
+        self.local_sheet_id.remove_value();
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    pub fn get_comment(&self) -> &str {
+        self.comment.get_value()
+    }
+
+    pub fn set_comment<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.comment.set_value(value);
+        self
     }
 
-    pub(crate) fn set_is_local_only(&mut self, value: bool) {
-        self.is_local_only = value;
+    /// Whether the name matches. (case insensitive)
+    pub(crate) fn is_name(&self, value: &str) -> bool {
+        self.name.eq_ignore_ascii_case(value)
     }
 }
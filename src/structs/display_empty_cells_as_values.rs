@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DisplayEmptyCellsAsValues {
+    Gap,
+    Span,
+    Zero,
+}
+impl Default for DisplayEmptyCellsAsValues {
+    fn default() -> Self {
+        Self::Zero
+    }
+}
+impl EnumTrait for DisplayEmptyCellsAsValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Gap => "gap",
+            Self::Span => "span",
+            Self::Zero => "zero",
+        }
+    }
+}
+impl FromStr for DisplayEmptyCellsAsValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "gap" => Ok(Self::Gap),
+            "span" => Ok(Self::Span),
+            "zero" => Ok(Self::Zero),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+mod axis;
+pub use self::axis::*;
+
+mod binning;
+pub use self::binning::*;
+
+mod chart;
+pub use self::chart::*;
+
+mod chart_data;
+pub use self::chart_data::*;
+
+mod chart_space;
+pub use self::chart_space::*;
+
+mod data;
+pub use self::data::*;
+
+mod dimension;
+pub use self::dimension::*;
+
+mod layout_properties;
+pub use self::layout_properties::*;
+
+mod legend;
+pub use self::legend::*;
+
+mod plot_area;
+pub use self::plot_area::*;
+
+mod plot_area_region;
+pub use self::plot_area_region::*;
+
+mod series;
+pub use self::series::*;
+
+mod series_layout_values;
+pub use self::series_layout_values::*;
+
+mod text;
+pub use self::text::*;
+
+mod title;
+pub use self::title::*;
@@ -0,0 +1,111 @@
+// cx:axis
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Axis {
+    id: UInt32Value,
+    hidden: BooleanValue,
+    other_xml: Vec<String>,
+}
+
+impl Axis {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn set_id(&mut self, value: u32) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Get the child elements. (cx:catScaling, cx:valScaling, cx:tickLabels, etc.)
+    /// Raw XML, kept as read.
+    pub fn get_other_xml(&self) -> &Vec<String> {
+        &self.other_xml
+    }
+
+    /// (This method is crate only.)
+    /// Make a category axis. (cx:catScaling)
+    pub(crate) fn make_category_axis(id: u32, gap_width: &str) -> Self {
+        let mut axis = Axis::default();
+        axis.set_id(id);
+        axis.other_xml
+            .push(format!(r#"<cx:catScaling gapWidth="{}"/>"#, gap_width));
+        axis.other_xml.push(String::from("<cx:tickLabels/>"));
+        axis
+    }
+
+    /// (This method is crate only.)
+    /// Make a value axis. (cx:valScaling)
+    pub(crate) fn make_value_axis(id: u32) -> Self {
+        let mut axis = Axis::default();
+        axis.set_id(id);
+        axis.other_xml.push(String::from("<cx:valScaling/>"));
+        axis.other_xml.push(String::from("<cx:majorGridlines/>"));
+        axis.other_xml.push(String::from("<cx:tickLabels/>"));
+        axis
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, hidden, "hidden");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                self.other_xml.push(read_raw_xml(reader, e, false));
+            },
+            Event::Empty(ref e) => {
+                self.other_xml.push(read_raw_xml(reader, e, true));
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:axis" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:axis")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:axis
+        let id = self.id.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("id", &id));
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        write_start_tag(writer, "cx:axis", attributes, false);
+
+        for v in &self.other_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:axis");
+    }
+}
@@ -0,0 +1,160 @@
+// cx:binning
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::DoubleValue;
+use structs::StringValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Binning {
+    interval_closed: StringValue,
+    underflow: StringValue,
+    overflow: StringValue,
+    bin_size: DoubleValue,
+    bin_count: UInt32Value,
+}
+
+impl Binning {
+    /// Get Interval Closed. ("l" or "r")
+    pub fn get_interval_closed(&self) -> &str {
+        self.interval_closed.get_value()
+    }
+
+    pub fn set_interval_closed<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.interval_closed.set_value(value);
+        self
+    }
+
+    /// Get Underflow bin. ("auto" or a number)
+    pub fn get_underflow(&self) -> &str {
+        self.underflow.get_value()
+    }
+
+    pub fn set_underflow<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.underflow.set_value(value);
+        self
+    }
+
+    /// Get Overflow bin. ("auto" or a number)
+    pub fn get_overflow(&self) -> &str {
+        self.overflow.get_value()
+    }
+
+    pub fn set_overflow<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.overflow.set_value(value);
+        self
+    }
+
+    /// Get Bin Width.
+    /// Has a value only when the width was set.
+    pub fn get_bin_size(&self) -> Option<&f64> {
+        match self.bin_size.has_value() {
+            true => Some(self.bin_size.get_value()),
+            false => None,
+        }
+    }
+
+    /// Set Bin Width.
+    /// Bin Count is removed.
+    pub fn set_bin_size(&mut self, value: f64) -> &mut Self {
+        self.bin_size.set_value(value);
+        self.bin_count = UInt32Value::default();
+        self
+    }
+
+    /// Get Number of Bins.
+    /// Has a value only when the count was set.
+    pub fn get_bin_count(&self) -> Option<&u32> {
+        match self.bin_count.has_value() {
+            true => Some(self.bin_count.get_value()),
+            false => None,
+        }
+    }
+
+    /// Set Number of Bins.
+    /// Bin Width is removed.
+    pub fn set_bin_count(&mut self, value: u32) -> &mut Self {
+        self.bin_count.set_value(value);
+        self.bin_size = DoubleValue::default();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, interval_closed, "intervalClosed");
+        set_string_from_xml!(self, e, underflow, "underflow");
+        set_string_from_xml!(self, e, overflow, "overflow");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"cx:binSize" => {
+                    set_string_from_xml!(self, e, bin_size, "val");
+                }
+                b"cx:binCount" => {
+                    set_string_from_xml!(self, e, bin_count, "val");
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:binning" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:binning")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:binning
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.interval_closed.has_value() {
+            attributes.push(("intervalClosed", self.interval_closed.get_value_string()));
+        }
+        if self.underflow.has_value() {
+            attributes.push(("underflow", self.underflow.get_value_string()));
+        }
+        if self.overflow.has_value() {
+            attributes.push(("overflow", self.overflow.get_value_string()));
+        }
+        if !self.bin_size.has_value() && !self.bin_count.has_value() {
+            write_start_tag(writer, "cx:binning", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "cx:binning", attributes, false);
+
+        // cx:binSize
+        if self.bin_size.has_value() {
+            write_start_tag(
+                writer,
+                "cx:binSize",
+                vec![("val", &self.bin_size.get_value_string())],
+                true,
+            );
+        }
+
+        // cx:binCount
+        if self.bin_count.has_value() {
+            write_start_tag(
+                writer,
+                "cx:binCount",
+                vec![("val", &self.bin_count.get_value_string())],
+                true,
+            );
+        }
+
+        write_end_tag(writer, "cx:binning");
+    }
+}
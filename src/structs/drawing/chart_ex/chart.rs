@@ -0,0 +1,142 @@
+// cx:chart
+use super::Legend;
+use super::PlotArea;
+use super::Title;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Chart {
+    title: Option<Title>,
+    plot_area: PlotArea,
+    legend: Option<Legend>,
+    extension_list_xml: Option<String>,
+}
+
+impl Chart {
+    pub fn get_title(&self) -> &Option<Title> {
+        &self.title
+    }
+
+    pub fn get_title_mut(&mut self) -> &mut Option<Title> {
+        &mut self.title
+    }
+
+    pub fn set_title(&mut self, value: Title) -> &mut Self {
+        self.title = Some(value);
+        self
+    }
+
+    pub fn remove_title(&mut self) -> &mut Self {
+        self.title = None;
+        self
+    }
+
+    pub fn get_plot_area(&self) -> &PlotArea {
+        &self.plot_area
+    }
+
+    pub fn get_plot_area_mut(&mut self) -> &mut PlotArea {
+        &mut self.plot_area
+    }
+
+    pub fn set_plot_area(&mut self, value: PlotArea) -> &mut Self {
+        self.plot_area = value;
+        self
+    }
+
+    pub fn get_legend(&self) -> &Option<Legend> {
+        &self.legend
+    }
+
+    pub fn get_legend_mut(&mut self) -> &mut Option<Legend> {
+        &mut self.legend
+    }
+
+    pub fn set_legend(&mut self, value: Legend) -> &mut Self {
+        self.legend = Some(value);
+        self
+    }
+
+    pub fn remove_legend(&mut self) -> &mut Self {
+        self.legend = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_title(obj);
+                }
+                b"cx:plotArea" => {
+                    self.plot_area.set_attributes(reader, e);
+                }
+                b"cx:legend" => {
+                    let mut obj = Legend::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_legend(obj);
+                }
+                b"cx:extLst" => {
+                    self.extension_list_xml = Some(read_raw_xml(reader, e, false));
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_title(obj);
+                }
+                b"cx:legend" => {
+                    let mut obj = Legend::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_legend(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:chart" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:chart")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:chart
+        write_start_tag(writer, "cx:chart", vec![], false);
+
+        // cx:title
+        if let Some(v) = &self.title {
+            v.write_to(writer);
+        }
+
+        // cx:plotArea
+        self.plot_area.write_to(writer);
+
+        // cx:legend
+        if let Some(v) = &self.legend {
+            v.write_to(writer);
+        }
+
+        // cx:extLst
+        if let Some(v) = &self.extension_list_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:chart");
+    }
+}
@@ -0,0 +1,69 @@
+// cx:chartData
+use super::Data;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::Spreadsheet;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct ChartData {
+    data: Vec<Data>,
+}
+
+impl ChartData {
+    pub fn get_data(&self) -> &Vec<Data> {
+        &self.data
+    }
+
+    pub fn get_data_mut(&mut self) -> &mut Vec<Data> {
+        &mut self.data
+    }
+
+    pub fn add_data(&mut self, value: Data) -> &mut Self {
+        self.data.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:data" => {
+                    let mut obj = Data::default();
+                    obj.set_attributes(reader, e);
+                    self.add_data(obj);
+                }
+                b"cx:externalData" => {
+                    // the embedded workbook is not kept, so the reference to it is not kept either.
+                    read_raw_xml(reader, e, false);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:chartData" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:chartData")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chartData
+        write_start_tag(writer, "cx:chartData", vec![], false);
+
+        // cx:data
+        for v in &self.data {
+            v.write_to(writer, spreadsheet);
+        }
+
+        write_end_tag(writer, "cx:chartData");
+    }
+}
@@ -0,0 +1,183 @@
+// cx:chartSpace
+use super::Chart;
+use super::ChartData;
+use super::SeriesLayoutValues;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::Spreadsheet;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct ChartSpace {
+    chart_data: ChartData,
+    chart: Chart,
+    other_xml: Vec<String>,
+    extra_namespaces: Vec<(String, String)>,
+    chart_style_xml: Option<String>,
+    chart_color_style_xml: Option<String>,
+}
+
+impl ChartSpace {
+    pub fn get_chart_data(&self) -> &ChartData {
+        &self.chart_data
+    }
+
+    pub fn get_chart_data_mut(&mut self) -> &mut ChartData {
+        &mut self.chart_data
+    }
+
+    pub fn set_chart_data(&mut self, value: ChartData) -> &mut Self {
+        self.chart_data = value;
+        self
+    }
+
+    pub fn get_chart(&self) -> &Chart {
+        &self.chart
+    }
+
+    pub fn get_chart_mut(&mut self) -> &mut Chart {
+        &mut self.chart
+    }
+
+    pub fn set_chart(&mut self, value: Chart) -> &mut Self {
+        self.chart = value;
+        self
+    }
+
+    /// Get the chart level formatting elements. (cx:spPr, cx:txPr, cx:clrMapOvr, cx:fmtOvrs, cx:printSettings)
+    /// Raw XML, kept as read.
+    pub fn get_other_xml(&self) -> &Vec<String> {
+        &self.other_xml
+    }
+
+    /// Get Chart Style part. (xl/charts/styleN.xml)
+    pub fn get_chart_style_xml(&self) -> &Option<String> {
+        &self.chart_style_xml
+    }
+
+    pub fn set_chart_style_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.chart_style_xml = Some(value.into());
+        self
+    }
+
+    /// Get Chart Color Style part. (xl/charts/colorsN.xml)
+    pub fn get_chart_color_style_xml(&self) -> &Option<String> {
+        &self.chart_color_style_xml
+    }
+
+    pub fn set_chart_color_style_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.chart_color_style_xml = Some(value.into());
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the markup compatibility namespace that a consumer must understand to show this chart.
+    pub(crate) fn get_requires(&self) -> (&str, &str) {
+        for series in self
+            .chart
+            .get_plot_area()
+            .get_plot_area_region()
+            .get_series()
+        {
+            match series.get_layout_id() {
+                SeriesLayoutValues::Funnel => {
+                    return (
+                        "cx2",
+                        "http://schemas.microsoft.com/office/drawing/2015/10/21/chartex",
+                    );
+                }
+                SeriesLayoutValues::RegionMap => {
+                    return (
+                        "cx4",
+                        "http://schemas.microsoft.com/office/drawing/2016/5/10/chartex",
+                    );
+                }
+                _ => {}
+            }
+        }
+        (
+            "cx1",
+            "http://schemas.microsoft.com/office/drawing/2015/9/8/chartex",
+        )
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        for a in e.attributes().with_checks(false).flatten() {
+            let key = String::from_utf8_lossy(a.key.into_inner()).to_string();
+            match key.as_str() {
+                "xmlns:a" | "xmlns:r" | "xmlns:cx" => {}
+                _ => {
+                    if let Ok(v) = get_attribute_value(&a) {
+                        self.extra_namespaces.push((key, v));
+                    }
+                }
+            }
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:chartData" => {
+                    self.chart_data.set_attributes(reader, e);
+                }
+                b"cx:chart" => {
+                    self.chart.set_attributes(reader, e);
+                }
+                _ => {
+                    self.other_xml.push(read_raw_xml(reader, e, false));
+                }
+            },
+            Event::Empty(ref e) => {
+                self.other_xml.push(read_raw_xml(reader, e, true));
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:chartSpace" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:chartSpace")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chartSpace
+        let mut attributes: Vec<(&str, &str)> = vec![
+            (
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+            (
+                "xmlns:cx",
+                "http://schemas.microsoft.com/office/drawing/2014/chartex",
+            ),
+        ];
+        for (key, value) in &self.extra_namespaces {
+            attributes.push((key, value));
+        }
+        write_start_tag(writer, "cx:chartSpace", attributes, false);
+
+        // cx:chartData
+        self.chart_data.write_to(writer, spreadsheet);
+
+        // cx:chart
+        self.chart.write_to(writer);
+
+        // cx:spPr, cx:txPr, cx:clrMapOvr, cx:fmtOvrs, cx:printSettings, cx:extLst
+        for v in &self.other_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:chartSpace");
+    }
+}
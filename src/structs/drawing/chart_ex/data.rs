@@ -0,0 +1,83 @@
+// cx:data
+use super::Dimension;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::Spreadsheet;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Data {
+    id: UInt32Value,
+    dimension: Vec<Dimension>,
+}
+
+impl Data {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn set_id(&mut self, value: u32) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    pub fn get_dimension(&self) -> &Vec<Dimension> {
+        &self.dimension
+    }
+
+    pub fn get_dimension_mut(&mut self) -> &mut Vec<Dimension> {
+        &mut self.dimension
+    }
+
+    pub fn add_dimension(&mut self, value: Dimension) -> &mut Self {
+        self.dimension.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, id, "id");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:strDim" | b"cx:numDim" => {
+                    let mut obj = Dimension::default();
+                    obj.set_attributes(reader, e);
+                    self.add_dimension(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:data" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:data")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:data
+        write_start_tag(
+            writer,
+            "cx:data",
+            vec![("id", &self.id.get_value_string())],
+            false,
+        );
+
+        // cx:strDim, cx:numDim
+        for v in &self.dimension {
+            v.write_to(writer, spreadsheet);
+        }
+
+        write_end_tag(writer, "cx:data");
+    }
+}
@@ -0,0 +1,240 @@
+// cx:strDim
+// cx:numDim
+use helper::address::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::Range;
+use structs::Spreadsheet;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Dimension {
+    is_numeric: BooleanValue,
+    dimension_type: StringValue,
+    formula: StringValue,
+    formula_direction: StringValue,
+    number_format_xml: Option<String>,
+    level_xml: Vec<String>,
+}
+
+impl Dimension {
+    /// Is numeric dimension. (cx:numDim)
+    /// If false, it is string dimension. (cx:strDim)
+    pub fn get_is_numeric(&self) -> &bool {
+        self.is_numeric.get_value()
+    }
+
+    pub fn set_is_numeric(&mut self, value: bool) -> &mut Self {
+        self.is_numeric.set_value(value);
+        self
+    }
+
+    /// Get Dimension Type.
+    /// "cat", "colorStr", "entityId" for string dimension.
+    /// "val", "x", "y", "size", "colorVal" for numeric dimension.
+    pub fn get_dimension_type(&self) -> &str {
+        self.dimension_type.get_value()
+    }
+
+    pub fn set_dimension_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dimension_type.set_value(value);
+        self
+    }
+
+    /// Get Formula. (e.g. "Sheet1!$A$2:$A$10")
+    pub fn get_formula(&self) -> &str {
+        self.formula.get_value()
+    }
+
+    pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula.set_value(value);
+        self
+    }
+
+    /// Get Formula Direction. ("row" or "col")
+    pub fn get_formula_direction(&self) -> &str {
+        self.formula_direction.get_value()
+    }
+
+    pub fn set_formula_direction<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula_direction.set_value(value);
+        self
+    }
+
+    /// Get Number Format. (cx:nf)
+    /// Raw XML, kept as read.
+    pub fn get_number_format_xml(&self) -> &Option<String> {
+        &self.number_format_xml
+    }
+
+    pub fn set_number_format_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.number_format_xml = Some(value.into());
+        self
+    }
+
+    /// Get Cached Levels. (cx:lvl)
+    /// Raw XML, kept as read.
+    /// When the formula points to a range of this workbook, the levels are rebuilt on write.
+    pub fn get_level_xml(&self) -> &Vec<String> {
+        &self.level_xml
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        self.is_numeric
+            .set_value(e.name().into_inner() == b"cx:numDim");
+        set_string_from_xml!(self, e, dimension_type, "type");
+
+        let mut is_formula = false;
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:f" => {
+                    set_string_from_xml!(self, e, formula_direction, "dir");
+                    is_formula = true;
+                }
+                b"cx:nf" => {
+                    self.number_format_xml = Some(read_raw_xml(reader, e, false));
+                }
+                b"cx:lvl" => {
+                    self.level_xml.push(read_raw_xml(reader, e, false));
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"cx:lvl" {
+                    self.level_xml.push(read_raw_xml(reader, e, true));
+                }
+            },
+            Event::Text(e) => {
+                if is_formula {
+                    self.formula.set_value(e.unescape().unwrap().to_string());
+                }
+            },
+            Event::End(ref e) => match e.name().into_inner() {
+                b"cx:f" => {
+                    is_formula = false;
+                }
+                b"cx:strDim" | b"cx:numDim" => return,
+                _ => (),
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:strDim")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        let tag_name = match self.get_is_numeric() {
+            true => "cx:numDim",
+            false => "cx:strDim",
+        };
+
+        // cx:strDim, cx:numDim
+        write_start_tag(
+            writer,
+            tag_name,
+            vec![("type", self.dimension_type.get_value_string())],
+            false,
+        );
+
+        // cx:f
+        if self.formula.has_value() {
+            let mut attributes: Vec<(&str, &str)> = Vec::new();
+            if self.formula_direction.has_value() {
+                attributes.push(("dir", self.formula_direction.get_value_string()));
+            }
+            write_start_tag(writer, "cx:f", attributes, false);
+            write_text_node(writer, self.formula.get_value());
+            write_end_tag(writer, "cx:f");
+        }
+
+        // cx:nf
+        if let Some(v) = &self.number_format_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:lvl
+        match self.make_level_list(spreadsheet) {
+            Some(level_list) => {
+                let pt_count = level_list.first().map(|v| v.len()).unwrap_or(0);
+                let pt_count = pt_count.to_string();
+                for level in level_list {
+                    let mut attributes: Vec<(&str, &str)> = Vec::new();
+                    attributes.push(("ptCount", &pt_count));
+                    if self.get_is_numeric() == &true {
+                        attributes.push(("formatCode", "General"));
+                    }
+                    write_start_tag(writer, "cx:lvl", attributes, false);
+                    for (idx, value) in level.iter().enumerate() {
+                        if value.is_empty() {
+                            continue;
+                        }
+                        write_start_tag(writer, "cx:pt", vec![("idx", &idx.to_string())], false);
+                        write_text_node(writer, value);
+                        write_end_tag(writer, "cx:pt");
+                    }
+                    write_end_tag(writer, "cx:lvl");
+                }
+            }
+            None => {
+                for v in &self.level_xml {
+                    write_text_node_no_escape(writer, v);
+                }
+            }
+        }
+
+        write_end_tag(writer, tag_name);
+    }
+
+    /// Collect the cached values from the referenced cells.
+    /// Each level is one column (or one row when the direction is "row"),
+    /// ordered from the innermost level to the outermost level.
+    fn make_level_list(&self, spreadsheet: &Spreadsheet) -> Option<Vec<Vec<String>>> {
+        if !is_address(self.formula.get_value()) {
+            return None;
+        }
+        let (sheet_name, range_str) = split_address(self.formula.get_value());
+        let worksheet = spreadsheet.get_sheet_by_name(&sheet_name).ok()?;
+        let mut range = Range::default();
+        range.set_range(range_str);
+        let col_start = *range.get_coordinate_start_col().as_ref()?.get_num();
+        let row_start = *range.get_coordinate_start_row().as_ref()?.get_num();
+        let col_end = range
+            .get_coordinate_end_col()
+            .as_ref()
+            .map(|v| *v.get_num())
+            .unwrap_or(col_start);
+        let row_end = range
+            .get_coordinate_end_row()
+            .as_ref()
+            .map(|v| *v.get_num())
+            .unwrap_or(row_start);
+
+        let mut result: Vec<Vec<String>> = Vec::new();
+        if self.get_formula_direction() == "row" {
+            for row in (row_start..=row_end).rev() {
+                let mut level: Vec<String> = Vec::new();
+                for col in col_start..=col_end {
+                    level.push(worksheet.get_cell_value((col, row)).get_value().to_string());
+                }
+                result.push(level);
+            }
+        } else {
+            for col in (col_start..=col_end).rev() {
+                let mut level: Vec<String> = Vec::new();
+                for row in row_start..=row_end {
+                    level.push(worksheet.get_cell_value((col, row)).get_value().to_string());
+                }
+                result.push(level);
+            }
+        }
+        Some(result)
+    }
+}
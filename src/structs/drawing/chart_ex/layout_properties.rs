@@ -0,0 +1,207 @@
+// cx:layoutPr
+use super::Binning;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct LayoutProperties {
+    parent_label_layout: StringValue,
+    region_label_layout_xml: Option<String>,
+    visibility_xml: Option<String>,
+    aggregation_xml: Option<String>,
+    binning: Option<Binning>,
+    geography_xml: Option<String>,
+    statistics_xml: Option<String>,
+    subtotals: Vec<u32>,
+    extension_list_xml: Option<String>,
+}
+
+impl LayoutProperties {
+    /// Get Parent Label Layout of treemap. ("none", "banner" or "overlapping")
+    pub fn get_parent_label_layout(&self) -> &str {
+        self.parent_label_layout.get_value()
+    }
+
+    pub fn set_parent_label_layout<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.parent_label_layout.set_value(value);
+        self
+    }
+
+    /// Get Visibility of box & whisker elements. (cx:visibility)
+    /// Raw XML, kept as read.
+    pub fn get_visibility_xml(&self) -> &Option<String> {
+        &self.visibility_xml
+    }
+
+    pub fn set_visibility_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.visibility_xml = Some(value.into());
+        self
+    }
+
+    /// Get Statistics of box & whisker. (cx:statistics)
+    /// Raw XML, kept as read.
+    pub fn get_statistics_xml(&self) -> &Option<String> {
+        &self.statistics_xml
+    }
+
+    pub fn set_statistics_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.statistics_xml = Some(value.into());
+        self
+    }
+
+    /// Get Binning of histogram.
+    pub fn get_binning(&self) -> &Option<Binning> {
+        &self.binning
+    }
+
+    pub fn get_binning_mut(&mut self) -> &mut Option<Binning> {
+        &mut self.binning
+    }
+
+    pub fn set_binning(&mut self, value: Binning) -> &mut Self {
+        self.binning = Some(value);
+        self.aggregation_xml = None;
+        self
+    }
+
+    /// Get Subtotals of waterfall.
+    /// Indexes of the data points that are shown as totals.
+    pub fn get_subtotals(&self) -> &Vec<u32> {
+        &self.subtotals
+    }
+
+    pub fn set_subtotals(&mut self, value: Vec<u32>) -> &mut Self {
+        self.subtotals = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:binning" => {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_binning(obj);
+                }
+                b"cx:subtotals" => {}
+                _ => {
+                    self.set_raw_xml(e, read_raw_xml(reader, e, false));
+                }
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"cx:parentLabelLayout" => {
+                    set_string_from_xml!(self, e, parent_label_layout, "val");
+                }
+                b"cx:binning" => {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_binning(obj);
+                }
+                b"cx:idx" => {
+                    if let Some(v) = get_attribute(e, b"val") {
+                        if let Ok(v) = v.parse::<u32>() {
+                            self.subtotals.push(v);
+                        }
+                    }
+                }
+                _ => {
+                    self.set_raw_xml(e, read_raw_xml(reader, e, true));
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:layoutPr" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:layoutPr")
+        );
+    }
+
+    fn set_raw_xml(&mut self, e: &BytesStart, value: String) {
+        match e.name().into_inner() {
+            b"cx:regionLabelLayout" => self.region_label_layout_xml = Some(value),
+            b"cx:visibility" => self.visibility_xml = Some(value),
+            b"cx:aggregation" => self.aggregation_xml = Some(value),
+            b"cx:geography" => self.geography_xml = Some(value),
+            b"cx:statistics" => self.statistics_xml = Some(value),
+            b"cx:extLst" => self.extension_list_xml = Some(value),
+            _ => (),
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:layoutPr
+        write_start_tag(writer, "cx:layoutPr", vec![], false);
+
+        // cx:parentLabelLayout
+        if self.parent_label_layout.has_value() {
+            write_start_tag(
+                writer,
+                "cx:parentLabelLayout",
+                vec![("val", self.parent_label_layout.get_value_string())],
+                true,
+            );
+        }
+
+        // cx:regionLabelLayout
+        if let Some(v) = &self.region_label_layout_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:visibility
+        if let Some(v) = &self.visibility_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:aggregation
+        if let Some(v) = &self.aggregation_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:binning
+        if let Some(v) = &self.binning {
+            v.write_to(writer);
+        }
+
+        // cx:geography
+        if let Some(v) = &self.geography_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:statistics
+        if let Some(v) = &self.statistics_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:subtotals
+        if !self.subtotals.is_empty() {
+            write_start_tag(writer, "cx:subtotals", vec![], false);
+            for v in &self.subtotals {
+                write_start_tag(writer, "cx:idx", vec![("val", &v.to_string())], true);
+            }
+            write_end_tag(writer, "cx:subtotals");
+        }
+
+        // cx:extLst
+        if let Some(v) = &self.extension_list_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:layoutPr");
+    }
+}
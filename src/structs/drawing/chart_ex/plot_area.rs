@@ -0,0 +1,103 @@
+// cx:plotArea
+use super::Axis;
+use super::PlotAreaRegion;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct PlotArea {
+    plot_area_region: PlotAreaRegion,
+    axis: Vec<Axis>,
+    other_xml: Vec<String>,
+}
+
+impl PlotArea {
+    pub fn get_plot_area_region(&self) -> &PlotAreaRegion {
+        &self.plot_area_region
+    }
+
+    pub fn get_plot_area_region_mut(&mut self) -> &mut PlotAreaRegion {
+        &mut self.plot_area_region
+    }
+
+    pub fn set_plot_area_region(&mut self, value: PlotAreaRegion) -> &mut Self {
+        self.plot_area_region = value;
+        self
+    }
+
+    pub fn get_axis(&self) -> &Vec<Axis> {
+        &self.axis
+    }
+
+    pub fn get_axis_mut(&mut self) -> &mut Vec<Axis> {
+        &mut self.axis
+    }
+
+    pub fn add_axis(&mut self, value: Axis) -> &mut Self {
+        self.axis.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:plotAreaRegion" => {
+                    self.plot_area_region.set_attributes(reader, e);
+                }
+                b"cx:axis" => {
+                    let mut obj = Axis::default();
+                    obj.set_attributes(reader, e, false);
+                    self.add_axis(obj);
+                }
+                _ => {
+                    self.other_xml.push(read_raw_xml(reader, e, false));
+                }
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"cx:axis" => {
+                    let mut obj = Axis::default();
+                    obj.set_attributes(reader, e, true);
+                    self.add_axis(obj);
+                }
+                _ => {
+                    self.other_xml.push(read_raw_xml(reader, e, true));
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:plotArea" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:plotArea")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:plotArea
+        write_start_tag(writer, "cx:plotArea", vec![], false);
+
+        // cx:plotAreaRegion
+        self.plot_area_region.write_to(writer);
+
+        // cx:axis
+        for v in &self.axis {
+            v.write_to(writer);
+        }
+
+        // cx:spPr, cx:extLst
+        for v in &self.other_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:plotArea");
+    }
+}
@@ -0,0 +1,93 @@
+// cx:plotAreaRegion
+use super::Series;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct PlotAreaRegion {
+    plot_surface_xml: Option<String>,
+    series: Vec<Series>,
+    extension_list_xml: Option<String>,
+}
+
+impl PlotAreaRegion {
+    /// Get Plot Surface. (cx:plotSurface)
+    /// Raw XML, kept as read.
+    pub fn get_plot_surface_xml(&self) -> &Option<String> {
+        &self.plot_surface_xml
+    }
+
+    pub fn get_series(&self) -> &Vec<Series> {
+        &self.series
+    }
+
+    pub fn get_series_mut(&mut self) -> &mut Vec<Series> {
+        &mut self.series
+    }
+
+    pub fn add_series(&mut self, value: Series) -> &mut Self {
+        self.series.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:series" => {
+                    let mut obj = Series::default();
+                    obj.set_attributes(reader, e);
+                    self.add_series(obj);
+                }
+                b"cx:plotSurface" => {
+                    self.plot_surface_xml = Some(read_raw_xml(reader, e, false));
+                }
+                b"cx:extLst" => {
+                    self.extension_list_xml = Some(read_raw_xml(reader, e, false));
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"cx:plotSurface" {
+                    self.plot_surface_xml = Some(read_raw_xml(reader, e, true));
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:plotAreaRegion" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:plotAreaRegion")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:plotAreaRegion
+        write_start_tag(writer, "cx:plotAreaRegion", vec![], false);
+
+        // cx:plotSurface
+        if let Some(v) = &self.plot_surface_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:series
+        for v in &self.series {
+            v.write_to(writer);
+        }
+
+        // cx:extLst
+        if let Some(v) = &self.extension_list_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:plotAreaRegion");
+    }
+}
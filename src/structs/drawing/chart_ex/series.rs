@@ -0,0 +1,255 @@
+// cx:series
+use super::LayoutProperties;
+use super::SeriesLayoutValues;
+use super::Text;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::EnumValue;
+use structs::StringValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Series {
+    layout_id: EnumValue<SeriesLayoutValues>,
+    hidden: BooleanValue,
+    owner_index: UInt32Value,
+    unique_id: StringValue,
+    format_index: UInt32Value,
+    text: Option<Text>,
+    format_xml: Vec<String>,
+    data_id: UInt32Value,
+    layout_properties: Option<LayoutProperties>,
+    axis_id: Vec<u32>,
+    extension_list_xml: Option<String>,
+}
+
+impl Series {
+    pub fn get_layout_id(&self) -> &SeriesLayoutValues {
+        self.layout_id.get_value()
+    }
+
+    pub fn set_layout_id(&mut self, value: SeriesLayoutValues) -> &mut Self {
+        self.layout_id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Get Owner Index.
+    /// Index of the series this series belongs to. (e.g. the pareto line of a histogram)
+    pub fn get_owner_index(&self) -> Option<&u32> {
+        match self.owner_index.has_value() {
+            true => Some(self.owner_index.get_value()),
+            false => None,
+        }
+    }
+
+    pub fn set_owner_index(&mut self, value: u32) -> &mut Self {
+        self.owner_index.set_value(value);
+        self
+    }
+
+    pub fn get_unique_id(&self) -> &str {
+        self.unique_id.get_value()
+    }
+
+    pub fn set_unique_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.unique_id.set_value(value);
+        self
+    }
+
+    pub fn get_format_index(&self) -> &u32 {
+        self.format_index.get_value()
+    }
+
+    pub fn set_format_index(&mut self, value: u32) -> &mut Self {
+        self.format_index.set_value(value);
+        self
+    }
+
+    /// Get Series Name.
+    pub fn get_text(&self) -> &Option<Text> {
+        &self.text
+    }
+
+    pub fn get_text_mut(&mut self) -> &mut Option<Text> {
+        &mut self.text
+    }
+
+    pub fn set_text(&mut self, value: Text) -> &mut Self {
+        self.text = Some(value);
+        self
+    }
+
+    /// Get the formatting elements. (cx:spPr, cx:valueColors, cx:dataPt, cx:dataLabels, etc.)
+    /// Raw XML, kept as read.
+    pub fn get_format_xml(&self) -> &Vec<String> {
+        &self.format_xml
+    }
+
+    pub fn get_format_xml_mut(&mut self) -> &mut Vec<String> {
+        &mut self.format_xml
+    }
+
+    /// Get Data Id.
+    /// Id of the `Data` in `ChartData` that this series shows.
+    pub fn get_data_id(&self) -> &u32 {
+        self.data_id.get_value()
+    }
+
+    pub fn set_data_id(&mut self, value: u32) -> &mut Self {
+        self.data_id.set_value(value);
+        self
+    }
+
+    pub fn get_layout_properties(&self) -> &Option<LayoutProperties> {
+        &self.layout_properties
+    }
+
+    pub fn get_layout_properties_mut(&mut self) -> &mut LayoutProperties {
+        self.layout_properties
+            .get_or_insert(LayoutProperties::default())
+    }
+
+    pub fn set_layout_properties(&mut self, value: LayoutProperties) -> &mut Self {
+        self.layout_properties = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self) -> &Vec<u32> {
+        &self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value: Vec<u32>) -> &mut Self {
+        self.axis_id = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, layout_id, "layoutId");
+        set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, owner_index, "ownerIdx");
+        set_string_from_xml!(self, e, unique_id, "uniqueId");
+        set_string_from_xml!(self, e, format_index, "formatIdx");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:tx" => {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e);
+                    self.set_text(obj);
+                }
+                b"cx:layoutPr" => {
+                    let mut obj = LayoutProperties::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_layout_properties(obj);
+                }
+                b"cx:extLst" => {
+                    self.extension_list_xml = Some(read_raw_xml(reader, e, false));
+                }
+                _ => {
+                    self.format_xml.push(read_raw_xml(reader, e, false));
+                }
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"cx:dataId" => {
+                    set_string_from_xml!(self, e, data_id, "val");
+                }
+                b"cx:axisId" => {
+                    if let Some(v) = get_attribute(e, b"val") {
+                        if let Ok(v) = v.parse::<u32>() {
+                            self.axis_id.push(v);
+                        }
+                    }
+                }
+                b"cx:layoutPr" => {
+                    let mut obj = LayoutProperties::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_layout_properties(obj);
+                }
+                _ => {
+                    self.format_xml.push(read_raw_xml(reader, e, true));
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:series" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:series")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:series
+        let owner_index = self.owner_index.get_value_string();
+        let format_index = self.format_index.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("layoutId", self.layout_id.get_value_string()));
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        if self.owner_index.has_value() {
+            attributes.push(("ownerIdx", &owner_index));
+        }
+        if self.unique_id.has_value() {
+            attributes.push(("uniqueId", self.unique_id.get_value_string()));
+        }
+        if self.format_index.has_value() {
+            attributes.push(("formatIdx", &format_index));
+        }
+        write_start_tag(writer, "cx:series", attributes, false);
+
+        // cx:tx
+        if let Some(v) = &self.text {
+            v.write_to(writer);
+        }
+
+        // cx:spPr, cx:valueColors, cx:valueColorPositions, cx:dataPt, cx:dataLabels
+        for v in &self.format_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        // cx:dataId
+        write_start_tag(
+            writer,
+            "cx:dataId",
+            vec![("val", &self.data_id.get_value_string())],
+            true,
+        );
+
+        // cx:layoutPr
+        if let Some(v) = &self.layout_properties {
+            v.write_to(writer);
+        }
+
+        // cx:axisId
+        for v in &self.axis_id {
+            write_start_tag(writer, "cx:axisId", vec![("val", &v.to_string())], true);
+        }
+
+        // cx:extLst
+        if let Some(v) = &self.extension_list_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:series");
+    }
+}
@@ -0,0 +1,114 @@
+// cx:tx
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Text {
+    formula: StringValue,
+    value: StringValue,
+    rich_text_xml: Option<String>,
+}
+
+impl Text {
+    /// Get the reference of the text. (cx:txData/cx:f)
+    pub fn get_formula(&self) -> &str {
+        self.formula.get_value()
+    }
+
+    pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula.set_value(value);
+        self
+    }
+
+    /// Get the cached or literal text. (cx:txData/cx:v)
+    pub fn get_value(&self) -> &str {
+        self.value.get_value()
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self.rich_text_xml = None;
+        self
+    }
+
+    /// Get Rich Text. (cx:rich)
+    /// Raw XML, kept as read.
+    pub fn get_rich_text_xml(&self) -> &Option<String> {
+        &self.rich_text_xml
+    }
+
+    pub fn set_rich_text_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.rich_text_xml = Some(value.into());
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        let mut tag = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:rich" => {
+                    self.rich_text_xml = Some(read_raw_xml(reader, e, false));
+                }
+                v => {
+                    tag = v.to_vec();
+                }
+            },
+            Event::Text(e) => {
+                let value = e.unescape().unwrap().to_string();
+                match tag.as_slice() {
+                    b"cx:f" => {
+                        self.formula.set_value(value);
+                    }
+                    b"cx:v" => {
+                        self.value.set_value(value);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                tag.clear();
+                if e.name().into_inner() == b"cx:tx" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:tx")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:tx
+        write_start_tag(writer, "cx:tx", vec![], false);
+
+        match &self.rich_text_xml {
+            Some(v) => {
+                // cx:rich
+                write_text_node_no_escape(writer, v);
+            }
+            None => {
+                // cx:txData
+                write_start_tag(writer, "cx:txData", vec![], false);
+                if self.formula.has_value() {
+                    write_start_tag(writer, "cx:f", vec![], false);
+                    write_text_node(writer, self.formula.get_value());
+                    write_end_tag(writer, "cx:f");
+                }
+                write_start_tag(writer, "cx:v", vec![], false);
+                write_text_node(writer, self.value.get_value());
+                write_end_tag(writer, "cx:v");
+                write_end_tag(writer, "cx:txData");
+            }
+        }
+
+        write_end_tag(writer, "cx:tx");
+    }
+}
@@ -0,0 +1,128 @@
+// cx:title
+use super::Text;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Title {
+    position: StringValue,
+    alignment: StringValue,
+    overlay: BooleanValue,
+    text: Option<Text>,
+    other_xml: Vec<String>,
+}
+
+impl Title {
+    /// Get Position. ("t", "b", "l" or "r")
+    pub fn get_position(&self) -> &str {
+        self.position.get_value()
+    }
+
+    pub fn set_position<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    /// Get Alignment. ("min", "ctr" or "max")
+    pub fn get_alignment(&self) -> &str {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub fn get_overlay(&self) -> &bool {
+        self.overlay.get_value()
+    }
+
+    pub fn set_overlay(&mut self, value: bool) -> &mut Self {
+        self.overlay.set_value(value);
+        self
+    }
+
+    pub fn get_text(&self) -> &Option<Text> {
+        &self.text
+    }
+
+    pub fn get_text_mut(&mut self) -> &mut Option<Text> {
+        &mut self.text
+    }
+
+    pub fn set_text(&mut self, value: Text) -> &mut Self {
+        self.text = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, position, "pos");
+        set_string_from_xml!(self, e, alignment, "align");
+        set_string_from_xml!(self, e, overlay, "overlay");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"cx:tx" => {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e);
+                    self.set_text(obj);
+                }
+                _ => {
+                    self.other_xml.push(read_raw_xml(reader, e, false));
+                }
+            },
+            Event::Empty(ref e) => {
+                self.other_xml.push(read_raw_xml(reader, e, true));
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cx:title" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cx:title")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:title
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.position.has_value() {
+            attributes.push(("pos", self.position.get_value_string()));
+        }
+        if self.alignment.has_value() {
+            attributes.push(("align", self.alignment.get_value_string()));
+        }
+        if self.overlay.has_value() {
+            attributes.push(("overlay", self.overlay.get_value_string()));
+        }
+        write_start_tag(writer, "cx:title", attributes, false);
+
+        // cx:tx
+        if let Some(v) = &self.text {
+            v.write_to(writer);
+        }
+
+        // cx:spPr, cx:txPr, cx:extLst
+        for v in &self.other_xml {
+            write_text_node_no_escape(writer, v);
+        }
+
+        write_end_tag(writer, "cx:title");
+    }
+}
@@ -426,3 +426,6 @@ pub use self::area_chart_series_list::*;
 
 mod series_text;
 pub use self::series_text::*;
+
+mod marker_size;
+pub use self::marker_size::*;
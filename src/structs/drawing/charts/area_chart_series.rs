@@ -8,10 +8,13 @@ use super::Formula;
 use super::Index;
 use super::InvertIfNegative;
 use super::Marker;
+use super::MarkerSize;
+use super::MarkerStyleValues;
 use super::Order;
 use super::SeriesText;
 use super::ShapeProperties;
 use super::Smooth;
+use super::Symbol;
 use super::Values;
 use super::XValues;
 use super::YValues;
@@ -20,6 +23,14 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use structs::drawing::GradientFill;
+use structs::drawing::GradientStop;
+use structs::drawing::LinearGradientFill;
+use structs::drawing::Outline;
+use structs::drawing::PresetDash;
+use structs::drawing::PresetLineDashValues;
+use structs::drawing::RgbColorModelHex;
+use structs::drawing::SolidFill;
 use structs::Spreadsheet;
 use writer::driver::*;
 
@@ -238,6 +249,124 @@ impl AreaChartSeries {
         self
     }
 
+    /// Set the fill color of the series.
+    /// # Arguments
+    /// * `rgb` - RGB hex color. (e.g. "4472C4")
+    pub fn set_fill_color<S: Into<String>>(&mut self, rgb: S) -> &mut Self {
+        let shape_properties = self
+            .shape_properties
+            .get_or_insert(ShapeProperties::default());
+        *shape_properties.get_no_fill_mut() = None;
+        *shape_properties.get_gradient_fill_mut() = None;
+        *shape_properties.get_pattern_fill_mut() = None;
+        shape_properties.set_solid_fill(Self::make_solid_fill(rgb));
+        self
+    }
+
+    /// Set a linear gradient fill of the series.
+    /// # Arguments
+    /// * `stops` - List of (position, RGB hex color). Position is in 1/1000 of a percent (0 - 100000).
+    /// * `angle` - Direction of the gradient in 1/60000 of a degree.
+    pub fn set_gradient_fill<S: Into<String>>(
+        &mut self,
+        stops: Vec<(i32, S)>,
+        angle: i32,
+    ) -> &mut Self {
+        let mut gradient_fill = GradientFill::default();
+        for (position, rgb) in stops {
+            let mut color = RgbColorModelHex::default();
+            color.set_val(rgb);
+            let mut gradient_stop = GradientStop::default();
+            gradient_stop.set_position(position);
+            gradient_stop.set_rgb_color_model_hex(color);
+            gradient_fill
+                .get_gradient_stop_list_mut()
+                .add_gradient_stop(gradient_stop);
+        }
+        let mut linear_gradient_fill = LinearGradientFill::default();
+        linear_gradient_fill.set_angle(angle);
+        linear_gradient_fill.set_scaled(false);
+        gradient_fill.set_linear_gradient_fill(linear_gradient_fill);
+
+        let shape_properties = self
+            .shape_properties
+            .get_or_insert(ShapeProperties::default());
+        *shape_properties.get_no_fill_mut() = None;
+        *shape_properties.get_solid_fill_mut() = None;
+        *shape_properties.get_pattern_fill_mut() = None;
+        shape_properties.set_gradient_fill(gradient_fill);
+        self
+    }
+
+    /// Set the line color of the series.
+    /// # Arguments
+    /// * `rgb` - RGB hex color. (e.g. "4472C4")
+    pub fn set_line_color<S: Into<String>>(&mut self, rgb: S) -> &mut Self {
+        let outline = self.get_outline_mut();
+        *outline.get_no_fill_mut() = None;
+        *outline.get_gradient_fill_mut() = None;
+        outline.set_solid_fill(Self::make_solid_fill(rgb));
+        self
+    }
+
+    /// Set the line width of the series.
+    /// # Arguments
+    /// * `value` - Width in points.
+    pub fn set_line_width(&mut self, value: f64) -> &mut Self {
+        self.get_outline_mut()
+            .set_width((value * 12700f64).round() as u32);
+        self
+    }
+
+    /// Set the line dash style of the series.
+    /// # Arguments
+    /// * `value` - PresetLineDashValues
+    pub fn set_line_dash(&mut self, value: PresetLineDashValues) -> &mut Self {
+        let mut preset_dash = PresetDash::default();
+        preset_dash.set_val(value);
+        self.get_outline_mut().set_preset_dash(preset_dash);
+        self
+    }
+
+    /// Set the marker style of the series.
+    /// # Arguments
+    /// * `value` - MarkerStyleValues
+    pub fn set_marker_style(&mut self, value: MarkerStyleValues) -> &mut Self {
+        let mut symbol = Symbol::default();
+        symbol.set_val(value);
+        self.marker
+            .get_or_insert(Marker::default())
+            .set_symbol(symbol);
+        self
+    }
+
+    /// Set the marker size of the series.
+    /// # Arguments
+    /// * `value` - Size in points. (2 - 72)
+    pub fn set_marker_size(&mut self, value: u8) -> &mut Self {
+        let mut marker_size = MarkerSize::default();
+        marker_size.set_val(value);
+        self.marker
+            .get_or_insert(Marker::default())
+            .set_marker_size(marker_size);
+        self
+    }
+
+    fn get_outline_mut(&mut self) -> &mut Outline {
+        self.shape_properties
+            .get_or_insert(ShapeProperties::default())
+            .get_outline_mut()
+            .get_or_insert(Outline::default())
+    }
+
+    fn make_solid_fill<S: Into<String>>(rgb: S) -> SolidFill {
+        let mut color = RgbColorModelHex::default();
+        color.set_val(rgb);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(color);
+        solid_fill
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
 
@@ -369,6 +498,11 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
         // c:explosion
         if let Some(v) = &self.explosion {
             v.write_to(writer);
@@ -384,11 +518,6 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
-        // c:spPr
-        if let Some(v) = &self.shape_properties {
-            v.write_to(writer);
-        }
-
         // c:dLbls
         if let Some(v) = &self.data_labels {
             v.write_to(writer);
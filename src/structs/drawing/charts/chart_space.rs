@@ -24,6 +24,8 @@ pub struct ChartSpace {
     chart: Chart,
     shape_properties: Option<ShapeProperties>,
     print_settings: Option<PrintSettings>,
+    chart_style_xml: Option<String>,
+    chart_color_style_xml: Option<String>,
 }
 
 impl ChartSpace {
@@ -118,6 +120,44 @@ impl ChartSpace {
         self
     }
 
+    /// Get Chart Style part. (xl/charts/styleN.xml)
+    /// Raw XML of the Office 2013+ chart style.
+    pub fn get_chart_style_xml(&self) -> &Option<String> {
+        &self.chart_style_xml
+    }
+
+    /// Set Chart Style part. (xl/charts/styleN.xml)
+    /// # Arguments
+    /// * `value` - Raw XML of the `cs:chartStyle` element.
+    pub fn set_chart_style_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.chart_style_xml = Some(value.into());
+        self
+    }
+
+    pub fn remove_chart_style_xml(&mut self) -> &mut Self {
+        self.chart_style_xml = None;
+        self
+    }
+
+    /// Get Chart Color Style part. (xl/charts/colorsN.xml)
+    /// Raw XML of the Office 2013+ chart color style.
+    pub fn get_chart_color_style_xml(&self) -> &Option<String> {
+        &self.chart_color_style_xml
+    }
+
+    /// Set Chart Color Style part. (xl/charts/colorsN.xml)
+    /// # Arguments
+    /// * `value` - Raw XML of the `cs:colorStyle` element.
+    pub fn set_chart_color_style_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.chart_color_style_xml = Some(value.into());
+        self
+    }
+
+    pub fn remove_chart_color_style_xml(&mut self) -> &mut Self {
+        self.chart_color_style_xml = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -156,6 +196,9 @@ impl ChartSpace {
                 b"c:roundedCorners" => {
                     self.rounded_corners.set_attributes(reader, e);
                 }
+                b"c:style" => {
+                    self.style.set_attributes_style(e);
+                }
                 _ => (),
             },
             Event::End(ref e) => {
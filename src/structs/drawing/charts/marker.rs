@@ -1,4 +1,6 @@
 // c:marker
+use super::MarkerSize;
+use super::ShapeProperties;
 use super::Symbol;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -10,6 +12,8 @@ use writer::driver::*;
 #[derive(Clone, Default, Debug)]
 pub struct Marker {
     symbol: Option<Symbol>,
+    marker_size: Option<MarkerSize>,
+    shape_properties: Option<ShapeProperties>,
 }
 
 impl Marker {
@@ -26,6 +30,32 @@ impl Marker {
         self
     }
 
+    pub fn get_marker_size(&self) -> &Option<MarkerSize> {
+        &self.marker_size
+    }
+
+    pub fn get_marker_size_mut(&mut self) -> &mut Option<MarkerSize> {
+        &mut self.marker_size
+    }
+
+    pub fn set_marker_size(&mut self, value: MarkerSize) -> &mut Marker {
+        self.marker_size = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> &Option<ShapeProperties> {
+        &self.shape_properties
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> &mut Option<ShapeProperties> {
+        &mut self.shape_properties
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Marker {
+        self.shape_properties = Some(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -38,12 +68,25 @@ impl Marker {
 
         xml_read_loop!(
             reader,
-            Event::Empty(ref e) => {
-                if e.name().0 == b"c:symbol" {
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:spPr" {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e);
+                    self.set_shape_properties(obj);
+                }
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:symbol" => {
                     let mut obj = Symbol::default();
                     obj.set_attributes(reader, e);
                     self.set_symbol(obj);
                 }
+                b"c:size" => {
+                    let mut obj = MarkerSize::default();
+                    obj.set_attributes(reader, e);
+                    self.set_marker_size(obj);
+                }
+                _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:marker" {
@@ -56,14 +99,24 @@ impl Marker {
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // c:marker
-        if self.symbol.is_some() {
+        if self.symbol.is_some() || self.marker_size.is_some() || self.shape_properties.is_some() {
             write_start_tag(writer, "c:marker", vec![], false);
 
-            // a:symbol
+            // c:symbol
             if let Some(v) = &self.symbol {
                 v.write_to(writer);
             }
 
+            // c:size
+            if let Some(v) = &self.marker_size {
+                v.write_to(writer);
+            }
+
+            // c:spPr
+            if let Some(v) = &self.shape_properties {
+                v.write_to(writer);
+            }
+
             write_end_tag(writer, "c:marker");
         } else {
             write_start_tag(writer, "c:marker", vec![], true);
@@ -1,5 +1,6 @@
 // c:spPr
 use super::super::EffectList;
+use super::super::GradientFill;
 use super::super::NoFill;
 use super::super::Outline;
 use super::super::PatternFill;
@@ -21,6 +22,7 @@ pub struct ShapeProperties {
     transform2d: Option<Transform2D>,
     preset_geometry: Option<PresetGeometry>,
     solid_fill: Option<SolidFill>,
+    gradient_fill: Option<GradientFill>,
     no_fill: Option<NoFill>,
     outline: Option<Outline>,
     effect_list: Option<EffectList>,
@@ -81,6 +83,19 @@ impl ShapeProperties {
         self
     }
 
+    pub fn get_gradient_fill(&self) -> &Option<GradientFill> {
+        &self.gradient_fill
+    }
+
+    pub fn get_gradient_fill_mut(&mut self) -> &mut Option<GradientFill> {
+        &mut self.gradient_fill
+    }
+
+    pub fn set_gradient_fill(&mut self, value: GradientFill) -> &mut Self {
+        self.gradient_fill = Some(value);
+        self
+    }
+
     pub fn get_no_fill(&self) -> &Option<NoFill> {
         &self.no_fill
     }
@@ -180,6 +195,11 @@ impl ShapeProperties {
                         obj.set_attributes(reader, e);
                         self.set_solid_fill(obj);
                     }
+                    b"a:gradFill" => {
+                        let mut obj = GradientFill::default();
+                        obj.set_attributes(reader, e);
+                        self.set_gradient_fill(obj);
+                    }
                     b"a:effectLst" => {
                         let mut obj = EffectList::default();
                         obj.set_attributes(reader, e, false);
@@ -238,6 +258,11 @@ impl ShapeProperties {
             v.write_to(writer);
         }
 
+        // a:gradFill
+        if let Some(v) = &self.gradient_fill {
+            v.write_to(writer);
+        }
+
         // a:noFill
         if let Some(v) = &self.no_fill {
             v.write_to(writer);
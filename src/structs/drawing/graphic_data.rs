@@ -1,4 +1,5 @@
 // *:graphicData
+use super::chart_ex::ChartSpace as ChartExSpace;
 use super::charts::ChartSpace;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -12,6 +13,7 @@ use writer::driver::*;
 #[derive(Clone, Default, Debug)]
 pub struct GraphicData {
     chart_space: ChartSpace,
+    chart_ex_space: Option<ChartExSpace>,
 }
 
 impl GraphicData {
@@ -28,6 +30,20 @@ impl GraphicData {
         self
     }
 
+    /// Get Office 2016 Chart. (cx:chart)
+    pub fn get_chart_ex_space(&self) -> &Option<ChartExSpace> {
+        &self.chart_ex_space
+    }
+
+    pub fn get_chart_ex_space_mut(&mut self) -> &mut Option<ChartExSpace> {
+        &mut self.chart_ex_space
+    }
+
+    pub fn set_chart_ex_space(&mut self, value: ChartExSpace) -> &mut Self {
+        self.chart_ex_space = Some(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -37,12 +53,55 @@ impl GraphicData {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
+                if e.name().into_inner() == b"cx:chart" {
+                    let chart_id = get_attribute(e, b"r:id").unwrap();
+                    let relationship = drawing_relationships
+                        .unwrap()
+                        .get_relationship_by_rid(&chart_id);
+                    let mut chart_ex_space = ChartExSpace::default();
+                    let _ = chart::read_chart_ex(relationship.get_raw_file(), &mut chart_ex_space);
+                    if let Some(chart_relationships) = relationship.get_relationships() {
+                        for chart_relationship in chart_relationships.get_relationship_list() {
+                            let data = String::from_utf8_lossy(
+                                chart_relationship.get_raw_file().get_file_data(),
+                            )
+                            .to_string();
+                            match chart_relationship.get_type() {
+                                "http://schemas.microsoft.com/office/2011/relationships/chartStyle" => {
+                                    chart_ex_space.set_chart_style_xml(data);
+                                }
+                                "http://schemas.microsoft.com/office/2011/relationships/chartColorStyle" => {
+                                    chart_ex_space.set_chart_color_style_xml(data);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    self.set_chart_ex_space(chart_ex_space);
+                }
                 if e.name().into_inner() == b"c:chart" {
                     let chart_id = get_attribute(e, b"r:id").unwrap();
                     let relationship = drawing_relationships
                         .unwrap()
                         .get_relationship_by_rid(&chart_id);
                     let _ = chart::read(relationship.get_raw_file(), &mut self.chart_space);
+                    if let Some(chart_relationships) = relationship.get_relationships() {
+                        for chart_relationship in chart_relationships.get_relationship_list() {
+                            let data = String::from_utf8_lossy(
+                                chart_relationship.get_raw_file().get_file_data(),
+                            )
+                            .to_string();
+                            match chart_relationship.get_type() {
+                                "http://schemas.microsoft.com/office/2011/relationships/chartStyle" => {
+                                    self.chart_space.set_chart_style_xml(data);
+                                }
+                                "http://schemas.microsoft.com/office/2011/relationships/chartColorStyle" => {
+                                    self.chart_space.set_chart_color_style_xml(data);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
                 }
             },
             Event::End(ref e) => {
@@ -55,6 +114,40 @@ impl GraphicData {
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &i32) {
+        if self.chart_ex_space.is_some() {
+            // a:graphicData
+            write_start_tag(
+                writer,
+                "a:graphicData",
+                vec![(
+                    "uri",
+                    "http://schemas.microsoft.com/office/drawing/2014/chartex",
+                )],
+                false,
+            );
+
+            // cx:chart
+            write_start_tag(
+                writer,
+                "cx:chart",
+                vec![
+                    (
+                        "xmlns:cx",
+                        "http://schemas.microsoft.com/office/drawing/2014/chartex",
+                    ),
+                    (
+                        "xmlns:r",
+                        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+                    ),
+                    ("r:id", format!("rId{}", r_id).as_str()),
+                ],
+                true,
+            );
+
+            write_end_tag(writer, "a:graphicData");
+            return;
+        }
+
         // a:graphicData
         write_start_tag(
             writer,
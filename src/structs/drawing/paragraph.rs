@@ -34,6 +34,10 @@ impl Paragraph {
         &self.run
     }
 
+    pub fn get_run_mut(&mut self) -> &mut Vec<Run> {
+        &mut self.run
+    }
+
     pub fn add_run(&mut self, value: Run) {
         self.run.push(value);
     }
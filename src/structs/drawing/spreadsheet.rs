@@ -66,3 +66,9 @@ pub use self::extent::*;
 
 mod marker_type;
 pub use self::marker_type::*;
+
+mod position;
+pub use self::position::*;
+
+mod absolute_anchor;
+pub use self::absolute_anchor::*;
@@ -0,0 +1,121 @@
+// xdr:absoluteAnchor
+use super::Extent;
+use super::GraphicFrame;
+use super::Position;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::raw::RawRelationships;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct AbsoluteAnchor {
+    position: Position,
+    extent: Extent,
+    graphic_frame: Option<GraphicFrame>,
+}
+
+impl AbsoluteAnchor {
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_position_mut(&mut self) -> &mut Position {
+        &mut self.position
+    }
+
+    pub fn set_position(&mut self, value: Position) -> &mut Self {
+        self.position = value;
+        self
+    }
+
+    pub fn get_extent(&self) -> &Extent {
+        &self.extent
+    }
+
+    pub fn get_extent_mut(&mut self) -> &mut Extent {
+        &mut self.extent
+    }
+
+    pub fn set_extent(&mut self, value: Extent) -> &mut Self {
+        self.extent = value;
+        self
+    }
+
+    pub fn get_graphic_frame(&self) -> &Option<GraphicFrame> {
+        &self.graphic_frame
+    }
+
+    pub fn get_graphic_frame_mut(&mut self) -> &mut Option<GraphicFrame> {
+        &mut self.graphic_frame
+    }
+
+    pub fn set_graphic_frame(&mut self, value: GraphicFrame) -> &mut Self {
+        self.graphic_frame = Some(value);
+        self
+    }
+
+    pub fn remove_graphic_frame(&mut self) -> &mut Self {
+        self.graphic_frame = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        drawing_relationships: Option<&RawRelationships>,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"xdr:graphicFrame" {
+                    let mut obj = GraphicFrame::default();
+                    obj.set_attributes(reader, e, drawing_relationships);
+                    self.set_graphic_frame(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"xdr:pos" => {
+                        self.position.set_attributes(reader, e);
+                    }
+                    b"xdr:ext" => {
+                        self.extent.set_attributes(reader, e);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"xdr:absoluteAnchor" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "xdr:absoluteAnchor")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut i32) {
+        // xdr:absoluteAnchor
+        write_start_tag(writer, "xdr:absoluteAnchor", vec![], false);
+
+        // xdr:pos
+        self.position.write_to(writer);
+
+        // xdr:ext
+        self.extent.write_to(writer);
+
+        // xdr:graphicFrame
+        if let Some(v) = &self.graphic_frame {
+            v.write_to(writer, r_id);
+            *r_id += 1;
+        }
+
+        // xdr:clientData
+        write_start_tag(writer, "xdr:clientData", vec![], true);
+
+        write_end_tag(writer, "xdr:absoluteAnchor");
+    }
+}
@@ -0,0 +1,56 @@
+// xdr:pos
+use super::super::super::Int64Value;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Position {
+    x: Int64Value,
+    y: Int64Value,
+}
+
+impl Position {
+    pub fn get_x(&self) -> &i64 {
+        self.x.get_value()
+    }
+
+    pub fn set_x(&mut self, value: i64) -> &mut Position {
+        self.x.set_value(value);
+        self
+    }
+
+    pub fn get_y(&self) -> &i64 {
+        self.y.get_value()
+    }
+
+    pub fn set_y(&mut self, value: i64) -> &mut Position {
+        self.y.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, x, "x");
+        set_string_from_xml!(self, e, y, "y");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xdr:pos
+        write_start_tag(
+            writer,
+            "xdr:pos",
+            vec![
+                ("x", &self.x.get_value_string()),
+                ("y", &self.y.get_value_string()),
+            ],
+            true,
+        );
+    }
+}
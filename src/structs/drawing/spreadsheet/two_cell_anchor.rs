@@ -145,6 +145,9 @@ impl TwoCellAnchor {
     }
 
     pub(crate) fn is_support(&self) -> bool {
+        if self.is_chart_ex() {
+            return true;
+        }
         match &self.graphic_frame {
             Some(v) => {
                 return v
@@ -170,6 +173,17 @@ impl TwoCellAnchor {
         false
     }
 
+    pub(crate) fn is_chart_ex(&self) -> bool {
+        match &self.graphic_frame {
+            Some(v) => v
+                .get_graphic()
+                .get_graphic_data()
+                .get_chart_ex_space()
+                .is_some(),
+            None => false,
+        }
+    }
+
     pub(crate) fn is_image(&self) -> bool {
         match &self.picture {
             Some(_) => {
@@ -236,7 +250,35 @@ impl TwoCellAnchor {
         r_id: &mut i32,
         ole_id: &usize,
     ) {
-        if self.get_is_alternate_content() == &true {
+        let chart_ex_space = self.graphic_frame.as_ref().and_then(|v| {
+            v.get_graphic()
+                .get_graphic_data()
+                .get_chart_ex_space()
+                .as_ref()
+        });
+        if let Some(chart_ex_space) = chart_ex_space {
+            let (requires, namespace) = chart_ex_space.get_requires();
+            let xmlns = format!("xmlns:{}", requires);
+
+            // mc:AlternateContent
+            write_start_tag(
+                writer,
+                "mc:AlternateContent",
+                vec![(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )],
+                false,
+            );
+
+            // mc:Choice
+            write_start_tag(
+                writer,
+                "mc:Choice",
+                vec![(&xmlns, namespace), ("Requires", requires)],
+                false,
+            );
+        } else if self.get_is_alternate_content() == &true {
             // mc:AlternateContent
             write_start_tag(
                 writer,
@@ -311,7 +353,7 @@ impl TwoCellAnchor {
 
         write_end_tag(writer, "xdr:twoCellAnchor");
 
-        if self.get_is_alternate_content() == &true {
+        if chart_ex_space.is_some() || self.get_is_alternate_content() == &true {
             write_end_tag(writer, "mc:Choice");
 
             // mc:Fallback
@@ -12,6 +12,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use structs::raw::RawRelationships;
 use structs::Chart;
+use structs::ChartEx;
 use structs::Image;
 use structs::OleObjects;
 use writer::driver::*;
@@ -20,6 +21,7 @@ use writer::driver::*;
 pub struct WorksheetDrawing {
     image_collection: Vec<Image>,
     chart_collection: Vec<Chart>,
+    chart_ex_collection: Vec<ChartEx>,
     one_cell_anchor_collection: Vec<OneCellAnchor>,
     two_cell_anchor_collection: Vec<TwoCellAnchor>,
 }
@@ -115,6 +117,31 @@ impl WorksheetDrawing {
         result
     }
 
+    pub fn get_chart_ex_collection(&self) -> &Vec<ChartEx> {
+        &self.chart_ex_collection
+    }
+
+    pub fn get_chart_ex_collection_mut(&mut self) -> &mut Vec<ChartEx> {
+        &mut self.chart_ex_collection
+    }
+
+    pub fn add_chart_ex_collection(&mut self, value: ChartEx) -> &mut Self {
+        self.chart_ex_collection.push(value);
+        self
+    }
+
+    pub fn get_chart_ex(&self, col: &u32, row: &u32) -> Option<&ChartEx> {
+        self.chart_ex_collection
+            .iter()
+            .find(|&chart| chart.get_col() == &(col - 1) && chart.get_row() == &(row - 1))
+    }
+
+    pub fn get_chart_ex_mut(&mut self, col: &u32, row: &u32) -> Option<&mut ChartEx> {
+        self.chart_ex_collection
+            .iter_mut()
+            .find(|chart| chart.get_col() == &(col - 1) && chart.get_row() == &(row - 1))
+    }
+
     pub fn get_one_cell_anchor_collection(&self) -> &Vec<OneCellAnchor> {
         &self.one_cell_anchor_collection
     }
@@ -143,6 +170,7 @@ impl WorksheetDrawing {
 
     pub fn has_drawing_object(&self) -> bool {
         !self.chart_collection.is_empty()
+            || !self.chart_ex_collection.is_empty()
             || !self.image_collection.is_empty()
             || !self.one_cell_anchor_collection.is_empty()
             || !self.two_cell_anchor_collection.is_empty()
@@ -296,6 +324,8 @@ impl WorksheetDrawing {
     ) {
         let mut ole_index = 0;
         let mut is_alternate_content = false;
+        let mut is_chart_ex_choice = false;
+        let mut is_chart_ex_fallback = false;
 
         xml_read_loop!(
             reader,
@@ -304,6 +334,14 @@ impl WorksheetDrawing {
                     b"mc:AlternateContent" => {
                         is_alternate_content = true;
                     }
+                    b"mc:Choice" => {
+                        is_chart_ex_choice = get_attribute(e, b"Requires")
+                            .map(|v| v.starts_with("cx"))
+                            .unwrap_or(false);
+                    }
+                    b"mc:Fallback" => {
+                        is_chart_ex_fallback = is_chart_ex_choice;
+                    }
                     b"xdr:oneCellAnchor" => {
                         if is_alternate_content {
                             continue;
@@ -319,6 +357,19 @@ impl WorksheetDrawing {
                         }
                     }
                     b"xdr:twoCellAnchor" => {
+                        if is_chart_ex_fallback {
+                            continue;
+                        }
+                        if is_chart_ex_choice {
+                            let mut obj = TwoCellAnchor::default();
+                            obj.set_attributes(reader, e, drawing_relationships);
+                            if obj.is_chart_ex() {
+                                let mut chart = ChartEx::default();
+                                chart.set_two_cell_anchor(obj);
+                                self.add_chart_ex_collection(chart);
+                            }
+                            continue;
+                        }
                         let os = ole_objects.get_ole_object_mut();
                         if is_alternate_content && !os.is_empty() {
                             os[ole_index]
@@ -356,6 +407,8 @@ impl WorksheetDrawing {
                 match e.name().into_inner() {
                     b"mc:AlternateContent" => {
                         is_alternate_content = false;
+                        is_chart_ex_choice = false;
+                        is_chart_ex_fallback = false;
                     }
                     b"xdr:wsDr" => return,
                     _ => (),
@@ -389,6 +442,9 @@ impl WorksheetDrawing {
         for chart in &self.chart_collection {
             chart.get_two_cell_anchor().write_to(writer, &mut r_id, &0);
         }
+        for chart in &self.chart_ex_collection {
+            chart.get_two_cell_anchor().write_to(writer, &mut r_id, &0);
+        }
         for image in &self.image_collection {
             image.write_to(writer, &mut r_id);
         }
@@ -0,0 +1,71 @@
+use super::Color;
+use super::IconSetValues;
+use super::Style;
+
+/// Look of a cell after the conditional formatting is applied.
+/// See `Worksheet::get_effective_style`.
+#[derive(Clone, Default, Debug)]
+pub struct EffectiveStyle {
+    style: Style,
+    color_scale_color: Option<Color>,
+    data_bar_color: Option<Color>,
+    data_bar_length: Option<f64>,
+    icon: Option<(IconSetValues, u32)>,
+}
+
+impl EffectiveStyle {
+    /// Get the style of the cell with the formats of the matching rules merged.
+    /// A color scale is applied as the background color.
+    pub fn get_style(&self) -> &Style {
+        &self.style
+    }
+
+    pub(crate) fn get_style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    pub(crate) fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = value;
+        self
+    }
+
+    /// Get the color given by a color scale.
+    pub fn get_color_scale_color(&self) -> &Option<Color> {
+        &self.color_scale_color
+    }
+
+    pub(crate) fn set_color_scale_color(&mut self, value: Color) -> &mut Self {
+        self.color_scale_color = Some(value);
+        self
+    }
+
+    /// Get the color of the data bar.
+    pub fn get_data_bar_color(&self) -> &Option<Color> {
+        &self.data_bar_color
+    }
+
+    pub(crate) fn set_data_bar_color(&mut self, value: Color) -> &mut Self {
+        self.data_bar_color = Some(value);
+        self
+    }
+
+    /// Get the length of the data bar. (0.0 - 1.0 of the cell width)
+    pub fn get_data_bar_length(&self) -> &Option<f64> {
+        &self.data_bar_length
+    }
+
+    pub(crate) fn set_data_bar_length(&mut self, value: f64) -> &mut Self {
+        self.data_bar_length = Some(value);
+        self
+    }
+
+    /// Get the icon shown in the cell as the icon set and the zero-based icon id.
+    pub fn get_icon(&self) -> &Option<(IconSetValues, u32)> {
+        &self.icon
+    }
+
+    pub(crate) fn set_icon(&mut self, icon_set: IconSetValues, icon_id: u32) -> &mut Self {
+        self.icon = Some((icon_set, icon_id));
+        self
+    }
+}
@@ -0,0 +1,99 @@
+// cell
+use super::Coordinate;
+use super::StringValue;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Cached value of a cell of the external workbook.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalCell {
+    coordinate: Coordinate,
+    data_type: StringValue,
+    value: String,
+}
+impl ExternalCell {
+    pub fn get_coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+
+    pub fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+        &mut self.coordinate
+    }
+
+    /// Get the type of the value. `n`: number, `s` or `str`: text, `b`: bool, `e`: error
+    pub fn get_data_type(&self) -> &str {
+        match self.data_type.has_value() {
+            true => self.data_type.get_value(),
+            false => "n",
+        }
+    }
+
+    pub fn set_data_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.data_type.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if let Some(v) = get_attribute(e, b"r") {
+            self.coordinate.set_coordinate(v);
+        }
+        set_string_from_xml!(self, e, data_type, "t");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.value = e.unescape().unwrap().to_string();
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "cell")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cell
+        let coordinate = self.coordinate.get_coordinate();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("r", &coordinate));
+        if self.get_data_type() != "n" {
+            attributes.push(("t", self.data_type.get_value_string()));
+        }
+        if self.value.is_empty() {
+            write_start_tag(writer, "cell", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "cell", attributes, false);
+
+        // v
+        write_start_tag(writer, "v", vec![], false);
+        write_text_node(writer, &self.value);
+        write_end_tag(writer, "v");
+
+        write_end_tag(writer, "cell");
+    }
+}
@@ -0,0 +1,69 @@
+// definedName
+use super::StringValue;
+use super::UInt32Value;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Defined name of the external workbook.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalDefinedName {
+    name: StringValue,
+    refers_to: StringValue,
+    sheet_id: UInt32Value,
+}
+impl ExternalDefinedName {
+    pub fn get_name(&self) -> &str {
+        self.name.get_value()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Get the address of the name. ex) `"=Sheet1!$A$1"`
+    pub fn get_refers_to(&self) -> &str {
+        self.refers_to.get_value()
+    }
+
+    pub fn set_refers_to<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.refers_to.set_value(value);
+        self
+    }
+
+    /// Get the index of the sheet of the scope. `None` means the workbook.
+    pub fn get_sheet_id(&self) -> Option<u32> {
+        match self.sheet_id.has_value() {
+            true => Some(*self.sheet_id.get_value()),
+            false => None,
+        }
+    }
+
+    pub fn set_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.sheet_id.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, refers_to, "refersTo");
+        set_string_from_xml!(self, e, sheet_id, "sheetId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // definedName
+        let sheet_id = self.sheet_id.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("name", self.name.get_value_string()));
+        if self.refers_to.has_value() {
+            attributes.push(("refersTo", self.refers_to.get_value_string()));
+        }
+        if self.sheet_id.has_value() {
+            attributes.push(("sheetId", &sheet_id));
+        }
+        write_start_tag(writer, "definedName", attributes, true);
+    }
+}
@@ -0,0 +1,266 @@
+// externalLink
+use super::ExternalCell;
+use super::ExternalDefinedName;
+use super::ExternalSheetData;
+use quick_xml::escape::unescape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::raw::RawFile;
+use structs::raw::RawRelationships;
+use writer::driver::*;
+
+/// Link to an external workbook. (xl/externalLinks/externalLinkN.xml)
+/// The formulas refer to the N-th link as `[N]`. ex) `[1]Sheet1!A1`
+/// DDE and OLE links are not modelled and are written back as read.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalLink {
+    r_id: String,
+    target: String,
+    sheet_names: Vec<String>,
+    defined_names: Vec<ExternalDefinedName>,
+    sheet_data_set: Vec<ExternalSheetData>,
+    raw_data: Option<Box<(RawFile, RawRelationships)>>,
+}
+impl ExternalLink {
+    /// Whether the link is to an external workbook.
+    /// Returns false for the DDE and OLE links kept as read.
+    pub fn is_external_book(&self) -> bool {
+        self.raw_data.is_none()
+    }
+
+    /// Get the path of the external workbook. ex) `"Budget.xlsx"`, `"file:///C:\Data\Budget.xlsx"`
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+
+    /// Set the path of the external workbook. Used to re-point the link to another file.
+    pub fn set_target<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.target = value.into();
+        self
+    }
+
+    pub fn get_sheet_names(&self) -> &Vec<String> {
+        &self.sheet_names
+    }
+
+    pub fn get_sheet_names_mut(&mut self) -> &mut Vec<String> {
+        &mut self.sheet_names
+    }
+
+    pub fn add_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_names.push(value.into());
+        self
+    }
+
+    pub fn get_defined_names(&self) -> &Vec<ExternalDefinedName> {
+        &self.defined_names
+    }
+
+    pub fn get_defined_names_mut(&mut self) -> &mut Vec<ExternalDefinedName> {
+        &mut self.defined_names
+    }
+
+    pub fn get_sheet_data_set(&self) -> &Vec<ExternalSheetData> {
+        &self.sheet_data_set
+    }
+
+    pub fn get_sheet_data_set_mut(&mut self) -> &mut Vec<ExternalSheetData> {
+        &mut self.sheet_data_set
+    }
+
+    /// Get the cached cell of the external workbook.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name of the external workbook. ex) `"Sheet1"`
+    /// * `coordinate` - Coordinate of the cell. ex) `"A1"`
+    pub fn get_cell(&self, sheet_name: &str, coordinate: &str) -> Option<&ExternalCell> {
+        let sheet_id = self.sheet_names.iter().position(|v| v == sheet_name)? as u32;
+        self.sheet_data_set
+            .iter()
+            .find(|v| v.get_sheet_id() == &sheet_id)?
+            .get_cell(coordinate)
+    }
+
+    /// Set the cached value of the cell of the external workbook.
+    /// The sheet name is added if it does not exist.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name of the external workbook. ex) `"Sheet1"`
+    /// * `coordinate` - Coordinate of the cell. ex) `"A1"`
+    /// * `value` - Cached value.
+    /// * `data_type` - Type of the value. `n`: number, `str`: text, `b`: bool, `e`: error
+    pub fn set_cell_value<S: Into<String>>(
+        &mut self,
+        sheet_name: &str,
+        coordinate: &str,
+        value: S,
+        data_type: &str,
+    ) -> &mut Self {
+        let sheet_id = match self.sheet_names.iter().position(|v| v == sheet_name) {
+            Some(v) => v as u32,
+            None => {
+                self.sheet_names.push(sheet_name.to_string());
+                (self.sheet_names.len() - 1) as u32
+            }
+        };
+        let sheet_data = match self
+            .sheet_data_set
+            .iter()
+            .position(|v| v.get_sheet_id() == &sheet_id)
+        {
+            Some(v) => &mut self.sheet_data_set[v],
+            None => {
+                let mut obj = ExternalSheetData::default();
+                obj.set_sheet_id(sheet_id);
+                self.sheet_data_set.push(obj);
+                self.sheet_data_set.last_mut().unwrap()
+            }
+        };
+        let cells = sheet_data.get_cells_mut();
+        let cell = match cells
+            .iter()
+            .position(|v| v.get_coordinate().get_coordinate() == coordinate)
+        {
+            Some(v) => &mut cells[v],
+            None => {
+                let mut obj = ExternalCell::default();
+                obj.get_coordinate_mut().set_coordinate(coordinate);
+                cells.push(obj);
+                cells.last_mut().unwrap()
+            }
+        };
+        cell.set_value(value).set_data_type(data_type);
+        self
+    }
+
+    pub(crate) fn get_r_id(&self) -> &str {
+        &self.r_id
+    }
+
+    pub(crate) fn set_r_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.r_id = value.into();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the part and relationships of a DDE or OLE link.
+    pub(crate) fn get_raw_data(&self) -> Option<&(RawFile, RawRelationships)> {
+        self.raw_data.as_deref()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        file: &RawFile,
+        relationships: &RawRelationships,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"ddeLink" | b"oleLink" => {
+                        self.raw_data = Some(Box::new((file.clone(), relationships.clone())));
+                    }
+                    b"externalBook" => {
+                        if let Some(v) = get_attribute(e, b"r:id") {
+                            for relationship in relationships.get_relationship_list() {
+                                if relationship.get_id() == v {
+                                    self.target = relationship.get_target().to_string();
+                                }
+                            }
+                        }
+                    }
+                    b"sheetData" => {
+                        let mut obj = ExternalSheetData::default();
+                        obj.set_attributes(reader, e, false);
+                        self.sheet_data_set.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"ddeLink" | b"oleLink" => {
+                        self.raw_data = Some(Box::new((file.clone(), relationships.clone())));
+                    }
+                    b"sheetName" => {
+                        let value = get_attribute(e, b"val").unwrap_or_default();
+                        let value = unescape(&value).map_or(value.clone(), |v| v.into_owned());
+                        self.sheet_names.push(value);
+                    }
+                    b"definedName" => {
+                        let mut obj = ExternalDefinedName::default();
+                        obj.set_attributes(e);
+                        self.defined_names.push(obj);
+                    }
+                    b"sheetData" => {
+                        let mut obj = ExternalSheetData::default();
+                        obj.set_attributes(reader, e, true);
+                        self.sheet_data_set.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"externalLink" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "externalLink")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // externalLink
+        write_start_tag(
+            writer,
+            "externalLink",
+            vec![
+                (
+                    "xmlns",
+                    "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+                ),
+                (
+                    "xmlns:r",
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+                ),
+            ],
+            false,
+        );
+
+        // externalBook
+        write_start_tag(writer, "externalBook", vec![("r:id", "rId1")], false);
+
+        // sheetNames
+        if !self.sheet_names.is_empty() {
+            write_start_tag(writer, "sheetNames", vec![], false);
+            for sheet_name in &self.sheet_names {
+                write_start_tag(writer, "sheetName", vec![("val", sheet_name)], true);
+            }
+            write_end_tag(writer, "sheetNames");
+        }
+
+        // definedNames
+        if !self.defined_names.is_empty() {
+            write_start_tag(writer, "definedNames", vec![], false);
+            for defined_name in &self.defined_names {
+                defined_name.write_to(writer);
+            }
+            write_end_tag(writer, "definedNames");
+        }
+
+        // sheetDataSet
+        if !self.sheet_data_set.is_empty() {
+            write_start_tag(writer, "sheetDataSet", vec![], false);
+            for sheet_data in &self.sheet_data_set {
+                sheet_data.write_to(writer);
+            }
+            write_end_tag(writer, "sheetDataSet");
+        }
+
+        write_end_tag(writer, "externalBook");
+        write_end_tag(writer, "externalLink");
+    }
+}
@@ -0,0 +1,135 @@
+// sheetData
+use super::BooleanValue;
+use super::ExternalCell;
+use super::UInt32Value;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Cached cells of a sheet of the external workbook.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalSheetData {
+    sheet_id: UInt32Value,
+    refresh_error: BooleanValue,
+    cells: Vec<ExternalCell>,
+}
+impl ExternalSheetData {
+    /// Get the index of the sheet in `ExternalLink::get_sheet_names`.
+    pub fn get_sheet_id(&self) -> &u32 {
+        self.sheet_id.get_value()
+    }
+
+    pub fn set_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.sheet_id.set_value(value);
+        self
+    }
+
+    pub fn get_refresh_error(&self) -> &bool {
+        self.refresh_error.get_value()
+    }
+
+    pub fn set_refresh_error(&mut self, value: bool) -> &mut Self {
+        self.refresh_error.set_value(value);
+        self
+    }
+
+    pub fn get_cells(&self) -> &Vec<ExternalCell> {
+        &self.cells
+    }
+
+    pub fn get_cells_mut(&mut self) -> &mut Vec<ExternalCell> {
+        &mut self.cells
+    }
+
+    pub fn add_cell(&mut self, value: ExternalCell) -> &mut Self {
+        self.cells.push(value);
+        self
+    }
+
+    /// Get the cached cell.
+    /// # Arguments
+    /// * `coordinate` - Coordinate of the cell. ex) `"A1"`
+    pub fn get_cell(&self, coordinate: &str) -> Option<&ExternalCell> {
+        self.cells
+            .iter()
+            .find(|v| v.get_coordinate().get_coordinate() == coordinate)
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, sheet_id, "sheetId");
+        set_string_from_xml!(self, e, refresh_error, "refreshError");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    let mut obj = ExternalCell::default();
+                    obj.set_attributes(reader, e, false);
+                    self.cells.push(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    let mut obj = ExternalCell::default();
+                    obj.set_attributes(reader, e, true);
+                    self.cells.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sheetData" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "sheetData")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // sheetData
+        let sheet_id = self.sheet_id.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("sheetId", &sheet_id));
+        if self.refresh_error.has_value() {
+            attributes.push(("refreshError", self.refresh_error.get_value_string()));
+        }
+        if self.cells.is_empty() {
+            write_start_tag(writer, "sheetData", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "sheetData", attributes, false);
+
+        // row
+        let mut cells: Vec<&ExternalCell> = self.cells.iter().collect();
+        cells.sort_by_key(|v| {
+            (
+                *v.get_coordinate().get_row_num(),
+                *v.get_coordinate().get_col_num(),
+            )
+        });
+        for (i, cell) in cells.iter().enumerate() {
+            let row = cell.get_coordinate().get_row_num();
+            if i == 0 || cells[i - 1].get_coordinate().get_row_num() != row {
+                if i != 0 {
+                    write_end_tag(writer, "row");
+                }
+                write_start_tag(writer, "row", vec![("r", &row.to_string())], false);
+            }
+            cell.write_to(writer);
+        }
+        write_end_tag(writer, "row");
+
+        write_end_tag(writer, "sheetData");
+    }
+}
@@ -1,34 +1,299 @@
+// hyperlink
+use fancy_regex::Regex;
+use helper::formula::*;
+use quick_xml::escape::unescape;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::raw::RawRelationships;
+use structs::Range;
+use writer::driver::*;
+
+/// Hyperlink of a cell.
+/// `url` is the external target and `location` is the place in the workbook. ex) `'Sheet 2'!A1`
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Hyperlink {
     url: String,
+    location: String,
+    display: String,
     tooltip: String,
-    location: bool,
+    reference: String,
 }
 impl Hyperlink {
-    pub fn get_url(&self) -> &String {
+    pub fn get_url(&self) -> &str {
         &self.url
     }
 
-    pub fn set_url<S: Into<String>>(&mut self, value: S) -> &mut Hyperlink {
+    pub fn set_url<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.url = value.into();
         self
     }
 
-    pub fn get_tooltip(&self) -> &String {
+    pub fn get_location(&self) -> &str {
+        &self.location
+    }
+
+    /// Set the place in the workbook.
+    /// # Arguments
+    /// * `value` - Cell, range or defined name. ex) `"'Sheet 2'!A1"`
+    pub fn set_location<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.location = value.into();
+        self
+    }
+
+    pub fn get_display(&self) -> &str {
+        &self.display
+    }
+
+    pub fn set_display<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.display = value.into();
+        self
+    }
+
+    pub fn get_tooltip(&self) -> &str {
         &self.tooltip
     }
 
-    pub fn set_tooltip<S: Into<String>>(&mut self, value: S) -> &mut Hyperlink {
+    pub fn set_tooltip<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.tooltip = value.into();
         self
     }
 
-    pub fn get_location(&self) -> &bool {
-        &self.location
+    /// Get the range of the hyperlink. Empty means the cell having the hyperlink.
+    pub fn get_reference(&self) -> &str {
+        &self.reference
     }
 
-    pub fn set_location(&mut self, value: bool) -> &mut Hyperlink {
-        self.location = value;
+    /// Set the range of the hyperlink.
+    /// # Arguments
+    /// * `value` - Range starting at the cell having the hyperlink. ex) `"A1:C1"`
+    pub fn set_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.reference = value.into();
         self
     }
+
+    /// Whether the hyperlink needs an external relationship.
+    pub fn is_external(&self) -> bool {
+        !self.url.is_empty()
+    }
+
+    /// Make a hyperlink from a `HYPERLINK()` formula.
+    /// Only a text literal of the link location is detected.
+    /// # Arguments
+    /// * `formula` - Formula. ex) `r#"HYPERLINK("https://example.com","Example")"#`
+    /// # Examples
+    /// ```
+    /// let hyperlink =
+    ///     umya_spreadsheet::Hyperlink::from_formula(r##"HYPERLINK("#Sheet2!A1","Go")"##).unwrap();
+    /// assert_eq!(hyperlink.get_location(), "Sheet2!A1");
+    /// assert_eq!(hyperlink.get_display(), "Go");
+    /// ```
+    pub fn from_formula(formula: &str) -> Option<Self> {
+        let formula = formula.trim().trim_start_matches('=');
+        if !formula
+            .get(..10)
+            .map_or(false, |v| v.eq_ignore_ascii_case("HYPERLINK("))
+        {
+            return None;
+        }
+        let args = split_function_arguments(formula[10..].strip_suffix(')')?);
+        let link = get_string_literal(args.first()?)?;
+        let mut result = Self::default();
+        match link.strip_prefix('#') {
+            Some(v) => result.set_location(v),
+            None => result.set_url(link),
+        };
+        if let Some(v) = args.get(1).and_then(|v| get_string_literal(v)) {
+            result.set_display(v);
+        }
+        Some(result)
+    }
+
+    /// (This method is crate only.)
+    /// Adjust the range of the hyperlink.
+    pub(crate) fn adjustment_insert_reference(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if self.reference.is_empty() {
+            return;
+        }
+        let mut range = Range::default();
+        range.set_range(&self.reference);
+        range.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.reference = range.get_range();
+    }
+
+    /// (This method is crate only.)
+    /// Adjust the range of the hyperlink.
+    pub(crate) fn adjustment_remove_reference(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if self.reference.is_empty() {
+            return;
+        }
+        let mut range = Range::default();
+        range.set_range(&self.reference);
+        range.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.reference = range.get_range();
+    }
+
+    /// (This method is crate only.)
+    /// Adjust the location when it points to the sheet.
+    pub(crate) fn adjustment_insert_location(
+        &mut self,
+        self_sheet_name: &str,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some((prefix, mut range)) = self.get_location_range(self_sheet_name, sheet_name) {
+            range.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+            self.location = format!("{}{}", prefix, range.get_range());
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Adjust the location when it points to the sheet.
+    pub(crate) fn adjustment_remove_location(
+        &mut self,
+        self_sheet_name: &str,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some((prefix, mut range)) = self.get_location_range(self_sheet_name, sheet_name) {
+            range.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+            self.location = format!("{}{}", prefix, range.get_range());
+        }
+    }
+
+    /// `(sheet prefix, range)` of the location when it is a cell or range of the sheet.
+    fn get_location_range(
+        &self,
+        self_sheet_name: &str,
+        sheet_name: &str,
+    ) -> Option<(String, Range)> {
+        lazy_static! {
+            static ref RANGE_REGEX: Regex =
+                Regex::new(r"^\$?[A-Z]{1,3}\$?[0-9]+(:\$?[A-Z]{1,3}\$?[0-9]+)?$").unwrap();
+        }
+        let (prefix, range) = match self.location.rfind('!') {
+            Some(i) => self.location.split_at(i + 1),
+            None => ("", self.location.as_str()),
+        };
+        let location_sheet_name = match prefix.strip_suffix('!') {
+            Some(v) => match v.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+                Some(v) => v.replace("''", "'"),
+                None => v.to_string(),
+            },
+            None => self_sheet_name.to_string(),
+        };
+        if location_sheet_name != sheet_name || !RANGE_REGEX.is_match(range).unwrap_or(false) {
+            return None;
+        }
+        let mut obj = Range::default();
+        obj.set_range(range);
+        Some((prefix.to_string(), obj))
+    }
+
+    /// (This method is crate only.)
+    /// Read the hyperlink. Returns the coordinate of the cell having it.
+    pub(crate) fn set_attributes(
+        &mut self,
+        e: &BytesStart,
+        raw_relationships: Option<&RawRelationships>,
+    ) -> String {
+        let get_text = |key: &[u8]| {
+            get_attribute(e, key).map(|v| unescape(&v).map_or(v.clone(), |v| v.into_owned()))
+        };
+        let reference = get_attribute(e, b"ref").unwrap_or_default();
+        if let Some(v) = get_text(b"location") {
+            self.location = v;
+        }
+        if let Some(v) = get_text(b"display") {
+            self.display = v;
+        }
+        if let Some(v) = get_text(b"tooltip") {
+            self.tooltip = v;
+        }
+        if let (Some(v), Some(relationships)) = (get_attribute(e, b"r:id"), raw_relationships) {
+            self.url = relationships
+                .get_relationship_by_rid(&v)
+                .get_target()
+                .into();
+        }
+        match reference.split_once(':') {
+            Some((start, _)) => {
+                let coordinate = start.to_string();
+                self.reference = reference;
+                coordinate
+            }
+            None => reference,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Write the hyperlink.
+    /// # Arguments
+    /// * `coordinate` - Coordinate of the cell having the hyperlink.
+    /// * `r_id` - Relationship id of the url.
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        coordinate: &str,
+        r_id: &str,
+    ) {
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.reference.is_empty() {
+            attributes.push(("ref", coordinate));
+        } else {
+            attributes.push(("ref", &self.reference));
+        }
+        if self.is_external() {
+            attributes.push(("r:id", r_id));
+        }
+        if !self.location.is_empty() {
+            attributes.push(("location", &self.location));
+        }
+        if !self.tooltip.is_empty() {
+            attributes.push(("tooltip", &self.tooltip));
+        }
+        if !self.display.is_empty() {
+            attributes.push(("display", &self.display));
+        }
+        write_start_tag(writer, "hyperlink", attributes, true);
+    }
 }
@@ -1,5 +1,9 @@
-use super::Color;
+// iconSet, x14:iconSet
+use super::BooleanValue;
 use super::ConditionalFormatValueObject;
+use super::ConditionalFormattingIcon;
+use super::EnumValue;
+use super::IconSetValues;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -10,11 +14,67 @@ use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
 pub struct IconSet {
+    icon_set: EnumValue<IconSetValues>,
+    show_value: BooleanValue,
+    percent: BooleanValue,
+    reverse: BooleanValue,
+    custom: BooleanValue,
     cfvo_collection: Vec<ConditionalFormatValueObject>,
-    color_collection: Vec<Color>,
+    cf_icon_collection: Vec<ConditionalFormattingIcon>,
 }
 
 impl IconSet {
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    pub fn get_show_value(&self) -> &bool {
+        if self.show_value.has_value() {
+            return self.show_value.get_value();
+        }
+        &true
+    }
+
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    pub fn get_percent(&self) -> &bool {
+        if self.percent.has_value() {
+            return self.percent.get_value();
+        }
+        &true
+    }
+
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    pub fn get_reverse(&self) -> &bool {
+        self.reverse.get_value()
+    }
+
+    pub fn set_reverse(&mut self, value: bool) -> &mut Self {
+        self.reverse.set_value(value);
+        self
+    }
+
+    pub fn get_custom(&self) -> &bool {
+        self.custom.get_value()
+    }
+
+    pub fn set_custom(&mut self, value: bool) -> &mut Self {
+        self.custom.set_value(value);
+        self
+    }
+
     pub fn get_cfvo_collection(&self) -> &Vec<ConditionalFormatValueObject> {
         &self.cfvo_collection
     }
@@ -29,65 +89,131 @@ impl IconSet {
         self
     }
 
-    pub fn get_color_collection(&self) -> &Vec<Color> {
-        &self.color_collection
+    /// Get the icons of a custom icon set. (Office 2010)
+    pub fn get_cf_icon_collection(&self) -> &Vec<ConditionalFormattingIcon> {
+        &self.cf_icon_collection
     }
 
-    pub fn set_color_collection(&mut self, value: Vec<Color>) -> &mut Self {
-        self.color_collection = value;
+    pub fn set_cf_icon_collection(&mut self, value: Vec<ConditionalFormattingIcon>) -> &mut Self {
+        self.cf_icon_collection = value;
         self
     }
 
-    pub fn add_color_collection(&mut self, value: Color) -> &mut Self {
-        self.color_collection.push(value);
+    pub fn add_cf_icon_collection(&mut self, value: ConditionalFormattingIcon) -> &mut Self {
+        self.cf_icon_collection.push(value);
         self
     }
 
+    /// (This method is crate only.)
+    /// Whether the icon set can only be written in the x14 extension.
+    pub(crate) fn is_x14(&self) -> bool {
+        self.icon_set.get_value().is_x14()
+            || *self.custom.get_value()
+            || !self.cf_icon_collection.is_empty()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
+        e: &BytesStart,
+        empty_flag: bool,
     ) {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, show_value, "showValue");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, reverse, "reverse");
+        set_string_from_xml!(self, e, custom, "custom");
+
+        if empty_flag {
+            return;
+        }
+
         xml_read_loop!(
             reader,
-                Event::Empty(ref e) => {
-                    match e.name().into_inner() {
-                        b"cfvo" => {
-                            let mut obj = ConditionalFormatValueObject::default();
-                            obj.set_attributes(reader, e, true);
-                            self.cfvo_collection.push(obj);
-                        }
-                        b"color" => {
-                            let mut obj = Color::default();
-                            obj.set_attributes(reader, e, true);
-                            self.color_collection.push(obj);
-                        }
-                        _ => (),
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"cfvo" | b"x14:cfvo" => {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_attributes(reader, e, true);
+                        self.cfvo_collection.push(obj);
+                    }
+                    b"x14:cfIcon" => {
+                        let mut obj = ConditionalFormattingIcon::default();
+                        obj.set_attributes(e);
+                        self.cf_icon_collection.push(obj);
                     }
-                },
-                Event::End(ref e) => {
-                    if e.name().into_inner() == b"dataBar" {
-                        return
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"cfvo" | b"x14:cfvo" => {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_attributes(reader, e, false);
+                        self.cfvo_collection.push(obj);
                     }
-                },
-                Event::Eof => panic!("Error not find {} end element", "dataBar")
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"iconSet" | b"x14:iconSet" => return,
+                    _ => (),
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "iconSet")
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        // iconSet
+        write_start_tag(writer, "iconSet", self.get_attributes(), false);
 
         // cfvo
         for v in &self.cfvo_collection {
             v.write_to(writer);
         }
 
-        // color
-        for v in &self.color_collection {
-            v.write_to_color(writer);
+        write_end_tag(writer, "iconSet");
+    }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:iconSet
+        let mut attributes = self.get_attributes();
+        if self.custom.has_value() {
+            attributes.push(("custom", self.custom.get_value_string()));
+        } else if !self.cf_icon_collection.is_empty() {
+            attributes.push(("custom", "1"));
         }
+        write_start_tag(writer, "x14:iconSet", attributes, false);
 
-        write_end_tag(writer, "dataBar");
+        // x14:cfvo
+        for v in &self.cfvo_collection {
+            v.write_to_x14(writer);
+        }
+
+        // x14:cfIcon
+        for v in &self.cf_icon_collection {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "x14:iconSet");
+    }
+
+    fn get_attributes(&self) -> Vec<(&str, &str)> {
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.icon_set.has_value() {
+            attributes.push(("iconSet", self.icon_set.get_value_string()));
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.get_value_string()));
+        }
+        if self.percent.has_value() {
+            attributes.push(("percent", self.percent.get_value_string()));
+        }
+        if self.reverse.has_value() {
+            attributes.push(("reverse", self.reverse.get_value_string()));
+        }
+        attributes
     }
 }
@@ -0,0 +1,50 @@
+use super::Style;
+
+/// A cell style with a name, as listed in the Cell Styles gallery of Excel.
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+pub struct NamedCellStyle {
+    name: String,
+    builtin_id: Option<u32>,
+    format_id: u32,
+    style: Style,
+}
+impl NamedCellStyle {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Get the builtinId. None if it is a custom style.
+    pub fn get_builtin_id(&self) -> &Option<u32> {
+        &self.builtin_id
+    }
+
+    pub(crate) fn set_builtin_id(&mut self, value: Option<u32>) -> &mut Self {
+        self.builtin_id = value;
+        self
+    }
+
+    /// Get the index of the style in cellStyleXfs. (xfId)
+    pub fn get_format_id(&self) -> &u32 {
+        &self.format_id
+    }
+
+    pub(crate) fn set_format_id(&mut self, value: u32) -> &mut Self {
+        self.format_id = value;
+        self
+    }
+
+    /// Get the formatting of the style.
+    pub fn get_style(&self) -> &Style {
+        &self.style
+    }
+
+    pub(crate) fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = value;
+        self
+    }
+}
@@ -0,0 +1,82 @@
+use super::Font;
+
+/// The appearance of a note made by `Worksheet::add_note_with_option`.
+#[derive(Clone, Debug)]
+pub struct NoteOption {
+    visible: bool,
+    width: u32,
+    height: u32,
+    size_in_pixels: bool,
+    fill_color: String,
+    font: Option<Font>,
+}
+impl Default for NoteOption {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            width: 2,
+            height: 4,
+            size_in_pixels: false,
+            fill_color: String::from("#ffffe1"),
+            font: None,
+        }
+    }
+}
+impl NoteOption {
+    pub fn get_visible(&self) -> &bool {
+        &self.visible
+    }
+
+    pub fn set_visible(&mut self, value: bool) -> &mut Self {
+        self.visible = value;
+        self
+    }
+
+    pub fn get_width(&self) -> &u32 {
+        &self.width
+    }
+
+    pub fn get_height(&self) -> &u32 {
+        &self.height
+    }
+
+    /// Whether the width and height are in pixels or in cells.
+    pub fn get_size_in_pixels(&self) -> &bool {
+        &self.size_in_pixels
+    }
+
+    /// Set the size of the box in columns and rows.
+    pub fn set_size_in_cells(&mut self, columns: u32, rows: u32) -> &mut Self {
+        self.width = columns;
+        self.height = rows;
+        self.size_in_pixels = false;
+        self
+    }
+
+    /// Set the size of the box in pixels.
+    pub fn set_size_in_pixels(&mut self, width: u32, height: u32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self.size_in_pixels = true;
+        self
+    }
+
+    pub fn get_fill_color(&self) -> &str {
+        &self.fill_color
+    }
+
+    /// Set the fill color of the box. ex) "#ffffe1"
+    pub fn set_fill_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.fill_color = value.into();
+        self
+    }
+
+    pub fn get_font(&self) -> &Option<Font> {
+        &self.font
+    }
+
+    pub fn set_font(&mut self, value: Font) -> &mut Self {
+        self.font = Some(value);
+        self
+    }
+}
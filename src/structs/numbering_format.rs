@@ -61,6 +61,9 @@ impl NumberingFormat {
     pub const FORMAT_DATE_TIME6: &'static str = "h:mm:ss";
     pub const FORMAT_DATE_TIME8: &'static str = "h:mm:ss;@";
     pub const FORMAT_DATE_YYYYMMDDSLASH: &'static str = "yyyy/mm/dd;@";
+    pub const FORMAT_DATE_YYYYMMDD_HHMMSS: &'static str = "yyyy-mm-dd h:mm:ss";
+    pub const FORMAT_DATE_YYYYMMDD_HHMMSS_000: &'static str = "yyyy-mm-dd h:mm:ss.000";
+    pub const FORMAT_DURATION: &'static str = "[h]:mm:ss";
 
     pub const FORMAT_CURRENCY_USD_SIMPLE: &'static str = r##""$"#,##0.00_-"##;
     pub const FORMAT_CURRENCY_USD: &'static str = r###"$#,##0_-"###;
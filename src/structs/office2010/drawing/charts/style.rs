@@ -4,12 +4,32 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::ByteValue;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
-pub struct Style {}
+pub struct Style {
+    val: ByteValue,
+}
 
 impl Style {
+    /// Get Chart Style Id.
+    /// The default is 2.
+    pub fn get_val(&self) -> &u8 {
+        if self.val.has_value() {
+            return self.val.get_value();
+        }
+        &2
+    }
+
+    /// Set Chart Style Id.
+    /// # Arguments
+    /// * `value` - Built-in chart style id. (1 - 48)
+    pub fn set_val(&mut self, value: u8) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -17,6 +37,19 @@ impl Style {
     ) {
         xml_read_loop!(
             reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c14:style" => {
+                    if let Some(v) = get_attribute(e, b"val") {
+                        if let Ok(v) = v.parse::<u8>() {
+                            self.val.set_value(v.saturating_sub(100));
+                        }
+                    }
+                }
+                b"c:style" => {
+                    self.set_attributes_style(e);
+                }
+                _ => (),
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
                     return
@@ -26,6 +59,14 @@ impl Style {
         );
     }
 
+    pub(crate) fn set_attributes_style(&mut self, e: &BytesStart) {
+        if let Some(v) = get_attribute(e, b"val") {
+            if let Ok(v) = v.parse::<u8>() {
+                self.val.set_value(v);
+            }
+        }
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // mc:AlternateContent
         write_start_tag(
@@ -53,15 +94,17 @@ impl Style {
         );
 
         // c14:style
-        write_start_tag(writer, "c14:style", vec![("val", "102")], true);
+        let c14_val = (100u16 + *self.get_val() as u16).to_string();
+        write_start_tag(writer, "c14:style", vec![("val", &c14_val)], true);
 
         write_end_tag(writer, "mc:Choice");
 
         // mc:Fallback
         write_start_tag(writer, "mc:Fallback", vec![], false);
 
-        // c14:style
-        write_start_tag(writer, "c:style", vec![("val", "2")], true);
+        // c:style
+        let val = self.get_val().to_string();
+        write_start_tag(writer, "c:style", vec![("val", &val)], true);
 
         write_end_tag(writer, "mc:Fallback");
 
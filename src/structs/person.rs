@@ -0,0 +1,78 @@
+// person
+use super::StringValue;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Author of threaded comments. (xl/persons/person.xml)
+#[derive(Clone, Default, Debug)]
+pub struct Person {
+    display_name: StringValue,
+    id: StringValue,
+    user_id: StringValue,
+    provider_id: StringValue,
+}
+
+impl Person {
+    pub fn get_display_name(&self) -> &str {
+        self.display_name.get_value()
+    }
+
+    pub fn set_display_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.display_name.set_value(value);
+        self
+    }
+
+    /// Get the GUID referred by `ThreadedComment::get_person_id`.
+    pub fn get_id(&self) -> &str {
+        self.id.get_value()
+    }
+
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    /// Get the user id given by the provider. ex) an email address
+    pub fn get_user_id(&self) -> &str {
+        self.user_id.get_value()
+    }
+
+    pub fn set_user_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.user_id.set_value(value);
+        self
+    }
+
+    /// Get the identity provider. ex) `AD`, `Windows Live`, `None`
+    pub fn get_provider_id(&self) -> &str {
+        self.provider_id.get_value()
+    }
+
+    pub fn set_provider_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.provider_id.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, display_name, "displayName");
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, user_id, "userId");
+        set_string_from_xml!(self, e, provider_id, "providerId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // person
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("displayName", self.display_name.get_value_string()));
+        attributes.push(("id", self.id.get_value_string()));
+        if self.user_id.has_value() {
+            attributes.push(("userId", self.user_id.get_value_string()));
+        }
+        if self.provider_id.has_value() {
+            attributes.push(("providerId", self.provider_id.get_value_string()));
+        }
+        write_start_tag(writer, "person", attributes, true);
+    }
+}
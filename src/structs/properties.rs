@@ -1,3 +1,6 @@
+use super::CustomProperty;
+use super::CustomPropertyValue;
+
 #[derive(Clone, Debug)]
 pub struct Properties {
     creator: String,
@@ -13,7 +16,7 @@ pub struct Properties {
     company: String,
     revision: String,
     version: String,
-    custom_properties: Vec<String>,
+    custom_properties: Vec<CustomProperty>,
 }
 impl Default for Properties {
     fn default() -> Self {
@@ -135,30 +138,107 @@ impl Properties {
         self
     }
 
+    /// The value is read from and written to `docProps/app.xml` with `AppProperties`.
+    #[deprecated(note = "use `Spreadsheet::get_app_properties().get_manager()` instead")]
     pub fn get_manager(&self) -> &str {
         &self.manager
     }
 
+    /// Used when `AppProperties` has no manager.
+    #[deprecated(note = "use `Spreadsheet::get_app_properties_mut().set_manager()` instead")]
     pub fn set_manager<S: Into<String>>(&mut self, value: S) -> &mut Properties {
         self.manager = value.into();
         self
     }
 
+    /// The value is read from and written to `docProps/app.xml` with `AppProperties`.
+    #[deprecated(note = "use `Spreadsheet::get_app_properties().get_company()` instead")]
     pub fn get_company(&self) -> &str {
         &self.company
     }
 
+    /// Used when `AppProperties` has no company.
+    #[deprecated(note = "use `Spreadsheet::get_app_properties_mut().set_company()` instead")]
     pub fn set_company<S: Into<String>>(&mut self, value: S) -> &mut Properties {
         self.company = value.into();
         self
     }
 
-    pub fn get_custom_properties(&self) -> &Vec<String> {
+    pub fn get_custom_properties(&self) -> &Vec<CustomProperty> {
         &self.custom_properties
     }
 
-    pub fn set_custom_properties(&mut self, value: Vec<String>) -> &mut Properties {
+    pub fn get_custom_properties_mut(&mut self) -> &mut Vec<CustomProperty> {
+        &mut self.custom_properties
+    }
+
+    pub fn set_custom_properties(&mut self, value: Vec<CustomProperty>) -> &mut Properties {
         self.custom_properties = value;
         self
     }
+
+    /// Get the custom property by the name.
+    pub fn get_custom_property(&self, name: &str) -> Option<&CustomProperty> {
+        self.custom_properties.iter().find(|v| v.get_name() == name)
+    }
+
+    /// Add a custom property. The pid is set if it is not set yet.
+    pub fn add_custom_property(&mut self, mut value: CustomProperty) -> &mut Properties {
+        if value.get_pid() == &0 {
+            let pid = self
+                .custom_properties
+                .iter()
+                .map(|v| *v.get_pid())
+                .max()
+                .unwrap_or(1)
+                .max(1);
+            value.set_pid(pid + 1);
+        }
+        self.custom_properties.push(value);
+        self
+    }
+
+    /// Set the value of the custom property. The property is added if it does not exist.
+    /// # Arguments
+    /// * `name` - Name of the property. ex) `"MSIP_Label_xxx_Enabled"`
+    /// * `value` - Text, number, bool or date.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let properties = book.get_properties_mut();
+    /// properties.set_custom_property("Reviewed", true);
+    /// properties.set_custom_property("Department", "Sales");
+    /// assert_eq!(
+    ///     properties.get_custom_property("Reviewed").unwrap().get_value().get_bool(),
+    ///     Some(true)
+    /// );
+    /// ```
+    pub fn set_custom_property<S: Into<String>, V: Into<CustomPropertyValue>>(
+        &mut self,
+        name: S,
+        value: V,
+    ) -> &mut Properties {
+        let name = name.into();
+        match self
+            .custom_properties
+            .iter_mut()
+            .find(|v| v.get_name() == name)
+        {
+            Some(v) => {
+                v.set_value(value);
+            }
+            None => {
+                let mut obj = CustomProperty::default();
+                obj.set_name(name).set_value(value);
+                self.add_custom_property(obj);
+            }
+        }
+        self
+    }
+
+    /// Remove the custom property by the name.
+    pub fn remove_custom_property(&mut self, name: &str) -> &mut Properties {
+        self.custom_properties.retain(|v| v.get_name() != name);
+        self
+    }
 }
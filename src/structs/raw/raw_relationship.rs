@@ -5,6 +5,7 @@ use reader::driver::*;
 use std::io;
 use std::io::Cursor;
 use structs::raw::RawFile;
+use structs::raw::RawRelationships;
 use structs::StringValue;
 use structs::WriterManager;
 use writer::driver::*;
@@ -17,6 +18,7 @@ pub(crate) struct RawRelationship {
     target: StringValue,
     raw_file: RawFile,
     target_mode: StringValue,
+    relationships: Option<Box<RawRelationships>>,
 }
 
 impl RawRelationship {
@@ -69,6 +71,10 @@ impl RawRelationship {
         self
     }
 
+    pub(crate) fn get_relationships(&self) -> Option<&RawRelationships> {
+        self.relationships.as_deref()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead, A: io::Read + io::Seek>(
         &mut self,
         _reader: &mut Reader<R>,
@@ -86,6 +92,19 @@ impl RawRelationship {
             let target = self.get_target().to_string();
             self.get_raw_file_mut()
                 .set_attributes(arv, base_path, &target);
+            if matches!(
+                self.get_type(),
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart"
+                    | "http://schemas.microsoft.com/office/2014/relationships/chartEx"
+            ) {
+                // chart parts carry their own style and colors relationships.
+                let path = self.get_raw_file().get_path();
+                let rel_name = self.get_raw_file().make_rel_name();
+                let mut relationships = RawRelationships::default();
+                if relationships.set_attributes(arv, &path, &rel_name) {
+                    self.relationships = Some(Box::new(relationships));
+                }
+            }
         }
     }
 
@@ -104,6 +123,10 @@ impl RawRelationship {
         &self,
         writer_mng: &mut WriterManager<W>,
     ) -> Result<(), XlsxError> {
-        self.get_raw_file().write_to(writer_mng)
+        self.get_raw_file().write_to(writer_mng)?;
+        if let Some(v) = &self.relationships {
+            v.write_to(writer_mng, None)?;
+        }
+        Ok(())
     }
 }
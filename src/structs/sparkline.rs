@@ -0,0 +1,93 @@
+// x14:sparkline
+use super::SequenceOfReferences;
+use super::StringValue;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Sparkline {
+    formula: StringValue,
+    sequence_of_references: SequenceOfReferences,
+}
+
+impl Sparkline {
+    /// Get the data range. (e.g. "Sheet1!A1:E1")
+    pub fn get_formula(&self) -> &str {
+        self.formula.get_value()
+    }
+
+    pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula.set_value(value);
+        self
+    }
+
+    /// Get the cell that shows the sparkline.
+    pub fn get_sequence_of_references(&self) -> &SequenceOfReferences {
+        &self.sequence_of_references
+    }
+
+    pub fn get_sequence_of_references_mut(&mut self) -> &mut SequenceOfReferences {
+        &mut self.sequence_of_references
+    }
+
+    pub fn set_sequence_of_references(&mut self, value: SequenceOfReferences) -> &mut Self {
+        self.sequence_of_references = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        let mut tag = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                tag = e.name().into_inner().to_vec();
+            },
+            Event::Text(e) => {
+                let value = e.unescape().unwrap().to_string();
+                match tag.as_slice() {
+                    b"xm:f" => {
+                        self.formula.set_value(value);
+                    }
+                    b"xm:sqref" => {
+                        self.sequence_of_references.set_sqref(value);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                tag.clear();
+                if e.name().into_inner() == b"x14:sparkline" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "x14:sparkline")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparkline
+        write_start_tag(writer, "x14:sparkline", vec![], false);
+
+        // xm:f
+        if self.formula.has_value() {
+            write_start_tag(writer, "xm:f", vec![], false);
+            write_text_node(writer, self.formula.get_value());
+            write_end_tag(writer, "xm:f");
+        }
+
+        // xm:sqref
+        write_start_tag(writer, "xm:sqref", vec![], false);
+        write_text_node(writer, self.sequence_of_references.get_sqref());
+        write_end_tag(writer, "xm:sqref");
+
+        write_end_tag(writer, "x14:sparkline");
+    }
+}
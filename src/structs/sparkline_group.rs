@@ -0,0 +1,553 @@
+// x14:sparklineGroup
+use super::BooleanValue;
+use super::Color;
+use super::DisplayEmptyCellsAsValues;
+use super::DoubleValue;
+use super::EnumValue;
+use super::Sparkline;
+use super::SparklineAxisMinMaxValues;
+use super::SparklineTypeValues;
+use super::StringValue;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct SparklineGroup {
+    manual_max: DoubleValue,
+    manual_min: DoubleValue,
+    line_weight: DoubleValue,
+    r#type: EnumValue<SparklineTypeValues>,
+    display_empty_cells_as: EnumValue<DisplayEmptyCellsAsValues>,
+    date_axis: BooleanValue,
+    markers: BooleanValue,
+    high: BooleanValue,
+    low: BooleanValue,
+    first: BooleanValue,
+    last: BooleanValue,
+    negative: BooleanValue,
+    display_x_axis: BooleanValue,
+    display_hidden: BooleanValue,
+    right_to_left: BooleanValue,
+    min_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    max_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    color_series: Color,
+    color_negative: Color,
+    color_axis: Color,
+    color_markers: Color,
+    color_first: Color,
+    color_last: Color,
+    color_high: Color,
+    color_low: Color,
+    date_axis_formula: StringValue,
+    sparkline_list: Vec<Sparkline>,
+}
+
+impl SparklineGroup {
+    pub fn get_type(&self) -> &SparklineTypeValues {
+        self.r#type.get_value()
+    }
+
+    pub fn set_type(&mut self, value: SparklineTypeValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// Get Line Weight in points. (Line sparklines only)
+    pub fn get_line_weight(&self) -> &f64 {
+        self.line_weight.get_value()
+    }
+
+    pub fn set_line_weight(&mut self, value: f64) -> &mut Self {
+        self.line_weight.set_value(value);
+        self
+    }
+
+    pub fn get_display_empty_cells_as(&self) -> &DisplayEmptyCellsAsValues {
+        self.display_empty_cells_as.get_value()
+    }
+
+    pub fn set_display_empty_cells_as(&mut self, value: DisplayEmptyCellsAsValues) -> &mut Self {
+        self.display_empty_cells_as.set_value(value);
+        self
+    }
+
+    /// Get Manual Maximum.
+    /// Used when the max axis type is `Custom`.
+    pub fn get_manual_max(&self) -> &f64 {
+        self.manual_max.get_value()
+    }
+
+    pub fn set_manual_max(&mut self, value: f64) -> &mut Self {
+        self.manual_max.set_value(value);
+        self
+    }
+
+    /// Get Manual Minimum.
+    /// Used when the min axis type is `Custom`.
+    pub fn get_manual_min(&self) -> &f64 {
+        self.manual_min.get_value()
+    }
+
+    pub fn set_manual_min(&mut self, value: f64) -> &mut Self {
+        self.manual_min.set_value(value);
+        self
+    }
+
+    pub fn get_min_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.min_axis_type.get_value()
+    }
+
+    pub fn set_min_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.min_axis_type.set_value(value);
+        self
+    }
+
+    pub fn get_max_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.max_axis_type.get_value()
+    }
+
+    pub fn set_max_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.max_axis_type.set_value(value);
+        self
+    }
+
+    /// Use the date axis range.
+    pub fn get_date_axis(&self) -> &bool {
+        self.date_axis.get_value()
+    }
+
+    pub fn set_date_axis(&mut self, value: bool) -> &mut Self {
+        self.date_axis.set_value(value);
+        self
+    }
+
+    /// Show markers. (Line sparklines only)
+    pub fn get_markers(&self) -> &bool {
+        self.markers.get_value()
+    }
+
+    pub fn set_markers(&mut self, value: bool) -> &mut Self {
+        self.markers.set_value(value);
+        self
+    }
+
+    /// Highlight the high point.
+    pub fn get_high(&self) -> &bool {
+        self.high.get_value()
+    }
+
+    pub fn set_high(&mut self, value: bool) -> &mut Self {
+        self.high.set_value(value);
+        self
+    }
+
+    /// Highlight the low point.
+    pub fn get_low(&self) -> &bool {
+        self.low.get_value()
+    }
+
+    pub fn set_low(&mut self, value: bool) -> &mut Self {
+        self.low.set_value(value);
+        self
+    }
+
+    /// Highlight the first point.
+    pub fn get_first(&self) -> &bool {
+        self.first.get_value()
+    }
+
+    pub fn set_first(&mut self, value: bool) -> &mut Self {
+        self.first.set_value(value);
+        self
+    }
+
+    /// Highlight the last point.
+    pub fn get_last(&self) -> &bool {
+        self.last.get_value()
+    }
+
+    pub fn set_last(&mut self, value: bool) -> &mut Self {
+        self.last.set_value(value);
+        self
+    }
+
+    /// Highlight negative points.
+    pub fn get_negative(&self) -> &bool {
+        self.negative.get_value()
+    }
+
+    pub fn set_negative(&mut self, value: bool) -> &mut Self {
+        self.negative.set_value(value);
+        self
+    }
+
+    /// Show the horizontal axis.
+    pub fn get_display_x_axis(&self) -> &bool {
+        self.display_x_axis.get_value()
+    }
+
+    pub fn set_display_x_axis(&mut self, value: bool) -> &mut Self {
+        self.display_x_axis.set_value(value);
+        self
+    }
+
+    /// Show data in hidden rows and columns.
+    pub fn get_display_hidden(&self) -> &bool {
+        self.display_hidden.get_value()
+    }
+
+    pub fn set_display_hidden(&mut self, value: bool) -> &mut Self {
+        self.display_hidden.set_value(value);
+        self
+    }
+
+    /// Plot data right-to-left.
+    pub fn get_right_to_left(&self) -> &bool {
+        self.right_to_left.get_value()
+    }
+
+    pub fn set_right_to_left(&mut self, value: bool) -> &mut Self {
+        self.right_to_left.set_value(value);
+        self
+    }
+
+    /// Series color.
+    pub fn get_color_series(&self) -> &Color {
+        &self.color_series
+    }
+
+    pub fn get_color_series_mut(&mut self) -> &mut Color {
+        &mut self.color_series
+    }
+
+    pub fn set_color_series(&mut self, value: Color) -> &mut Self {
+        self.color_series = value;
+        self
+    }
+
+    /// Negative points color.
+    pub fn get_color_negative(&self) -> &Color {
+        &self.color_negative
+    }
+
+    pub fn get_color_negative_mut(&mut self) -> &mut Color {
+        &mut self.color_negative
+    }
+
+    pub fn set_color_negative(&mut self, value: Color) -> &mut Self {
+        self.color_negative = value;
+        self
+    }
+
+    /// Axis color.
+    pub fn get_color_axis(&self) -> &Color {
+        &self.color_axis
+    }
+
+    pub fn get_color_axis_mut(&mut self) -> &mut Color {
+        &mut self.color_axis
+    }
+
+    pub fn set_color_axis(&mut self, value: Color) -> &mut Self {
+        self.color_axis = value;
+        self
+    }
+
+    /// Markers color.
+    pub fn get_color_markers(&self) -> &Color {
+        &self.color_markers
+    }
+
+    pub fn get_color_markers_mut(&mut self) -> &mut Color {
+        &mut self.color_markers
+    }
+
+    pub fn set_color_markers(&mut self, value: Color) -> &mut Self {
+        self.color_markers = value;
+        self
+    }
+
+    /// First point color.
+    pub fn get_color_first(&self) -> &Color {
+        &self.color_first
+    }
+
+    pub fn get_color_first_mut(&mut self) -> &mut Color {
+        &mut self.color_first
+    }
+
+    pub fn set_color_first(&mut self, value: Color) -> &mut Self {
+        self.color_first = value;
+        self
+    }
+
+    /// Last point color.
+    pub fn get_color_last(&self) -> &Color {
+        &self.color_last
+    }
+
+    pub fn get_color_last_mut(&mut self) -> &mut Color {
+        &mut self.color_last
+    }
+
+    pub fn set_color_last(&mut self, value: Color) -> &mut Self {
+        self.color_last = value;
+        self
+    }
+
+    /// High point color.
+    pub fn get_color_high(&self) -> &Color {
+        &self.color_high
+    }
+
+    pub fn get_color_high_mut(&mut self) -> &mut Color {
+        &mut self.color_high
+    }
+
+    pub fn set_color_high(&mut self, value: Color) -> &mut Self {
+        self.color_high = value;
+        self
+    }
+
+    /// Low point color.
+    pub fn get_color_low(&self) -> &Color {
+        &self.color_low
+    }
+
+    pub fn get_color_low_mut(&mut self) -> &mut Color {
+        &mut self.color_low
+    }
+
+    pub fn set_color_low(&mut self, value: Color) -> &mut Self {
+        self.color_low = value;
+        self
+    }
+
+    /// Get the date axis range. (e.g. "Sheet1!A10:E10")
+    pub fn get_date_axis_formula(&self) -> &str {
+        self.date_axis_formula.get_value()
+    }
+
+    pub fn set_date_axis_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.date_axis_formula.set_value(value);
+        self.date_axis.set_value(true);
+        self
+    }
+
+    pub fn get_sparkline_list(&self) -> &Vec<Sparkline> {
+        &self.sparkline_list
+    }
+
+    pub fn get_sparkline_list_mut(&mut self) -> &mut Vec<Sparkline> {
+        &mut self.sparkline_list
+    }
+
+    pub fn set_sparkline_list(&mut self, value: Vec<Sparkline>) -> &mut Self {
+        self.sparkline_list = value;
+        self
+    }
+
+    pub fn add_sparkline_list(&mut self, value: Sparkline) -> &mut Self {
+        self.sparkline_list.push(value);
+        self
+    }
+
+    /// Add a sparkline.
+    /// # Arguments
+    /// * `formula` - Data range. (e.g. "Sheet1!A1:E1")
+    /// * `sqref` - Cell that shows the sparkline. (e.g. "F1")
+    pub fn add_sparkline<S: Into<String>>(&mut self, formula: S, sqref: S) -> &mut Self {
+        let mut sparkline = Sparkline::default();
+        sparkline.set_formula(formula);
+        sparkline.get_sequence_of_references_mut().set_sqref(sqref);
+        self.add_sparkline_list(sparkline);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, manual_max, "manualMax");
+        set_string_from_xml!(self, e, manual_min, "manualMin");
+        set_string_from_xml!(self, e, line_weight, "lineWeight");
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, display_empty_cells_as, "displayEmptyCellsAs");
+        set_string_from_xml!(self, e, date_axis, "dateAxis");
+        set_string_from_xml!(self, e, markers, "markers");
+        set_string_from_xml!(self, e, high, "high");
+        set_string_from_xml!(self, e, low, "low");
+        set_string_from_xml!(self, e, first, "first");
+        set_string_from_xml!(self, e, last, "last");
+        set_string_from_xml!(self, e, negative, "negative");
+        set_string_from_xml!(self, e, display_x_axis, "displayXAxis");
+        set_string_from_xml!(self, e, display_hidden, "displayHidden");
+        set_string_from_xml!(self, e, right_to_left, "rightToLeft");
+        set_string_from_xml!(self, e, min_axis_type, "minAxisType");
+        set_string_from_xml!(self, e, max_axis_type, "maxAxisType");
+
+        let mut is_formula = false;
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"x14:colorSeries" => {
+                    self.color_series.set_attributes(reader, e, true);
+                }
+                b"x14:colorNegative" => {
+                    self.color_negative.set_attributes(reader, e, true);
+                }
+                b"x14:colorAxis" => {
+                    self.color_axis.set_attributes(reader, e, true);
+                }
+                b"x14:colorMarkers" => {
+                    self.color_markers.set_attributes(reader, e, true);
+                }
+                b"x14:colorFirst" => {
+                    self.color_first.set_attributes(reader, e, true);
+                }
+                b"x14:colorLast" => {
+                    self.color_last.set_attributes(reader, e, true);
+                }
+                b"x14:colorHigh" => {
+                    self.color_high.set_attributes(reader, e, true);
+                }
+                b"x14:colorLow" => {
+                    self.color_low.set_attributes(reader, e, true);
+                }
+                _ => (),
+            },
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"x14:sparkline" => {
+                    let mut obj = Sparkline::default();
+                    obj.set_attributes(reader, e);
+                    self.add_sparkline_list(obj);
+                }
+                b"xm:f" => {
+                    is_formula = true;
+                }
+                _ => (),
+            },
+            Event::Text(e) => {
+                if is_formula {
+                    self.date_axis_formula.set_value(e.unescape().unwrap().to_string());
+                }
+            },
+            Event::End(ref e) => match e.name().into_inner() {
+                b"xm:f" => {
+                    is_formula = false;
+                }
+                b"x14:sparklineGroup" => return,
+                _ => (),
+            },
+            Event::Eof => panic!("Error not find {} end element", "x14:sparklineGroup")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparklineGroup
+        let manual_max = self.manual_max.get_value_string();
+        let manual_min = self.manual_min.get_value_string();
+        let line_weight = self.line_weight.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.manual_max.has_value() {
+            attributes.push(("manualMax", &manual_max));
+        }
+        if self.manual_min.has_value() {
+            attributes.push(("manualMin", &manual_min));
+        }
+        if self.line_weight.has_value() {
+            attributes.push(("lineWeight", &line_weight));
+        }
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.get_value_string()));
+        }
+        if self.date_axis.has_value() {
+            attributes.push(("dateAxis", self.date_axis.get_value_string()));
+        }
+        if self.display_empty_cells_as.has_value() {
+            attributes.push((
+                "displayEmptyCellsAs",
+                self.display_empty_cells_as.get_value_string(),
+            ));
+        }
+        if self.markers.has_value() {
+            attributes.push(("markers", self.markers.get_value_string()));
+        }
+        if self.high.has_value() {
+            attributes.push(("high", self.high.get_value_string()));
+        }
+        if self.low.has_value() {
+            attributes.push(("low", self.low.get_value_string()));
+        }
+        if self.first.has_value() {
+            attributes.push(("first", self.first.get_value_string()));
+        }
+        if self.last.has_value() {
+            attributes.push(("last", self.last.get_value_string()));
+        }
+        if self.negative.has_value() {
+            attributes.push(("negative", self.negative.get_value_string()));
+        }
+        if self.display_x_axis.has_value() {
+            attributes.push(("displayXAxis", self.display_x_axis.get_value_string()));
+        }
+        if self.display_hidden.has_value() {
+            attributes.push(("displayHidden", self.display_hidden.get_value_string()));
+        }
+        if self.min_axis_type.has_value() {
+            attributes.push(("minAxisType", self.min_axis_type.get_value_string()));
+        }
+        if self.max_axis_type.has_value() {
+            attributes.push(("maxAxisType", self.max_axis_type.get_value_string()));
+        }
+        if self.right_to_left.has_value() {
+            attributes.push(("rightToLeft", self.right_to_left.get_value_string()));
+        }
+        write_start_tag(writer, "x14:sparklineGroup", attributes, false);
+
+        // x14:colorSeries
+        self.color_series.write_to(writer, "x14:colorSeries");
+
+        // x14:colorNegative
+        self.color_negative.write_to(writer, "x14:colorNegative");
+
+        // x14:colorAxis
+        self.color_axis.write_to(writer, "x14:colorAxis");
+
+        // x14:colorMarkers
+        self.color_markers.write_to(writer, "x14:colorMarkers");
+
+        // x14:colorFirst
+        self.color_first.write_to(writer, "x14:colorFirst");
+
+        // x14:colorLast
+        self.color_last.write_to(writer, "x14:colorLast");
+
+        // x14:colorHigh
+        self.color_high.write_to(writer, "x14:colorHigh");
+
+        // x14:colorLow
+        self.color_low.write_to(writer, "x14:colorLow");
+
+        // xm:f
+        if self.date_axis_formula.has_value() {
+            write_start_tag(writer, "xm:f", vec![], false);
+            write_text_node(writer, self.date_axis_formula.get_value());
+            write_end_tag(writer, "xm:f");
+        }
+
+        // x14:sparklines
+        write_start_tag(writer, "x14:sparklines", vec![], false);
+        for obj in &self.sparkline_list {
+            obj.write_to(writer);
+        }
+        write_end_tag(writer, "x14:sparklines");
+
+        write_end_tag(writer, "x14:sparklineGroup");
+    }
+}
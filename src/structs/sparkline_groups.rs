@@ -0,0 +1,75 @@
+// x14:sparklineGroups
+use super::SparklineGroup;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct SparklineGroups {
+    sparkline_group_list: Vec<SparklineGroup>,
+}
+
+impl SparklineGroups {
+    pub fn get_sparkline_group_list(&self) -> &Vec<SparklineGroup> {
+        &self.sparkline_group_list
+    }
+
+    pub fn get_sparkline_group_list_mut(&mut self) -> &mut Vec<SparklineGroup> {
+        &mut self.sparkline_group_list
+    }
+
+    pub fn set_sparkline_group_list(&mut self, value: Vec<SparklineGroup>) -> &mut Self {
+        self.sparkline_group_list = value;
+        self
+    }
+
+    pub fn add_sparkline_group_list(&mut self, value: SparklineGroup) -> &mut Self {
+        self.sparkline_group_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroup" {
+                    let mut obj = SparklineGroup::default();
+                    obj.set_attributes(reader, e);
+                    self.add_sparkline_group_list(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroups" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "x14:sparklineGroups")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparklineGroups
+        write_start_tag(
+            writer,
+            "x14:sparklineGroups",
+            vec![(
+                "xmlns:xm",
+                "http://schemas.microsoft.com/office/excel/2006/main",
+            )],
+            false,
+        );
+
+        for obj in &self.sparkline_group_list {
+            obj.write_to(writer);
+        }
+
+        write_end_tag(writer, "x14:sparklineGroups");
+    }
+}
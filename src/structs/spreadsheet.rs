@@ -1,14 +1,30 @@
 use helper::address::*;
+use helper::conditional_formatting::{get_effective_style, RangeCache};
 use helper::coordinate::*;
+use helper::crypt::make_guid;
+use helper::data_validation::validate_cells;
+use helper::formula::remove_external_link_index;
 use reader::xlsx::*;
 use std::sync::Arc;
 use std::sync::RwLock;
 use structs::drawing::Theme;
 use structs::Address;
+use structs::AppProperties;
+use structs::BuiltinCellStyleValues;
+use structs::CalculationProperties;
 use structs::CellValue;
 use structs::Cells;
+use structs::Chart;
+use structs::Chartsheet;
+use structs::DataValidationViolation;
+use structs::DefinedName;
+use structs::EffectiveStyle;
+use structs::ExternalLink;
+use structs::NamedCellStyle;
+use structs::Person;
 use structs::Properties;
 use structs::SharedStringTable;
+use structs::Style;
 use structs::Stylesheet;
 use structs::WorkbookProtection;
 use structs::WorkbookView;
@@ -19,7 +35,9 @@ use structs::Worksheet;
 #[derive(Clone, Default, Debug)]
 pub struct Spreadsheet {
     properties: Properties,
+    app_properties: AppProperties,
     work_sheet_collection: Vec<Worksheet>,
+    chart_sheet_collection: Vec<Chartsheet>,
     macros_code: Option<Vec<u8>>,
     ribbon_xml_data: Option<String>,
     theme: Theme,
@@ -29,6 +47,11 @@ pub struct Spreadsheet {
     backup_context_types: Vec<(String, String)>,
     pivot_caches: Vec<(String, String, String)>,
     workbook_protection: Option<WorkbookProtection>,
+    person_collection: Vec<Person>,
+    external_links: Vec<ExternalLink>,
+    defined_names: Vec<DefinedName>,
+    date1904: bool,
+    calculation_properties: CalculationProperties,
 }
 
 impl Spreadsheet {
@@ -244,6 +267,79 @@ impl Spreadsheet {
         self
     }
 
+    /// Get the extended properties. (docProps/app.xml)
+    pub fn get_app_properties(&self) -> &AppProperties {
+        &self.app_properties
+    }
+
+    pub fn get_app_properties_mut(&mut self) -> &mut AppProperties {
+        &mut self.app_properties
+    }
+
+    pub fn set_app_properties(&mut self, value: AppProperties) -> &mut Self {
+        self.app_properties = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the parts of the document by the heading for `HeadingPairs` and `TitlesOfParts`.
+    /// The visible defined names are listed as the named ranges.
+    pub(crate) fn get_titles_of_parts(&self) -> Vec<(&str, Vec<String>)> {
+        let get_name = |defined_name: &DefinedName, sheet_name: Option<&str>| -> Option<String> {
+            if *defined_name.get_hidden() {
+                return None;
+            }
+            let name = defined_name.get_name();
+            let name = match name.strip_prefix("_xlnm.") {
+                Some(v) if v == "Print_Area" || v == "Print_Titles" => v,
+                Some(_) => return None,
+                None => name,
+            };
+            Some(match sheet_name {
+                Some(v) if v.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                    format!("{}!{}", v, name)
+                }
+                Some(v) => format!("'{}'!{}", v.replace('\'', "''"), name),
+                None => name.to_string(),
+            })
+        };
+
+        let sheets = self.get_sheet_collection_no_check();
+        let mut named_ranges: Vec<String> = Vec::new();
+        for defined_name in &self.defined_names {
+            let sheet_name = defined_name
+                .get_local_sheet_id()
+                .and_then(|v| sheets.get(v as usize))
+                .map(|v| v.get_name());
+            named_ranges.extend(get_name(defined_name, sheet_name));
+        }
+        for sheet in sheets {
+            for defined_name in sheet.get_defined_names() {
+                let sheet_name = defined_name.get_local_sheet_id().map(|_| sheet.get_name());
+                named_ranges.extend(get_name(defined_name, sheet_name));
+            }
+        }
+
+        let mut result: Vec<(&str, Vec<String>)> = Vec::new();
+        result.push((
+            "Worksheets",
+            sheets.iter().map(|v| v.get_name().to_string()).collect(),
+        ));
+        if !self.chart_sheet_collection.is_empty() {
+            result.push((
+                "Charts",
+                self.chart_sheet_collection
+                    .iter()
+                    .map(|v| v.get_name().to_string())
+                    .collect(),
+            ));
+        }
+        if !named_ranges.is_empty() {
+            result.push(("Named Ranges", named_ranges));
+        }
+        result
+    }
+
     /// Get Macros Code.
     /// # Return value
     /// * `&Option<Vec<u8>>` - Macros Code Raw Data.
@@ -285,6 +381,80 @@ impl Spreadsheet {
         self
     }
 
+    /// Get the named cell styles of the workbook.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// for named_cell_style in book.get_named_cell_styles() {
+    ///     println!("{}", named_cell_style.get_name());
+    /// }
+    /// ```
+    pub fn get_named_cell_styles(&self) -> Vec<NamedCellStyle> {
+        self.stylesheet.get_named_cell_styles()
+    }
+
+    /// Get the named cell style by the name.
+    /// # Arguments
+    /// * `name` - The name of the style. ex) "Good"
+    pub fn get_named_cell_style(&self, name: &str) -> Option<NamedCellStyle> {
+        self.stylesheet
+            .get_named_cell_styles()
+            .into_iter()
+            .find(|v| v.get_name() == name)
+    }
+
+    /// Add a custom named cell style.
+    /// The formatting of a style with the same name is replaced.
+    /// # Arguments
+    /// * `name` - The name of the style.
+    /// * `style` - The formatting of the style. The formatting which is None is not included in the style.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut style = umya_spreadsheet::Style::default();
+    /// style.set_background_color("FFFFCC99");
+    /// style.get_number_format_mut().set_format_code("#,##0.00");
+    /// let named_cell_style = book.add_named_cell_style("Currency Input", &style);
+    /// book.get_sheet_by_name_mut("Sheet1")
+    ///     .unwrap()
+    ///     .set_named_cell_style_by_range("A1:B2", &named_cell_style);
+    /// ```
+    pub fn add_named_cell_style<S: Into<String>>(
+        &mut self,
+        name: S,
+        style: &Style,
+    ) -> NamedCellStyle {
+        self.stylesheet
+            .add_named_cell_style(&name.into(), None, style)
+    }
+
+    /// Add a built-in cell style of Excel.
+    /// The style in the workbook is returned if the workbook already has it.
+    /// # Arguments
+    /// * `value` - The built-in style.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let good = book.add_builtin_cell_style(umya_spreadsheet::BuiltinCellStyleValues::Good);
+    /// book.get_sheet_by_name_mut("Sheet1")
+    ///     .unwrap()
+    ///     .get_style_mut("A1")
+    ///     .set_named_cell_style(&good);
+    /// ```
+    pub fn add_builtin_cell_style(&mut self, value: BuiltinCellStyleValues) -> NamedCellStyle {
+        let builtin_id = value.get_builtin_id();
+        if let Some(v) = self
+            .stylesheet
+            .get_named_cell_styles()
+            .into_iter()
+            .find(|v| v.get_builtin_id() == &Some(builtin_id))
+        {
+            return v;
+        }
+        self.stylesheet
+            .add_named_cell_style(value.get_name(), Some(builtin_id), &value.get_style())
+    }
+
     /// (This method is crate only.)
     /// Set Default Value Stylesheet.
     pub(crate) fn set_stylesheet_defalut_value(&mut self) -> &mut Self {
@@ -402,6 +572,49 @@ impl Spreadsheet {
         }
     }
 
+    /// Check the cell values of the sheet against its data validations.
+    /// Same as `Worksheet::validate_cells`, with the references to the other sheets resolved.
+    /// # Arguments
+    /// * `index` - sheet index
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// for violation in book.validate_cells(&0) {
+    ///     println!("{}: {}", violation.get_coordinate().get_coordinate(), violation.get_value());
+    /// }
+    /// ```
+    pub fn validate_cells(&self, index: &usize) -> Vec<DataValidationViolation> {
+        match self.get_sheet(index) {
+            Ok(v) => validate_cells(v, Some(self)),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Get the look of a cell of the sheet after the conditional formatting is applied.
+    /// Same as `Worksheet::get_effective_style`, with the references to the other sheets
+    /// and the defined names of the workbook resolved.
+    /// # Arguments
+    /// * `index` - sheet index
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    pub fn get_effective_style<T>(
+        &self,
+        index: &usize,
+        coordinate: T,
+    ) -> Result<EffectiveStyle, &'static str>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let worksheet = self.get_sheet(index)?;
+        Ok(get_effective_style(
+            worksheet,
+            Some(self),
+            col,
+            row,
+            &mut RangeCache::default(),
+        ))
+    }
+
     pub fn get_lazy_read_sheet_cells(&self, index: &usize) -> Result<Cells, &'static str> {
         let shared_string_table = self.get_shared_string_table();
         match self.work_sheet_collection.get(*index) {
@@ -448,25 +661,74 @@ impl Spreadsheet {
         }
     }
 
+    /// Set Active Work Sheet.
+    /// # Arguments
+    /// * `index` - sheet index
     pub fn set_active_sheet(&mut self, index: u32) -> &mut Self {
-        self.get_workbook_view_mut().set_active_tab(index);
+        let tab = SheetTab::Worksheet(index as usize);
+        let position = self
+            .get_sheet_tab_order()
+            .iter()
+            .position(|v| v == &tab)
+            .map_or(index, |v| v as u32);
+        self.get_workbook_view_mut().set_active_tab(position);
         self
     }
 
     /// Get Active Work Sheet.
+    /// When a chart sheet is active, the nearest worksheet is returned.
+    /// # Panics
+    /// Panics if the workbook has no worksheet.
     /// # Return value
     /// * `&Worksheet` - Work sheet.
     pub fn get_active_sheet(&self) -> &Worksheet {
-        let index = *self.get_workbook_view().get_active_tab();
-        self.get_sheet(&(index as usize)).unwrap()
+        let index = self.get_active_sheet_index();
+        self.get_sheet(&index).unwrap()
     }
 
     /// Get Active Work Sheet in mutable.
+    /// When a chart sheet is active, the nearest worksheet is returned.
+    /// # Panics
+    /// Panics if the workbook has no worksheet.
     /// # Return value
     /// * `&mut Worksheet` - Work sheet.
     pub fn get_active_sheet_mut(&mut self) -> &mut Worksheet {
-        let index = *self.get_workbook_view().get_active_tab();
-        self.get_sheet_mut(&(index as usize)).unwrap()
+        let index = self.get_active_sheet_index();
+        self.get_sheet_mut(&index).unwrap()
+    }
+
+    /// (This method is crate only.)
+    /// Get the index of the active worksheet, or of the worksheet nearest to the active tab.
+    fn get_active_sheet_index(&self) -> usize {
+        let sheet_tab_order = self.get_sheet_tab_order();
+        let position = *self.workbook_view.get_active_tab() as usize;
+        let position = position.min(sheet_tab_order.len().saturating_sub(1));
+        let before = sheet_tab_order[..position].iter().rev();
+        let after = sheet_tab_order[position..].iter();
+        let nearest = |(distance, v): (usize, &SheetTab)| match v {
+            SheetTab::Worksheet(i) => Some((distance, *i)),
+            SheetTab::Chartsheet(_) => None,
+        };
+        let before = before
+            .enumerate()
+            .map(|(i, v)| (i + 1, v))
+            .find_map(nearest);
+        let after = after.enumerate().find_map(nearest);
+        match (before, after) {
+            (Some(b), Some(a)) if b.0 <= a.0 => b.1,
+            (_, Some(a)) => a.1,
+            (Some(b), None) => b.1,
+            (None, None) => 0,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Keep the active tab on the same sheet when the sheet at the tab position is removed.
+    fn remove_active_tab_position(&mut self, position: usize) {
+        let active_tab = *self.workbook_view.get_active_tab() as usize;
+        if active_tab > position {
+            self.workbook_view.set_active_tab((active_tab - 1) as u32);
+        }
     }
 
     /// Add Work Sheet.
@@ -474,12 +736,13 @@ impl Spreadsheet {
     /// * `value` - Work Sheet
     /// # Return value
     /// * `Result<&mut Worksheet, &'static str>` - OK:added work sheet. Err:Error.
-    pub fn add_sheet(&mut self, value: Worksheet) -> Result<&mut Worksheet, &'static str> {
+    pub fn add_sheet(&mut self, mut value: Worksheet) -> Result<&mut Worksheet, &'static str> {
         let title = value.get_name();
         match Spreadsheet::check_sheet_name(self, title) {
             Ok(_) => {}
             Err(e) => return Err(e),
         }
+        value.set_date1904(self.date1904);
         self.work_sheet_collection.push(value);
         Ok(self.work_sheet_collection.last_mut().unwrap())
     }
@@ -493,7 +756,12 @@ impl Spreadsheet {
         if self.work_sheet_collection.len() <= index {
             return Err("out of index.");
         }
+        let tab = SheetTab::Worksheet(index);
+        if let Some(position) = self.get_sheet_tab_order().iter().position(|v| v == &tab) {
+            self.remove_active_tab_position(position);
+        }
         self.work_sheet_collection.remove(index);
+        self.shift_chart_sheet_positions(index);
         Ok(())
     }
 
@@ -503,14 +771,26 @@ impl Spreadsheet {
     /// # Return value
     /// * `Result<(), &'static str>` - OK:removed worksheet. Err:Error.
     pub fn remove_sheet_by_name(&mut self, sheet_name: &str) -> Result<(), &'static str> {
-        let cnt_before = self.work_sheet_collection.len();
-        self.work_sheet_collection
-            .retain(|x| x.get_name() != sheet_name);
-        let cnt_after = self.work_sheet_collection.len();
-        if cnt_before == cnt_after {
-            return Err("out of index.");
+        match self
+            .work_sheet_collection
+            .iter()
+            .position(|x| x.get_name() == sheet_name)
+        {
+            Some(index) => self.remove_sheet(index),
+            None => Err("out of index."),
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Keep the chart sheets after a removed worksheet at their tab position.
+    fn shift_chart_sheet_positions(&mut self, index: usize) {
+        for chart_sheet in &mut self.chart_sheet_collection {
+            if let Some(position) = *chart_sheet.get_position() {
+                if position > index {
+                    chart_sheet.set_position(Some(position - 1));
+                }
+            }
         }
-        Ok(())
     }
 
     /// Add New Work Sheet.
@@ -549,6 +829,7 @@ impl Spreadsheet {
         worksheet
             .get_sheet_format_properties_mut()
             .set_defalut_value();
+        worksheet.set_date1904(self.date1904);
         self.work_sheet_collection.push(worksheet);
         self.work_sheet_collection.last_mut().unwrap()
     }
@@ -586,9 +867,152 @@ impl Spreadsheet {
                 return Err("name duplicate.");
             }
         }
+        for chart_sheet in &self.chart_sheet_collection {
+            if value == chart_sheet.get_name() {
+                return Err("name duplicate.");
+            }
+        }
         Ok(())
     }
 
+    /// Get Chart Sheet List.
+    /// Chart sheets keep their tab position among the worksheets.
+    pub fn get_chart_sheet_collection(&self) -> &Vec<Chartsheet> {
+        &self.chart_sheet_collection
+    }
+
+    /// Get Chart Sheet List in mutable.
+    pub fn get_chart_sheet_collection_mut(&mut self) -> &mut Vec<Chartsheet> {
+        &mut self.chart_sheet_collection
+    }
+
+    /// Get Chart Sheet.
+    /// # Arguments
+    /// * `sheet_name` - sheet name
+    /// # Return value
+    /// * `Result<&Chartsheet, &'static str>` - OK:chart sheet. Err:Error.
+    pub fn get_chart_sheet_by_name(&self, sheet_name: &str) -> Result<&Chartsheet, &'static str> {
+        self.chart_sheet_collection
+            .iter()
+            .find(|v| v.get_name() == sheet_name)
+            .ok_or("Not found.")
+    }
+
+    /// Get Chart Sheet in mutable.
+    /// # Arguments
+    /// * `sheet_name` - sheet name
+    /// # Return value
+    /// * `Result<&mut Chartsheet, &'static str>` - OK:chart sheet. Err:Error.
+    pub fn get_chart_sheet_by_name_mut(
+        &mut self,
+        sheet_name: &str,
+    ) -> Result<&mut Chartsheet, &'static str> {
+        self.chart_sheet_collection
+            .iter_mut()
+            .find(|v| v.get_name() == sheet_name)
+            .ok_or("Not found.")
+    }
+
+    /// Add Chart Sheet.
+    /// # Arguments
+    /// * `value` - Chart Sheet
+    /// # Return value
+    /// * `Result<&mut Chartsheet, &'static str>` - OK:added chart sheet. Err:Error.
+    pub fn add_chart_sheet(
+        &mut self,
+        mut value: Chartsheet,
+    ) -> Result<&mut Chartsheet, &'static str> {
+        Spreadsheet::check_sheet_name(self, value.get_name())?;
+        value.set_position(Some(self.work_sheet_collection.len()));
+        self.chart_sheet_collection.push(value);
+        Ok(self.chart_sheet_collection.last_mut().unwrap())
+    }
+
+    /// Add New Chart Sheet from an existing Chart.
+    /// # Arguments
+    /// * `sheet_title` - sheet title
+    /// * `chart` - Chart to show on the sheet.
+    /// # Return value
+    /// * `Result<&mut Chartsheet, &'static str>` - OK:added chart sheet. Err:Error.
+    pub fn new_chart_sheet<S: Into<String>>(
+        &mut self,
+        sheet_title: S,
+        chart: &Chart,
+    ) -> Result<&mut Chartsheet, &'static str> {
+        let mut chart_sheet = Chartsheet::default();
+        chart_sheet.set_name(sheet_title);
+        chart_sheet.set_chart(chart);
+        self.add_chart_sheet(chart_sheet)
+    }
+
+    /// Remove Chart Sheet.
+    /// # Arguments
+    /// * `sheet_name` - sheet name
+    /// # Return value
+    /// * `Result<(), &'static str>` - OK:removed chart sheet. Err:Error.
+    pub fn remove_chart_sheet_by_name(&mut self, sheet_name: &str) -> Result<(), &'static str> {
+        let index = match self
+            .chart_sheet_collection
+            .iter()
+            .position(|x| x.get_name() == sheet_name)
+        {
+            Some(v) => v,
+            None => return Err("out of index."),
+        };
+        let tab = SheetTab::Chartsheet(index);
+        if let Some(position) = self.get_sheet_tab_order().iter().position(|v| v == &tab) {
+            self.remove_active_tab_position(position);
+        }
+        self.chart_sheet_collection.remove(index);
+        Ok(())
+    }
+
+    /// (This method is crate only.)
+    /// Move the sheets read as worksheets with the given r:id to the chart sheet list.
+    /// Each chart sheet keeps its tab position.
+    pub(crate) fn convert_to_chart_sheets(&mut self, r_ids: &[String]) -> &mut Self {
+        let mut work_sheets: Vec<Worksheet> = Vec::new();
+        for worksheet in self.work_sheet_collection.drain(..) {
+            if !r_ids.contains(worksheet.get_r_id()) {
+                work_sheets.push(worksheet);
+                continue;
+            }
+            let mut chart_sheet = Chartsheet::default();
+            chart_sheet.set_name(worksheet.get_name());
+            chart_sheet.set_sheet_id(worksheet.get_sheet_id());
+            chart_sheet.set_r_id(worksheet.get_r_id());
+            chart_sheet.set_position(Some(work_sheets.len()));
+            self.chart_sheet_collection.push(chart_sheet);
+        }
+        self.work_sheet_collection = work_sheets;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the worksheets and chart sheets in tab order.
+    pub(crate) fn get_sheet_tab_order(&self) -> Vec<SheetTab> {
+        let count = self.work_sheet_collection.len();
+        let mut chart_sheets: Vec<(usize, usize)> = self
+            .chart_sheet_collection
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.get_position().map_or(count, |p| p.min(count)), i))
+            .collect();
+        chart_sheets.sort();
+
+        let mut result: Vec<SheetTab> = Vec::new();
+        let mut chart_sheets = chart_sheets.into_iter().peekable();
+        for index in 0..=count {
+            while let Some((_, i)) = chart_sheets.next_if(|(p, _)| p == &index) {
+                result.push(SheetTab::Chartsheet(i));
+            }
+            if index < count {
+                result.push(SheetTab::Worksheet(index));
+            }
+        }
+        result
+    }
+
     /// (This method is crate only.)
     /// Has Ribbon XML Data.
     pub(crate) fn has_ribbon(&self) -> bool {
@@ -613,9 +1037,123 @@ impl Spreadsheet {
         self
     }
 
+    /// Whether the workbook uses the 1904 date system. (workbookPr date1904)
+    pub fn get_date1904(&self) -> &bool {
+        &self.date1904
+    }
+
+    /// Set the date system of the workbook.
+    /// The serial dates of the cells are formatted with it.
+    /// # Arguments
+    /// * `value` - true for the 1904 date system, false for the 1900 date system.
+    pub fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.set_date1904(value);
+        }
+        self
+    }
+
+    /// Get the calculation properties. (calcPr)
+    pub fn get_calculation_properties(&self) -> &CalculationProperties {
+        &self.calculation_properties
+    }
+
+    pub fn get_calculation_properties_mut(&mut self) -> &mut CalculationProperties {
+        &mut self.calculation_properties
+    }
+
+    pub fn set_calculation_properties(&mut self, value: CalculationProperties) -> &mut Self {
+        self.calculation_properties = value;
+        self
+    }
+
+    /// Get the defined names of the workbook which do not belong to a worksheet.
+    /// ex) constants, formulas and names of multiple areas.
+    pub fn get_defined_names(&self) -> &Vec<DefinedName> {
+        &self.defined_names
+    }
+
+    pub fn get_defined_names_mut(&mut self) -> &mut Vec<DefinedName> {
+        &mut self.defined_names
+    }
+
+    pub fn set_defined_names(&mut self, value: Vec<DefinedName>) -> &mut Self {
+        self.defined_names = value;
+        self
+    }
+
+    /// Add Defined Name.
+    /// A name scoped to a sheet (local sheet id) is added to that worksheet.
+    /// # Arguments
+    /// * `value` - DefinedName.
+    pub fn add_defined_names(&mut self, value: DefinedName) -> &mut Self {
+        let index = value.get_local_sheet_id().map(|v| v as usize);
+        match index.and_then(|v| self.work_sheet_collection.get_mut(v)) {
+            Some(sheet) => sheet.add_defined_names(value),
+            None => self.defined_names.push(value),
+        }
+        self
+    }
+
+    /// Add a workbook scoped Defined Name.
+    /// # Arguments
+    /// * `name` - Name. ex) "TaxRate"
+    /// * `formula` - Address, constant or formula. ex) "Sheet1!$A$1:$A$10", "0.2", "OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let _ = book.add_defined_name("TaxRate", "0.2");
+    /// ```
+    pub fn add_defined_name<S: Into<String>>(&mut self, name: S, formula: S) -> Result<(), &str> {
+        let name = name.into();
+        if name.is_empty() || name.contains(' ') || is_address(&name) {
+            return Err("invalid name.");
+        }
+        if self.find_defined_name(&name, None).is_some() {
+            return Err("name already exists.");
+        }
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(name);
+        defined_name.set_address(formula.into());
+        self.defined_names.push(defined_name);
+        Ok(())
+    }
+
+    /// Find a Defined Name as a formula of the sheet does.
+    /// A name scoped to the sheet is preferred to a workbook scoped name. (case insensitive)
+    /// # Arguments
+    /// * `name` - Name. ex) "TaxRate"
+    /// * `sheet_index` - Index of the sheet using the name. None for the workbook scope only.
+    pub fn find_defined_name(
+        &self,
+        name: &str,
+        sheet_index: Option<usize>,
+    ) -> Option<&DefinedName> {
+        let local = sheet_index
+            .and_then(|v| self.work_sheet_collection.get(v))
+            .and_then(|sheet| {
+                sheet
+                    .get_defined_names()
+                    .iter()
+                    .find(|v| v.get_local_sheet_id().is_some() && v.is_name(name))
+            });
+        if local.is_some() {
+            return local;
+        }
+        self.work_sheet_collection
+            .iter()
+            .flat_map(|sheet| sheet.get_defined_names())
+            .chain(self.defined_names.iter())
+            .find(|v| v.get_local_sheet_id().is_none() && v.is_name(name))
+    }
+
     /// (This method is crate only.)
     /// Has Defined Names.
     pub(crate) fn has_defined_names(&self) -> bool {
+        if !self.defined_names.is_empty() {
+            return true;
+        }
         for sheet in self.get_sheet_collection_no_check() {
             if sheet.has_defined_names() {
                 return true;
@@ -624,6 +1162,17 @@ impl Spreadsheet {
         false
     }
 
+    pub(crate) fn has_dynamic_array_formula(&self) -> bool {
+        self.get_sheet_collection_no_check()
+            .iter()
+            .filter(|v| v.is_deserialized())
+            .any(|v| {
+                v.get_cell_collection()
+                    .iter()
+                    .any(|c| c.is_dynamic_array_formula())
+            })
+    }
+
     pub(crate) fn get_backup_context_types(&self) -> &Vec<(String, String)> {
         &self.backup_context_types
     }
@@ -674,6 +1223,131 @@ impl Spreadsheet {
         self
     }
 
+    /// Get the authors of the threaded comments.
+    pub fn get_person_collection(&self) -> &Vec<Person> {
+        &self.person_collection
+    }
+
+    pub fn get_person_collection_mut(&mut self) -> &mut Vec<Person> {
+        &mut self.person_collection
+    }
+
+    /// Add an author of threaded comments.
+    /// # Arguments
+    /// * `value` - Person. The id is made if it is empty.
+    pub fn add_person(&mut self, mut value: Person) -> &mut Self {
+        if value.get_id().is_empty() {
+            value.set_id(make_guid());
+        }
+        self.person_collection.push(value);
+        self
+    }
+
+    /// Get the links to the external workbooks.
+    /// The formulas refer to the link of the index `i` as `[i + 1]`. ex) `[1]Sheet1!A1`
+    pub fn get_external_links(&self) -> &Vec<ExternalLink> {
+        &self.external_links
+    }
+
+    pub fn get_external_links_mut(&mut self) -> &mut Vec<ExternalLink> {
+        &mut self.external_links
+    }
+
+    /// Add a link to an external workbook.
+    /// The formulas refer to it as `[n]` where `n` is the number of the links.
+    pub fn add_external_link(&mut self, value: ExternalLink) -> &mut Self {
+        self.external_links.push(value);
+        self
+    }
+
+    /// Break the link to the external workbook.
+    /// The formulas referring to the link are replaced by their cached values,
+    /// the defined names referring to it are removed and the other references are renumbered.
+    /// Only the cell formulas and the defined names are updated. The references in
+    /// conditional formatting, data validation and chart formulas are not renumbered.
+    /// # Arguments
+    /// * `index` - Index of the link in `get_external_links`.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut link = umya_spreadsheet::ExternalLink::default();
+    /// link.set_target("Budget.xlsx");
+    /// book.add_external_link(link);
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet
+    ///     .get_cell_mut("A1")
+    ///     .set_value("10")
+    ///     .set_formula("[1]Sheet1!A1*2");
+    /// book.break_external_link(&0);
+    /// let cell = book.get_sheet(&0).unwrap().get_cell("A1").unwrap();
+    /// assert!(!cell.is_formula());
+    /// assert_eq!(cell.get_value(), "10");
+    /// ```
+    pub fn break_external_link(&mut self, index: &usize) -> &mut Self {
+        if index >= &self.external_links.len() {
+            return self;
+        }
+        self.external_links.remove(*index);
+        let number = (*index + 1) as u32;
+
+        let update_defined_names = |defined_names: &mut Vec<DefinedName>| {
+            defined_names.retain_mut(|v| {
+                let address = v.get_address_str();
+                match remove_external_link_index(&address, &number) {
+                    Some(new_address) => {
+                        if new_address != address {
+                            v.set_address(new_address);
+                        }
+                        true
+                    }
+                    None => false,
+                }
+            });
+        };
+        update_defined_names(&mut self.defined_names);
+
+        self.read_sheet_collection();
+        for worksheet in &mut self.work_sheet_collection {
+            update_defined_names(worksheet.get_defined_names_mut());
+            // the dependents of a removed shared formula are removed with it.
+            let mut removed_shared_indexes: Vec<u32> = Vec::new();
+            for cell in worksheet.get_cell_collection_mut() {
+                let cell_value = cell.get_cell_value_mut();
+                let (text, shared_index) = match cell_value.get_cell_formula() {
+                    Some(v) => (v.get_text().to_string(), v.get_shared_index()),
+                    None => continue,
+                };
+                match remove_external_link_index(&text, &number) {
+                    Some(v) => {
+                        if v != text {
+                            cell_value.get_cell_formula_mut().unwrap().set_text(v);
+                        }
+                    }
+                    None => {
+                        if let Some(si) = shared_index {
+                            removed_shared_indexes.push(si);
+                        }
+                        cell_value.remove_formula();
+                    }
+                }
+            }
+            if removed_shared_indexes.is_empty() {
+                continue;
+            }
+            for cell in worksheet.get_cell_collection_mut() {
+                let cell_value = cell.get_cell_value_mut();
+                let is_removed = cell_value
+                    .get_cell_formula()
+                    .and_then(|v| v.get_shared_index())
+                    .map_or(false, |v| removed_shared_indexes.contains(&v));
+                if is_removed {
+                    cell_value.remove_formula();
+                }
+            }
+        }
+        self
+    }
+
     pub fn get_workbook_protection(&self) -> &Option<WorkbookProtection> {
         &self.workbook_protection
     }
@@ -693,3 +1367,11 @@ impl Spreadsheet {
         self
     }
 }
+
+/// (This enum is crate only.)
+/// A sheet in tab order, by index in the worksheet or chart sheet list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SheetTab {
+    Worksheet(usize),
+    Chartsheet(usize),
+}
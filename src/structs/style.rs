@@ -4,6 +4,7 @@ use structs::Borders;
 use structs::Color;
 use structs::Fill;
 use structs::Font;
+use structs::NamedCellStyle;
 use structs::NumberingFormat;
 use structs::PatternValues;
 use structs::Protection;
@@ -242,6 +243,33 @@ impl Style {
         self
     }
 
+    /// Apply the named cell style.
+    /// The formatting which the named cell style does not include is kept.
+    /// # Arguments
+    /// * `value` - The named cell style. (Spreadsheet::get_named_cell_style)
+    pub fn set_named_cell_style(&mut self, value: &NamedCellStyle) -> &mut Self {
+        let style = value.get_style();
+        if let Some(v) = style.get_font() {
+            self.font = Some(v.clone());
+        }
+        if let Some(v) = style.get_fill() {
+            self.fill = Some(v.clone());
+        }
+        if let Some(v) = style.get_borders() {
+            self.borders = Some(v.clone());
+        }
+        if let Some(v) = style.get_alignment() {
+            self.alignment = Some(v.clone());
+        }
+        if let Some(v) = style.get_numbering_format() {
+            self.numbering_format = Some(v.clone());
+        }
+        if let Some(v) = style.get_protection() {
+            self.protection = Some(v.clone());
+        }
+        self.set_format_id(*value.get_format_id())
+    }
+
     pub fn get_protection(&self) -> &Option<Protection> {
         &self.protection
     }
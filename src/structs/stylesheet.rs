@@ -1,13 +1,20 @@
 // styleSheet
+use super::Borders;
 use super::BordersCrate;
+use super::BuiltinCellStyleValues;
 use super::CellFormat;
 use super::CellFormats;
+use super::CellStyle;
 use super::CellStyleFormats;
 use super::CellStyles;
 use super::Colors;
 use super::DifferentialFormats;
+use super::Fill;
 use super::Fills;
+use super::Font;
 use super::Fonts;
+use super::NamedCellStyle;
+use super::NumberingFormat;
 use super::NumberingFormats;
 use super::Protection;
 use super::Style;
@@ -16,6 +23,7 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::numbering_format::FILL_BUILT_IN_FORMAT_CODES;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
@@ -159,77 +167,90 @@ impl Stylesheet {
             let def_cell_format = self
                 .cell_style_formats
                 .get_cell_format()
-                .get(*cell_format.get_format_id() as usize)
-                .cloned()
-                .unwrap_or_default();
+                .get(*cell_format.get_format_id() as usize);
 
             let mut style = Style::default();
-            self.get_style_by_cell_format(&mut style, &def_cell_format, cell_format);
+            self.get_style_by_cell_format(&mut style, def_cell_format, cell_format);
             self.maked_style_list.push(style);
         }
 
         self
     }
 
+    /// (This method is crate only.)
+    /// Set the formatting of the cell format into the style.
+    /// The formatting which the cell format does not apply is inherited from the cell style format.
     pub(crate) fn get_style_by_cell_format(
         &self,
         style: &mut Style,
-        def_cell_format: &CellFormat,
+        def_cell_format: Option<&CellFormat>,
         cell_format: &CellFormat,
     ) {
+        let default = CellFormat::default();
+        let def = def_cell_format.unwrap_or(&default);
+        // Returns the cell format which the formatting is taken from.
+        let get_source = |def_apply: Option<bool>, apply: Option<bool>| match apply {
+            Some(true) => Some(cell_format),
+            Some(false) => def_cell_format,
+            None => match def_apply {
+                Some(false) => None,
+                _ => Some(cell_format),
+            },
+        };
+        let to_option = |has_value: bool, value: &bool| if has_value { Some(*value) } else { None };
+
         // number_format
-        let mut apply = true;
-        if def_cell_format.has_apply_number_format() {
-            apply = *def_cell_format.get_apply_number_format();
-        }
-        if cell_format.has_apply_number_format() {
-            apply = *cell_format.get_apply_number_format();
-        }
-        if apply {
-            let id = cell_format.get_number_format_id();
-            if let Some(obj) = self.numbering_formats.get_numbering_format().get(id) {
-                style.set_numbering_format(obj.clone());
+        if let Some(source) = get_source(
+            to_option(def.has_apply_number_format(), def.get_apply_number_format()),
+            to_option(
+                cell_format.has_apply_number_format(),
+                cell_format.get_apply_number_format(),
+            ),
+        ) {
+            let id = source.get_number_format_id();
+            match self.numbering_formats.get_numbering_format().get(id) {
+                Some(obj) => {
+                    style.set_numbering_format(obj.clone());
+                }
+                None => {
+                    if FILL_BUILT_IN_FORMAT_CODES.contains_key(id) {
+                        let mut obj = NumberingFormat::default();
+                        obj.set_number_format_id(*id);
+                        style.set_numbering_format(obj);
+                    }
+                }
             }
         }
 
         // font
-        let mut apply = true;
-        if def_cell_format.has_apply_font() {
-            apply = *def_cell_format.get_apply_font();
-        }
-        if cell_format.has_apply_font() {
-            apply = *cell_format.get_apply_font();
-        }
-        if apply {
-            let id = *cell_format.get_font_id() as usize;
+        if let Some(source) = get_source(
+            to_option(def.has_apply_font(), def.get_apply_font()),
+            to_option(cell_format.has_apply_font(), cell_format.get_apply_font()),
+        ) {
+            let id = *source.get_font_id() as usize;
             let obj = self.fonts.get_font().get(id).unwrap();
             style.set_font(obj.clone());
         }
 
         // fill
-        let mut apply = true;
-        if def_cell_format.has_apply_fill() {
-            apply = *def_cell_format.get_apply_fill();
-        }
-        if cell_format.has_apply_fill() {
-            apply = *cell_format.get_apply_fill();
-        }
-        if apply {
-            let id = *cell_format.get_fill_id() as usize;
+        if let Some(source) = get_source(
+            to_option(def.has_apply_fill(), def.get_apply_fill()),
+            to_option(cell_format.has_apply_fill(), cell_format.get_apply_fill()),
+        ) {
+            let id = *source.get_fill_id() as usize;
             let obj = self.fills.get_fill().get(id).unwrap();
             style.set_fill(obj.clone());
         }
 
         // borders
-        let mut apply = true;
-        if def_cell_format.has_apply_border() {
-            apply = *def_cell_format.get_apply_border();
-        }
-        if cell_format.has_apply_border() {
-            apply = *cell_format.get_apply_border();
-        }
-        if apply {
-            let id = *cell_format.get_border_id() as usize;
+        if let Some(source) = get_source(
+            to_option(def.has_apply_border(), def.get_apply_border()),
+            to_option(
+                cell_format.has_apply_border(),
+                cell_format.get_apply_border(),
+            ),
+        ) {
+            let id = *source.get_border_id() as usize;
             let obj = self.borders.get_borders().get(id).unwrap();
             style.set_borders(obj.clone());
         }
@@ -238,48 +259,34 @@ impl Stylesheet {
         style.set_format_id(*cell_format.get_format_id());
 
         // alignment
-        let mut apply = true;
-        if def_cell_format.has_apply_alignment() {
-            apply = *def_cell_format.get_apply_alignment();
-        }
-        if cell_format.has_apply_alignment() {
-            apply = *cell_format.get_apply_alignment();
-        }
-        if apply {
-            match def_cell_format.get_alignment() {
-                Some(v) => {
-                    style.set_alignment(v.clone());
-                }
-                None => {}
+        if let Some(source) = get_source(
+            to_option(def.has_apply_alignment(), def.get_apply_alignment()),
+            to_option(
+                cell_format.has_apply_alignment(),
+                cell_format.get_apply_alignment(),
+            ),
+        ) {
+            if let Some(v) = def.get_alignment() {
+                style.set_alignment(v.clone());
             }
-            match cell_format.get_alignment() {
-                Some(v) => {
-                    style.set_alignment(v.clone());
-                }
-                None => {}
+            if let Some(v) = source.get_alignment() {
+                style.set_alignment(v.clone());
             }
         }
 
         // protection
-        let mut apply = true;
-        if def_cell_format.has_apply_protection() {
-            apply = *def_cell_format.get_apply_protection();
-        }
-        if cell_format.has_apply_protection() {
-            apply = *cell_format.get_apply_protection();
-        }
-        if apply {
-            match def_cell_format.get_protection() {
-                Some(v) => {
-                    style.set_protection(v.clone());
-                }
-                None => {}
+        if let Some(source) = get_source(
+            to_option(def.has_apply_protection(), def.get_apply_protection()),
+            to_option(
+                cell_format.has_apply_protection(),
+                cell_format.get_apply_protection(),
+            ),
+        ) {
+            if let Some(v) = def.get_protection() {
+                style.set_protection(v.clone());
             }
-            match cell_format.get_protection() {
-                Some(v) => {
-                    style.set_protection(v.clone());
-                }
-                None => {}
+            if let Some(v) = source.get_protection() {
+                style.set_protection(v.clone());
             }
         }
     }
@@ -296,13 +303,22 @@ impl Stylesheet {
             }
             index += 1;
         }
+        self.add_cell_format(style);
+        index
+    }
+
+    // Add the cell format of the style to cellXfs.
+    fn add_cell_format(&mut self, style: &Style) {
         let mut cell_format = CellFormat::default();
 
         let number_format_id = self.numbering_formats.set_style(style);
         let font_id = self.fonts.set_style(style);
         let fill_id = self.fills.set_style(style);
         let border_id = self.borders.set_style(style);
-        let format_id = 0;
+        let format_id = match *style.get_format_id() {
+            v if (v as usize) < self.cell_style_formats.get_cell_format().len() => v,
+            _ => 0,
+        };
 
         cell_format.set_number_format_id(number_format_id);
         cell_format.set_font_id(font_id);
@@ -310,38 +326,40 @@ impl Stylesheet {
         cell_format.set_border_id(border_id);
         cell_format.set_format_id(format_id);
 
-        match style.get_numbering_format() {
-            Some(_) => {
-                cell_format.set_apply_number_format(true);
-            }
-            None => {}
+        // the formatting same as the cell style is inherited from it.
+        let named_style = match format_id {
+            0 => None,
+            _ => Some(self.get_style_by_format_id(format_id)),
+        };
+        let is_override = |is_same: fn(&Style, &Style) -> bool| match &named_style {
+            Some(v) => !is_same(v, style),
+            None => true,
+        };
+
+        if style.get_numbering_format().is_some() {
+            cell_format.set_apply_number_format(is_override(|a, b| {
+                a.get_numbering_format() == b.get_numbering_format()
+            }));
         }
 
-        match style.get_font() {
-            Some(_) => {
-                cell_format.set_apply_font(true);
-            }
-            None => {}
+        if style.get_font().is_some() {
+            cell_format.set_apply_font(is_override(|a, b| a.get_font() == b.get_font()));
         }
 
-        match style.get_fill() {
-            Some(_) => {
-                cell_format.set_apply_fill(true);
-            }
-            None => {}
+        if style.get_fill().is_some() {
+            cell_format.set_apply_fill(is_override(|a, b| a.get_fill() == b.get_fill()));
         }
 
-        match style.get_borders() {
-            Some(_) => {
-                cell_format.set_apply_border(true);
-            }
-            None => {}
+        if style.get_borders().is_some() {
+            cell_format.set_apply_border(is_override(|a, b| a.get_borders() == b.get_borders()));
         }
 
         match style.get_alignment() {
             Some(v) => {
                 cell_format.set_alignment(v.clone());
-                cell_format.set_apply_alignment(true);
+                cell_format.set_apply_alignment(is_override(|a, b| {
+                    a.get_alignment() == b.get_alignment()
+                }));
             }
             None => {}
         }
@@ -349,14 +367,204 @@ impl Stylesheet {
         match style.get_protection() {
             Some(v) => {
                 cell_format.set_protection(v.clone());
-                cell_format.set_apply_protection(true);
+                cell_format.set_apply_protection(is_override(|a, b| {
+                    a.get_protection() == b.get_protection()
+                }));
             }
             None => {}
         }
 
         self.maked_style_list.push(style.clone());
         self.cell_formats.set_cell_format(cell_format);
-        index
+    }
+
+    /// (This method is crate only.)
+    /// Get the stylesheet which has only the formatting used by the cells.
+    /// The fonts, fills, borders, number formats and cell formats are deduplicated.
+    /// The cell style formats are kept in the same order, so the xfId of the cells is not changed.
+    /// # Arguments
+    /// * `keep_differential_formats` - Keep dxfs as they are. (ex. pivot tables refer them.)
+    pub(crate) fn get_compacted(&self, keep_differential_formats: bool) -> Stylesheet {
+        let mut obj = Stylesheet::default();
+        obj.colors = self.colors.clone();
+        if keep_differential_formats {
+            obj.differential_formats = self.differential_formats.clone();
+        }
+
+        // the first entries are the defaults of the workbook.
+        match self.fonts.get_font().first() {
+            Some(v) => obj.fonts.set_font(v.clone()),
+            None => obj.fonts.set_font(Font::get_default_value()),
+        };
+        match self.fills.get_fill().first() {
+            Some(v) => obj.fills.set_fill(v.clone()),
+            None => obj.fills.set_fill(Fill::get_default_value()),
+        };
+        match self.fills.get_fill().get(1) {
+            Some(v) => obj.fills.set_fill(v.clone()),
+            None => obj.fills.set_fill(Fill::get_default_value_2()),
+        };
+        match self.borders.get_borders().first() {
+            Some(v) => obj.borders.set_borders(v.clone()),
+            None => obj.borders.set_borders(Borders::get_default_value()),
+        };
+
+        for cell_format in self.cell_style_formats.get_cell_format() {
+            let mut style = Style::default();
+            self.get_style_by_cell_format(&mut style, None, cell_format);
+            let mut cell_format = cell_format.clone();
+            cell_format
+                .set_number_format_id(obj.numbering_formats.set_style(&style))
+                .set_font_id(obj.fonts.set_style(&style))
+                .set_fill_id(obj.fills.set_style(&style))
+                .set_border_id(obj.borders.set_style(&style));
+            obj.cell_style_formats.set_cell_format(cell_format);
+        }
+        obj.cell_styles = self.cell_styles.clone();
+
+        // the style of the cells with no style.
+        let style = match self.maked_style_list.first() {
+            Some(v) => v.clone(),
+            None => Style::get_default_value(),
+        };
+        obj.add_cell_format(&style);
+        obj
+    }
+
+    /// (This method is crate only.)
+    /// Get the formatting of the cell style format.
+    pub(crate) fn get_style_by_format_id(&self, format_id: u32) -> Style {
+        let mut style = Style::default();
+        if let Some(v) = self
+            .cell_style_formats
+            .get_cell_format()
+            .get(format_id as usize)
+        {
+            self.get_style_by_cell_format(&mut style, None, v);
+        }
+        style.set_format_id(format_id);
+        style
+    }
+
+    /// (This method is crate only.)
+    /// Get the cell styles. (cellStyles)
+    pub(crate) fn get_named_cell_styles(&self) -> Vec<NamedCellStyle> {
+        self.cell_styles
+            .get_cell_style()
+            .iter()
+            .map(|v| {
+                let mut obj = NamedCellStyle::default();
+                obj.set_name(v.get_name())
+                    .set_builtin_id(v.has_builtin_id().then(|| *v.get_builtin_id()))
+                    .set_format_id(*v.get_format_id())
+                    .set_style(self.get_style_by_format_id(*v.get_format_id()));
+                obj
+            })
+            .collect()
+    }
+
+    /// (This method is crate only.)
+    /// Add a cell style, or replace the formatting of the cell style with the same name.
+    pub(crate) fn add_named_cell_style(
+        &mut self,
+        name: &str,
+        builtin_id: Option<u32>,
+        style: &Style,
+    ) -> NamedCellStyle {
+        self.set_normal_cell_style();
+
+        let mut cell_format = CellFormat::default();
+        cell_format.set_number_format_id(self.numbering_formats.set_style(style));
+        cell_format.set_font_id(self.fonts.set_style(style));
+        cell_format.set_fill_id(self.fills.set_style(style));
+        cell_format.set_border_id(self.borders.set_style(style));
+        // the formatting which the style does not include.
+        if style.get_numbering_format().is_none() {
+            cell_format.set_apply_number_format(false);
+        }
+        if style.get_font().is_none() {
+            cell_format.set_apply_font(false);
+        }
+        if style.get_fill().is_none() {
+            cell_format.set_apply_fill(false);
+        }
+        if style.get_borders().is_none() {
+            cell_format.set_apply_border(false);
+        }
+        match style.get_alignment() {
+            Some(v) => {
+                cell_format.set_alignment(v.clone());
+            }
+            None => {
+                cell_format.set_apply_alignment(false);
+            }
+        }
+        match style.get_protection() {
+            Some(v) => {
+                cell_format.set_protection(v.clone());
+            }
+            None => {
+                cell_format.set_apply_protection(false);
+            }
+        }
+
+        let format_id = match self
+            .cell_styles
+            .get_cell_style()
+            .iter()
+            .find(|v| v.get_name() == name)
+        {
+            Some(v) => {
+                let format_id = *v.get_format_id();
+                self.cell_style_formats.get_cell_format_mut()[format_id as usize] = cell_format;
+                format_id
+            }
+            None => {
+                let format_id = self.cell_style_formats.get_cell_format().len() as u32;
+                self.cell_style_formats.set_cell_format(cell_format);
+                let mut cell_style = CellStyle::default();
+                cell_style.set_name(name).set_format_id(format_id);
+                if let Some(v) = builtin_id {
+                    cell_style.set_builtin_id(v);
+                }
+                self.cell_styles.add_cell_style(cell_style);
+                format_id
+            }
+        };
+
+        let mut obj = NamedCellStyle::default();
+        obj.set_name(name)
+            .set_builtin_id(builtin_id)
+            .set_format_id(format_id)
+            .set_style(self.get_style_by_format_id(format_id));
+        obj
+    }
+
+    // The Normal style is the first cell style format.
+    fn set_normal_cell_style(&mut self) {
+        if !self.cell_style_formats.get_cell_format().is_empty() {
+            return;
+        }
+        let mut cell_format = CellFormat::default();
+        cell_format
+            .set_number_format_id(0)
+            .set_font_id(0)
+            .set_fill_id(0)
+            .set_border_id(0);
+        self.cell_style_formats.set_cell_format(cell_format);
+        if !self
+            .cell_styles
+            .get_cell_style()
+            .iter()
+            .any(|v| v.get_format_id() == &0)
+        {
+            let mut cell_style = CellStyle::default();
+            cell_style
+                .set_name(BuiltinCellStyleValues::Normal.get_name())
+                .set_format_id(0)
+                .set_builtin_id(BuiltinCellStyleValues::Normal.get_builtin_id());
+            self.cell_styles.add_cell_style(cell_style);
+        }
     }
 
     pub(crate) fn set_defalut_value(&mut self) -> &mut Self {
@@ -0,0 +1,191 @@
+// threadedComment
+use super::BooleanValue;
+use super::Coordinate;
+use super::StringValue;
+use super::ThreadedCommentMention;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// Comment of a conversation in a cell. (Office 365)
+/// The first comment of a cell starts the thread and the others reply to it by `parent_id`.
+#[derive(Clone, Default, Debug)]
+pub struct ThreadedComment {
+    coordinate: Coordinate,
+    date_time: StringValue,
+    person_id: StringValue,
+    id: StringValue,
+    parent_id: StringValue,
+    done: BooleanValue,
+    text: StringValue,
+    mentions: Vec<ThreadedCommentMention>,
+}
+
+impl ThreadedComment {
+    pub fn get_coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+
+    pub fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+        &mut self.coordinate
+    }
+
+    /// Get the time the comment was written. ex) `2024-01-31T09:30:00.00`
+    pub fn get_date_time(&self) -> &str {
+        self.date_time.get_value()
+    }
+
+    pub fn set_date_time<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.date_time.set_value(value);
+        self
+    }
+
+    /// Get the id of the `Person` who wrote the comment.
+    pub fn get_person_id(&self) -> &str {
+        self.person_id.get_value()
+    }
+
+    pub fn set_person_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.person_id.set_value(value);
+        self
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.get_value()
+    }
+
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    /// Get the id of the comment which starts the thread. (empty for the first comment)
+    pub fn get_parent_id(&self) -> &str {
+        self.parent_id.get_value()
+    }
+
+    pub fn set_parent_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.parent_id.set_value(value);
+        self
+    }
+
+    /// Get the resolved state of the thread. (first comment only)
+    pub fn get_done(&self) -> &bool {
+        self.done.get_value()
+    }
+
+    pub fn set_done(&mut self, value: bool) -> &mut Self {
+        self.done.set_value(value);
+        self
+    }
+
+    pub fn get_text(&self) -> &str {
+        self.text.get_value()
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    pub fn get_mentions(&self) -> &Vec<ThreadedCommentMention> {
+        &self.mentions
+    }
+
+    pub fn get_mentions_mut(&mut self) -> &mut Vec<ThreadedCommentMention> {
+        &mut self.mentions
+    }
+
+    pub fn add_mentions(&mut self, value: ThreadedCommentMention) -> &mut Self {
+        self.mentions.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.coordinate.set_coordinate(v);
+        }
+        set_string_from_xml!(self, e, date_time, "dT");
+        set_string_from_xml!(self, e, person_id, "personId");
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, parent_id, "parentId");
+        set_string_from_xml!(self, e, done, "done");
+
+        if empty_flag {
+            return;
+        }
+
+        let mut is_text = false;
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"text" {
+                    is_text = true;
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"mention" {
+                    let mut obj = ThreadedCommentMention::default();
+                    obj.set_attributes(e);
+                    self.mentions.push(obj);
+                }
+            },
+            Event::Text(e) => {
+                if is_text {
+                    self.text.set_value(e.unescape().unwrap().to_string());
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"text" => is_text = false,
+                    b"threadedComment" => return,
+                    _ => (),
+                }
+            },
+            Event::Eof => panic!("Error not find {} end element", "threadedComment")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // threadedComment
+        let coordinate = self.coordinate.get_coordinate();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("ref", &coordinate));
+        if self.date_time.has_value() {
+            attributes.push(("dT", self.date_time.get_value_string()));
+        }
+        attributes.push(("personId", self.person_id.get_value_string()));
+        attributes.push(("id", self.id.get_value_string()));
+        if self.parent_id.has_value() {
+            attributes.push(("parentId", self.parent_id.get_value_string()));
+        }
+        if self.done.has_value() {
+            attributes.push(("done", self.done.get_value_string()));
+        }
+        write_start_tag(writer, "threadedComment", attributes, false);
+
+        // text
+        write_start_tag(writer, "text", vec![], false);
+        write_text_node(writer, self.text.get_value_string());
+        write_end_tag(writer, "text");
+
+        // mentions
+        if !self.mentions.is_empty() {
+            write_start_tag(writer, "mentions", vec![], false);
+            for v in &self.mentions {
+                v.write_to(writer);
+            }
+            write_end_tag(writer, "mentions");
+        }
+
+        write_end_tag(writer, "threadedComment");
+    }
+}
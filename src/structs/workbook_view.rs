@@ -12,6 +12,7 @@ pub struct WorkbookView {
 }
 
 impl WorkbookView {
+    /// Get the position of the active tab. Chart sheets are counted in the tab order.
     pub fn get_active_tab(&self) -> &u32 {
         self.active_tab.get_value()
     }
@@ -1,31 +1,57 @@
+use chrono::{NaiveDate, NaiveDateTime};
 use hashbrown::HashMap;
+use helper::conditional_formatting::{get_effective_style, RangeCache};
 use helper::coordinate::*;
+use helper::crypt::make_guid;
+use helper::data_validation::validate_cells;
+use helper::date::*;
+use helper::formula::translate_formula;
+use helper::number_format::{FormattedValue, NumberFormatLocale};
 use helper::range::*;
 use structs::drawing::spreadsheet::WorksheetDrawing;
 use structs::raw::RawWorksheet;
+use structs::vml::spreadsheet::Anchor;
 use structs::AutoFilter;
 use structs::Cell;
+use structs::CellData;
+use structs::CellFormula;
+use structs::CellFormulaValues;
 use structs::CellValue;
 use structs::Cells;
 use structs::Chart;
+use structs::ChartEx;
 use structs::Color;
+use structs::ColorScale;
 use structs::Column;
 use structs::ColumnBreaks;
 use structs::Columns;
 use structs::Comment;
+use structs::ConditionalFormatValueObject;
+use structs::ConditionalFormatValueObjectValues;
+use structs::ConditionalFormatValues;
 use structs::ConditionalFormatting;
+use structs::ConditionalFormattingOperatorValues;
+use structs::ConditionalFormattingRule;
+use structs::Coordinate;
+use structs::DataBar;
+use structs::DataValidationViolation;
 use structs::DataValidations;
 use structs::DefinedName;
+use structs::EffectiveStyle;
+use structs::Formula;
 use structs::HeaderFooter;
 use structs::Hyperlink;
 use structs::Image;
 use structs::MediaObject;
 use structs::MergeCells;
+use structs::NamedCellStyle;
+use structs::NoteOption;
 use structs::OleObjects;
 use structs::PageMargins;
 use structs::PageSetup;
 use structs::PrintOptions;
 use structs::Range;
+use structs::RichText;
 use structs::Row;
 use structs::RowBreaks;
 use structs::Rows;
@@ -33,9 +59,13 @@ use structs::SharedStringTable;
 use structs::SheetFormatProperties;
 use structs::SheetProtection;
 use structs::SheetViews;
+use structs::SparklineGroup;
+use structs::SparklineGroups;
 use structs::Style;
 use structs::Stylesheet;
 use structs::Table;
+use structs::TextElement;
+use structs::ThreadedComment;
 
 use reader::xlsx::worksheet::*;
 
@@ -59,6 +89,7 @@ pub struct Worksheet {
     merge_cells: MergeCells,
     auto_filter: Option<AutoFilter>,
     comments: Vec<Comment>,
+    threaded_comments: Vec<ThreadedComment>,
     active_cell: String,
     tab_color: Option<Color>,
     code_name: Option<String>,
@@ -71,6 +102,11 @@ pub struct Worksheet {
     data_validations: Option<DataValidations>,
     sheet_format_properties: SheetFormatProperties,
     sheet_protection: Option<SheetProtection>,
+    sparkline_groups: Option<SparklineGroups>,
+    unknown_elements: Vec<(String, String)>,
+    unknown_extensions: Vec<String>,
+    namespaces: Vec<(String, String)>,
+    date1904: bool,
 }
 
 impl Worksheet {
@@ -140,8 +176,219 @@ impl Worksheet {
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
-        self.cell_collection
-            .get_formatted_value_by_column_and_row(&col, &row)
+        let calendar = if self.date1904 {
+            CALENDAR_MAC_1904
+        } else {
+            CALENDAR_WINDOWS_1900
+        };
+        match self.cell_collection.get((&col, &row)) {
+            Some(cell) => cell.get_formatted_value_by_calendar(calendar),
+            None => "".into(),
+        }
+    }
+
+    /// Get formatted value and the color of the number format with the locale.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `locale` - Separators and names of months and days.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::helper::number_format::NumberFormatLocale;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(-1234.5);
+    /// worksheet
+    ///     .get_style_mut("A1")
+    ///     .get_number_format_mut()
+    ///     .set_format_code("#,##0.00;[Red]-#,##0.00");
+    /// let locale = NumberFormatLocale::from_name("de-DE").unwrap();
+    /// let value = worksheet.get_formatted_value_by_locale("A1", &locale);
+    /// assert_eq!(value.get_text(), "-1.234,50");
+    /// assert_eq!(value.get_color(), Some("FFFF0000"));
+    /// ```
+    pub fn get_formatted_value_by_locale<T>(
+        &self,
+        coordinate: T,
+        locale: &NumberFormatLocale,
+    ) -> FormattedValue
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let calendar = if self.date1904 {
+            CALENDAR_MAC_1904
+        } else {
+            CALENDAR_WINDOWS_1900
+        };
+        match self.cell_collection.get((&col, &row)) {
+            Some(cell) => cell.get_formatted_value_by_locale(locale, calendar),
+            None => FormattedValue::default(),
+        }
+    }
+
+    /// Get the date time of the cell with the date system of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `Option<NaiveDateTime>` - None if the cell is not a number with a date format.
+    pub fn get_value_datetime<T>(&self, coordinate: T) -> Option<NaiveDateTime>
+    where
+        T: Into<CellCoordinates>,
+    {
+        self.get_cell(coordinate)?
+            .get_value_datetime_crate(self.date1904)
+    }
+
+    /// Get the typed value of the cell with the date system of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `CellData` - `CellData::Empty` if the cell does not exist.
+    pub fn get_typed_value<T>(&self, coordinate: T) -> CellData
+    where
+        T: Into<CellCoordinates>,
+    {
+        match self.get_cell(coordinate) {
+            Some(cell) => cell.get_typed_value_crate(self.date1904),
+            None => CellData::Empty,
+        }
+    }
+
+    /// Get the formula of the cell.
+    /// The dependent cells of a shared formula get the formula moved from the master cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_formula_shared("B1:B3", "A1*2");
+    /// assert_eq!(worksheet.get_formula("B3"), "A3*2");
+    /// ```
+    pub fn get_formula<T>(&self, coordinate: T) -> String
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let formula = match self.get_cell((col, row)).and_then(|v| v.get_cell_formula()) {
+            Some(v) => v,
+            None => return String::new(),
+        };
+        if !formula.is_shared() || formula.is_shared_master() {
+            return formula.get_text().to_string();
+        }
+        let shared_index = formula.get_shared_index();
+        let is_master = |cell: &Cell| {
+            cell.get_cell_formula().map_or(false, |v| {
+                v.is_shared_master() && v.get_shared_index() == shared_index
+            })
+        };
+        match self
+            .find_shared_formula_master(col, row, shared_index)
+            .or_else(|| {
+                self.cell_collection
+                    .get_collection_to_hashmap()
+                    .values()
+                    .find(|v| is_master(v))
+            }) {
+            Some(master) => {
+                let col_offset = col as i32 - *master.get_coordinate().get_col_num() as i32;
+                let row_offset = row as i32 - *master.get_coordinate().get_row_num() as i32;
+                translate_formula(
+                    master.get_cell_formula().unwrap().get_text(),
+                    col_offset,
+                    row_offset,
+                )
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Find the master of a shared formula by walking up and then left through its group.
+    /// The master is the top left cell of the range of a shared formula.
+    fn find_shared_formula_master(
+        &self,
+        col: u32,
+        row: u32,
+        shared_index: Option<u32>,
+    ) -> Option<&Cell> {
+        let get_formula = |col: u32, row: u32| {
+            self.get_cell((col, row))
+                .and_then(|v| v.get_cell_formula())
+                .filter(|v| v.get_shared_index() == shared_index)
+        };
+        let mut row = row;
+        while row > 1 && get_formula(col, row - 1).map_or(false, |v| !v.is_shared_master()) {
+            row -= 1;
+        }
+        let mut col = col;
+        while col > 1 && get_formula(col - 1, row).map_or(false, |v| !v.is_shared_master()) {
+            col -= 1;
+        }
+        for (col, row) in [(col, row - 1), (col - 1, row)] {
+            if col == 0 || row == 0 {
+                continue;
+            }
+            if get_formula(col, row).map_or(false, |v| v.is_shared_master()) {
+                return self.get_cell((col, row));
+            }
+        }
+        None
+    }
+
+    /// Set a shared formula to the range.
+    /// The first cell keeps the formula and the other cells share it with moved references.
+    /// # Arguments
+    /// * `range` - Range of the cells. ex) `"B1:B10"`
+    /// * `value` - Formula of the first cell without `=`. ex) `"A1*2"`
+    pub fn set_formula_shared<S: Into<String>>(&mut self, range: &str, value: S) -> &mut Self {
+        let shared_index = self
+            .cell_collection
+            .get_collection()
+            .iter()
+            .filter_map(|v| v.get_cell_formula().and_then(|f| f.get_shared_index()))
+            .max()
+            .map_or(0, |v| v + 1);
+        let value = value.into();
+        for (i, (col, row)) in get_coordinate_list(range).into_iter().enumerate() {
+            let mut formula = CellFormula::default();
+            formula
+                .set_formula_type(CellFormulaValues::Shared)
+                .set_shared_index(shared_index);
+            if i == 0 {
+                formula.set_text(value.clone()).set_reference(range);
+            }
+            let cell = self.get_cell_mut((col, row));
+            cell.get_cell_value_mut().remove_formula();
+            cell.set_cell_formula(formula);
+        }
+        self
+    }
+
+    /// Set a date time to the cell with the date system of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `value` - Date time.
+    pub fn set_value_datetime<T>(&mut self, coordinate: T, value: NaiveDateTime) -> &mut Cell
+    where
+        T: Into<CellCoordinates>,
+    {
+        let date1904 = self.date1904;
+        self.get_cell_mut(coordinate)
+            .set_value_datetime_crate(value, date1904)
+    }
+
+    /// Set a date to the cell with the date system of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `value` - Date.
+    pub fn set_value_date<T>(&mut self, coordinate: T, value: NaiveDate) -> &mut Cell
+    where
+        T: Into<CellCoordinates>,
+    {
+        let date1904 = self.date1904;
+        self.get_cell_mut(coordinate)
+            .set_value_date_crate(value, date1904)
     }
 
     // ************************
@@ -173,12 +420,14 @@ impl Worksheet {
         if self.is_deserialized() {
             panic!("This Worksheet is Deserialized.");
         }
-        read_lite(
+        let mut cells = read_lite(
             self.raw_data_of_worksheet.as_ref().unwrap(),
             shared_string_table,
             stylesheet,
         )
-        .unwrap()
+        .unwrap();
+        cells.set_date1904(self.date1904);
+        cells
     }
 
     /// (This method is crate only.)
@@ -432,6 +681,45 @@ impl Worksheet {
         self
     }
 
+    /// Apply the named cell style to the cells in the range.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:B2"
+    /// * `value` - The named cell style. (Spreadsheet::get_named_cell_style)
+    pub fn set_named_cell_style_by_range(
+        &mut self,
+        range: &str,
+        value: &NamedCellStyle,
+    ) -> &mut Self {
+        let range_upper = range.to_uppercase();
+        let coordinate_list = get_coordinate_list(&range_upper);
+
+        let (col_num_start, row_num_start) = coordinate_list[0];
+        if col_num_start == 0 {
+            let (_, row_num_end) = coordinate_list[1];
+            for row_num in row_num_start..=row_num_end {
+                self.get_row_dimension_mut(&row_num)
+                    .get_style_mut()
+                    .set_named_cell_style(value);
+            }
+            return self;
+        }
+        if row_num_start == 0 {
+            let (col_num_end, _) = coordinate_list[1];
+            for col_num in col_num_start..=col_num_end {
+                self.get_column_dimension_by_number_mut(&col_num)
+                    .get_style_mut()
+                    .set_named_cell_style(value);
+            }
+            return self;
+        }
+
+        for (col_num, row_num) in coordinate_list {
+            self.get_style_mut((col_num, row_num))
+                .set_named_cell_style(value);
+        }
+        self
+    }
+
     // ************************
     // Comment
     // ************************
@@ -474,6 +762,269 @@ impl Worksheet {
         !self.comments.is_empty()
     }
 
+    /// Add a note to the cell. The note replaces the comment of the cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `author` - The author of the note.
+    /// * `text` - The text of the note.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// worksheet.add_note("A1", "Author", "Note text.");
+    /// ```
+    pub fn add_note<T, S, U>(&mut self, coordinate: T, author: S, text: U) -> &mut Comment
+    where
+        T: Into<CellCoordinates>,
+        S: Into<String>,
+        U: Into<String>,
+    {
+        self.add_note_with_option(coordinate, author, text, &NoteOption::default())
+    }
+
+    /// Add a note to the cell with the visibility, the size, the fill color and the font.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `author` - The author of the note.
+    /// * `text` - The text of the note.
+    /// * `option` - The appearance of the note.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// let mut option = umya_spreadsheet::NoteOption::default();
+    /// option
+    ///     .set_visible(true)
+    ///     .set_size_in_pixels(200, 100)
+    ///     .set_fill_color("#ccffcc");
+    /// worksheet.add_note_with_option("B2", "Author", "Note text.", &option);
+    /// ```
+    pub fn add_note_with_option<T, S, U>(
+        &mut self,
+        coordinate: T,
+        author: S,
+        text: U,
+        option: &NoteOption,
+    ) -> &mut Comment
+    where
+        T: Into<CellCoordinates>,
+        S: Into<String>,
+        U: Into<String>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.remove_comment((col, row));
+
+        let mut comment = Comment::default();
+        comment
+            .get_coordinate_mut()
+            .set_col_num(col)
+            .set_row_num(row);
+        comment.set_author(author).set_default_shape();
+
+        let mut text_element = TextElement::default();
+        text_element.set_text(text);
+        if let Some(font) = option.get_font() {
+            text_element.set_font(font.clone());
+        }
+        comment.get_text_mut().add_rich_text_elements(text_element);
+
+        // the box starts at the same place as the default shape. (zero-based)
+        let mut anchor = comment.get_anchor().clone();
+        let left_col = *anchor.get_left_column();
+        let top_row = *anchor.get_top_row();
+        let margin_left = (0..left_col)
+            .map(|v| self.get_column_width_in_pixels(v + 1))
+            .sum::<u32>()
+            + anchor.get_left_offset();
+        let margin_top = (0..top_row)
+            .map(|v| self.get_row_height_in_pixels(v + 1))
+            .sum::<u32>()
+            + anchor.get_top_offset();
+        let (width, height) = if *option.get_size_in_pixels() {
+            let (right_col, right_offset) = Self::get_cell_from_pixels(
+                left_col,
+                anchor.get_left_offset() + option.get_width(),
+                |v| self.get_column_width_in_pixels(v + 1),
+            );
+            let (bottom_row, bottom_offset) = Self::get_cell_from_pixels(
+                top_row,
+                anchor.get_top_offset() + option.get_height(),
+                |v| self.get_row_height_in_pixels(v + 1),
+            );
+            anchor.set_right_column(right_col);
+            anchor.set_right_offset(right_offset);
+            anchor.set_bottom_row(bottom_row);
+            anchor.set_bottom_offset(bottom_offset);
+            (*option.get_width(), *option.get_height())
+        } else {
+            let right_col = left_col + option.get_width();
+            let bottom_row = top_row + option.get_height();
+            anchor.set_right_column(right_col);
+            anchor.set_right_offset(*anchor.get_left_offset());
+            anchor.set_bottom_row(bottom_row);
+            anchor.set_bottom_offset(*anchor.get_top_offset());
+            let width = (left_col..right_col)
+                .map(|v| self.get_column_width_in_pixels(v + 1))
+                .sum::<u32>();
+            let height = (top_row..bottom_row)
+                .map(|v| self.get_row_height_in_pixels(v + 1))
+                .sum::<u32>();
+            (width, height)
+        };
+        comment
+            .set_box(anchor, margin_left, margin_top, width, height)
+            .set_visible(*option.get_visible())
+            .set_fill_color(option.get_fill_color());
+
+        self.comments.push(comment);
+        self.comments.last_mut().unwrap()
+    }
+
+    /// Remove the comment of the cell and the threaded comments of the cell.
+    /// Returns false if the cell has no comment.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    pub fn remove_comment<T>(&mut self, coordinate: T) -> bool
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let is_match = |coordinate: &Coordinate| {
+            coordinate.get_col_num() == &col && coordinate.get_row_num() == &row
+        };
+        let count = self.comments.len() + self.threaded_comments.len();
+        self.comments.retain(|v| !is_match(v.get_coordinate()));
+        self.threaded_comments
+            .retain(|v| !is_match(v.get_coordinate()));
+        count != self.comments.len() + self.threaded_comments.len()
+    }
+
+    // the width of the column in pixels. (Calibri 11pt)
+    fn get_column_width_in_pixels(&self, col: u32) -> u32 {
+        let width = match self.get_column_dimension_by_number(&col) {
+            Some(v) => *v.get_width(),
+            None => match *self.sheet_format_properties.get_default_column_width() {
+                v if v > 0f64 => v,
+                _ => 8.43,
+            },
+        };
+        (width * 7f64 + 5f64).trunc() as u32
+    }
+
+    // the height of the row in pixels.
+    fn get_row_height_in_pixels(&self, row: u32) -> u32 {
+        let height = match self.get_row_dimension(&row).map(|v| *v.get_height()) {
+            Some(v) if v > 0f64 => v,
+            _ => match *self.sheet_format_properties.get_default_row_height() {
+                v if v > 0f64 => v,
+                _ => 15f64,
+            },
+        };
+        (height * 4f64 / 3f64).round() as u32
+    }
+
+    // the cell and the offset at the distance from the start of the cell.
+    fn get_cell_from_pixels<F: Fn(u32) -> u32>(start: u32, distance: u32, size: F) -> (u32, u32) {
+        let mut index = start;
+        let mut rest = distance;
+        loop {
+            let size = size(index);
+            if rest < size {
+                return (index, rest);
+            }
+            rest -= size;
+            index += 1;
+        }
+    }
+
+    /// Get Threaded Comments. (Office 365)
+    pub fn get_threaded_comments(&self) -> &Vec<ThreadedComment> {
+        &self.threaded_comments
+    }
+
+    /// Get Threaded Comments in mutable.
+    pub fn get_threaded_comments_mut(&mut self) -> &mut Vec<ThreadedComment> {
+        &mut self.threaded_comments
+    }
+
+    /// Add a threaded comment.
+    /// The comment starts a thread if the cell has none, otherwise it replies to the thread.
+    /// A legacy note for older versions of Excel is added to the cell as well.
+    /// # Arguments
+    /// * `value` - ThreadedComment. The id is made if it is empty.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut person = umya_spreadsheet::Person::default();
+    /// person.set_display_name("John").set_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}");
+    /// book.add_person(person);
+    /// let mut comment = umya_spreadsheet::ThreadedComment::default();
+    /// comment.get_coordinate_mut().set_coordinate("A1");
+    /// comment
+    ///     .set_person_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}")
+    ///     .set_text("Please check.");
+    /// book.get_sheet_by_name_mut("Sheet1").unwrap().add_threaded_comment(comment);
+    /// ```
+    pub fn add_threaded_comment(&mut self, mut value: ThreadedComment) -> &mut Self {
+        if value.get_id().is_empty() {
+            value.set_id(make_guid());
+        }
+        let coordinate = value.get_coordinate().get_coordinate();
+        let parent_id = self
+            .threaded_comments
+            .iter()
+            .find(|v| {
+                v.get_parent_id().is_empty() && v.get_coordinate().get_coordinate() == coordinate
+            })
+            .map(|v| v.get_id().to_string());
+        match parent_id {
+            Some(v) => {
+                if value.get_parent_id().is_empty() {
+                    value.set_parent_id(v);
+                }
+            }
+            None => {
+                // the legacy note of the thread.
+                self.comments
+                    .retain(|v| v.get_coordinate().get_coordinate() != coordinate);
+                let mut comment = Comment::default();
+                comment
+                    .get_coordinate_mut()
+                    .set_coordinate(coordinate.as_str());
+                comment
+                    .set_author(format!("tc={}", value.get_id()))
+                    .set_default_shape();
+                self.comments.push(comment);
+            }
+        }
+        self.threaded_comments.push(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the text of the legacy note which stands for a thread.
+    /// Returns None if the comment is not the note of a thread.
+    pub(crate) fn get_threaded_comment_placeholder(&self, comment: &Comment) -> Option<RichText> {
+        let id = comment.get_author().strip_prefix("tc=")?;
+        let thread = self
+            .threaded_comments
+            .iter()
+            .find(|v| v.get_id() == id && v.get_parent_id().is_empty())?;
+        let mut text = String::from("[Threaded comment]\n\nYour version of Excel allows you to read this threaded comment; however, any edits to it will get removed if the file is opened in a newer version of Excel. Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    ");
+        text.push_str(thread.get_text());
+        for reply in self
+            .threaded_comments
+            .iter()
+            .filter(|v| v.get_parent_id() == id)
+        {
+            text.push_str("\nReply:\n    ");
+            text.push_str(reply.get_text());
+        }
+        let mut result = RichText::default();
+        result.set_text(text);
+        Some(result)
+    }
+
     // ************************
     // Conditional
     // ************************
@@ -496,26 +1047,363 @@ impl Worksheet {
         self.conditional_formatting_collection.push(value);
     }
 
+    /// (This method is crate only.)
+    /// Add ConditionalFormatting read from the x14 extension.
+    /// Data bar rules are merged into the rule of the same id.
+    /// Returns false, and adds nothing, if a rule to merge into is not found.
+    pub(crate) fn add_conditional_formatting_x14(
+        &mut self,
+        mut value: ConditionalFormatting,
+    ) -> bool {
+        let is_linked = |rule: &ConditionalFormattingRule,
+                         collection: &Vec<ConditionalFormatting>| {
+            collection
+                .iter()
+                .flat_map(|v| v.get_conditional_collection())
+                .any(|v| v.get_data_bar().is_some() && v.get_id() == rule.get_id())
+                && !rule.get_id().is_empty()
+        };
+        for rule in value.get_conditional_collection() {
+            if rule.get_data_bar().is_some()
+                && !is_linked(rule, &self.conditional_formatting_collection)
+            {
+                return false;
+            }
+        }
+
+        let mut rules = Vec::new();
+        for rule in value.get_conditional_collection_mut().drain(..) {
+            let x14 = match rule.get_data_bar() {
+                Some(v) => v.clone(),
+                None => {
+                    rules.push(rule);
+                    continue;
+                }
+            };
+            for base in self
+                .conditional_formatting_collection
+                .iter_mut()
+                .flat_map(|v| v.get_conditional_collection_mut())
+            {
+                if base.get_id() == rule.get_id() {
+                    if let Some(data_bar) = base.get_data_bar_mut() {
+                        data_bar.merge_x14(x14);
+                        break;
+                    }
+                }
+            }
+        }
+        if !rules.is_empty() {
+            value.set_conditional_collection(rules);
+            self.conditional_formatting_collection.push(value);
+        }
+        true
+    }
+
+    /// Add a conditional formatting rule to a range.
+    /// The rule is given the lowest priority of the worksheet, its highest priority number plus one.
+    /// Priorities are numbered per worksheet, as a priority only orders the rules of its own worksheet.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `rule` - ConditionalFormattingRule
+    pub fn add_conditional_formatting_rule<S: Into<String>>(
+        &mut self,
+        range: S,
+        mut rule: ConditionalFormattingRule,
+    ) -> &mut ConditionalFormattingRule {
+        let priority = self
+            .conditional_formatting_collection
+            .iter()
+            .flat_map(|v| v.get_conditional_collection())
+            .map(|v| *v.get_priority())
+            .max()
+            .unwrap_or(0);
+        rule.set_priority(priority + 1);
+
+        let mut obj = ConditionalFormatting::default();
+        obj.get_sequence_of_references_mut().set_sqref(range);
+        obj.add_conditional_collection(rule);
+        self.conditional_formatting_collection.push(obj);
+        self.conditional_formatting_collection
+            .last_mut()
+            .unwrap()
+            .get_conditional_collection_mut()
+            .last_mut()
+            .unwrap()
+    }
+
+    /// Highlight the cells whose value compares with a value.
+    /// The upper bound of `Between` and `NotBetween` is given by `set_formula2` of the rule.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `operator` - ConditionalFormattingOperatorValues
+    /// * `value` - Value or formula. ex) "10", "\"text\"", "$B$1"
+    /// * `style` - Style applied to the cells.
+    pub fn add_cell_is_rule<S: Into<String>, T: Into<String>>(
+        &mut self,
+        range: S,
+        operator: ConditionalFormattingOperatorValues,
+        value: T,
+        style: Style,
+    ) -> &mut ConditionalFormattingRule {
+        let mut formula = Formula::default();
+        formula.set_address_str(value);
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::CellIs)
+            .set_operator(operator)
+            .set_formula(formula)
+            .set_style(style);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Add a 2-color scale from the lowest to the highest value.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `min_color` - ARGB of the lowest value. ex) "FFF8696B"
+    /// * `max_color` - ARGB of the highest value.
+    pub fn add_color_scale_2<S: Into<String>>(
+        &mut self,
+        range: S,
+        min_color: &str,
+        max_color: &str,
+    ) -> &mut ConditionalFormattingRule {
+        let mut color_scale = ColorScale::default();
+        for (value_type, argb) in [
+            (ConditionalFormatValueObjectValues::Min, min_color),
+            (ConditionalFormatValueObjectValues::Max, max_color),
+        ] {
+            let mut cfvo = ConditionalFormatValueObject::default();
+            cfvo.set_type(value_type);
+            color_scale.add_cfvo_collection(cfvo);
+            let mut color = Color::default();
+            color.set_argb(argb);
+            color_scale.add_color_collection(color);
+        }
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::ColorScale)
+            .set_color_scale(color_scale);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Add a 3-color scale from the lowest to the highest value. The midpoint is the 50th percentile.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `min_color` - ARGB of the lowest value. ex) "FFF8696B"
+    /// * `mid_color` - ARGB of the midpoint.
+    /// * `max_color` - ARGB of the highest value.
+    pub fn add_color_scale_3<S: Into<String>>(
+        &mut self,
+        range: S,
+        min_color: &str,
+        mid_color: &str,
+        max_color: &str,
+    ) -> &mut ConditionalFormattingRule {
+        let mut color_scale = ColorScale::default();
+        for (value_type, value, argb) in [
+            (ConditionalFormatValueObjectValues::Min, None, min_color),
+            (
+                ConditionalFormatValueObjectValues::Percentile,
+                Some("50"),
+                mid_color,
+            ),
+            (ConditionalFormatValueObjectValues::Max, None, max_color),
+        ] {
+            let mut cfvo = ConditionalFormatValueObject::default();
+            cfvo.set_type(value_type);
+            if let Some(v) = value {
+                cfvo.set_val(v);
+            }
+            color_scale.add_cfvo_collection(cfvo);
+            let mut color = Color::default();
+            color.set_argb(argb);
+            color_scale.add_color_collection(color);
+        }
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::ColorScale)
+            .set_color_scale(color_scale);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Add a data bar from the lowest to the highest value.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `color` - ARGB of the bar. ex) "FF638EC6"
+    pub fn add_data_bar<S: Into<String>>(
+        &mut self,
+        range: S,
+        color: &str,
+    ) -> &mut ConditionalFormattingRule {
+        let mut data_bar = DataBar::default();
+        for value_type in [
+            ConditionalFormatValueObjectValues::Min,
+            ConditionalFormatValueObjectValues::Max,
+        ] {
+            let mut cfvo = ConditionalFormatValueObject::default();
+            cfvo.set_type(value_type);
+            data_bar.add_cfvo_collection(cfvo);
+        }
+        let mut obj = Color::default();
+        obj.set_argb(color);
+        data_bar.add_color_collection(obj);
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::DataBar)
+            .set_data_bar(data_bar);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Highlight the top n values.
+    /// Use `set_bottom(true)` or `set_percent(true)` on the returned rule for the bottom n or top n percent.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `n` - Number of values.
+    /// * `style` - Style applied to the cells.
+    pub fn add_top_n<S: Into<String>>(
+        &mut self,
+        range: S,
+        n: u32,
+        style: Style,
+    ) -> &mut ConditionalFormattingRule {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::Top10)
+            .set_rank(n)
+            .set_style(style);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Highlight the values that appear more than once.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `style` - Style applied to the cells.
+    pub fn add_duplicate_values<S: Into<String>>(
+        &mut self,
+        range: S,
+        style: Style,
+    ) -> &mut ConditionalFormattingRule {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::DuplicateValues)
+            .set_style(style);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Highlight the cells that contain a text. (case-insensitive)
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:A10"
+    /// * `text` - Text to find.
+    /// * `style` - Style applied to the cells.
+    pub fn add_text_contains<S: Into<String>, T: Into<String>>(
+        &mut self,
+        range: S,
+        text: T,
+        style: Style,
+    ) -> &mut ConditionalFormattingRule {
+        let range = range.into();
+        let text = text.into();
+        // the formula is relative to the top left cell of the range.
+        let top_left = range
+            .split_whitespace()
+            .next()
+            .and_then(|v| v.split(':').next())
+            .unwrap_or_default()
+            .replace('$', "");
+        let mut formula = Formula::default();
+        formula.set_string_value(format!(
+            "NOT(ISERROR(SEARCH(\"{}\",{})))",
+            text.replace('"', "\"\""),
+            top_left
+        ));
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::ContainsText)
+            .set_operator(ConditionalFormattingOperatorValues::ContainsText)
+            .set_text(text)
+            .set_formula(formula)
+            .set_style(style);
+        self.add_conditional_formatting_rule(range, rule)
+    }
+
+    /// Get the look of the cell after the conditional formatting is applied.
+    /// Rules are evaluated in the order of their priority.
+    /// Rules with a formula this crate cannot evaluate do not apply.
+    /// References to the other sheets and the names of the workbook need `Spreadsheet::get_effective_style`.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `EffectiveStyle` - Style, color scale color, data bar and icon of the cell.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    /// let style = worksheet.get_effective_style("A1");
+    /// ```
+    pub fn get_effective_style<T>(&self, coordinate: T) -> EffectiveStyle
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        get_effective_style(self, None, col, row, &mut RangeCache::default())
+    }
+
+    /// Get the looks of the cells of a range after the conditional formatting is applied.
+    /// Faster than `get_effective_style` for each cell as the values of the rules are read once.
+    /// # Arguments
+    /// * `range` - Range. ex) `"A1:C10"`
+    /// # Return value
+    /// * `Vec<EffectiveStyle>` - In the order of the rows.
+    pub fn get_effective_style_by_range(&self, range: &str) -> Vec<EffectiveStyle> {
+        let mut cache = RangeCache::default();
+        get_coordinate_list(&range.to_uppercase())
+            .into_iter()
+            .map(|(col, row)| get_effective_style(self, None, col, row, &mut cache))
+            .collect()
+    }
+
     // ************************
     // Hyperlink
     // ************************
     /// (This method is crate only.)
-    /// Get Hyperlink convert to hashmap.
-    pub(crate) fn get_hyperlink_collection_to_hashmap(&self) -> HashMap<String, &Hyperlink> {
-        let mut result: HashMap<String, &Hyperlink> = HashMap::new();
-        for cell in self.cell_collection.get_collection() {
-            match cell.get_hyperlink() {
-                Some(hyperlink) => {
-                    let coordition = coordinate_from_index(
-                        cell.get_coordinate().get_col_num(),
-                        cell.get_coordinate().get_row_num(),
-                    );
-                    result.insert(coordition, hyperlink);
-                }
-                None => {}
-            }
+    /// Get the hyperlinks with the coordinates of the cells in the order of the rows.
+    pub(crate) fn get_hyperlink_collection(&self) -> Vec<(String, &Hyperlink)> {
+        let mut cells: Vec<&Cell> = self
+            .cell_collection
+            .get_collection()
+            .into_iter()
+            .filter(|v| v.get_hyperlink().is_some())
+            .collect();
+        cells.sort_by_key(|v| {
+            (
+                *v.get_coordinate().get_row_num(),
+                *v.get_coordinate().get_col_num(),
+            )
+        });
+        cells
+            .into_iter()
+            .map(|v| {
+                (
+                    coordinate_from_index(
+                        v.get_coordinate().get_col_num(),
+                        v.get_coordinate().get_row_num(),
+                    ),
+                    v.get_hyperlink().as_ref().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    /// Remove the hyperlink of the cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    pub fn remove_hyperlink<T>(&mut self, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        if let Some(v) = self
+            .cell_collection
+            .get_collection_to_hashmap_mut()
+            .get_mut(&(row, col))
+        {
+            v.remove_hyperlink();
         }
-        result
+        self
     }
 
     /// (This method is crate only.)
@@ -970,6 +1858,18 @@ impl Worksheet {
                 );
             }
 
+            // threaded comments
+            for threaded_comment in &mut self.threaded_comments {
+                threaded_comment
+                    .get_coordinate_mut()
+                    .adjustment_insert_coordinate(
+                        root_col_num,
+                        offset_col_num,
+                        root_row_num,
+                        offset_row_num,
+                    );
+            }
+
             // conditional styles
             for conditional_styles in &mut self.conditional_formatting_collection {
                 for range in conditional_styles
@@ -1112,6 +2012,26 @@ impl Worksheet {
                 );
             }
 
+            // threaded comments
+            self.threaded_comments.retain(|x| {
+                !(x.get_coordinate().is_remove(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                ))
+            });
+            for threaded_comment in &mut self.threaded_comments {
+                threaded_comment
+                    .get_coordinate_mut()
+                    .adjustment_remove_coordinate(
+                        root_col_num,
+                        offset_col_num,
+                        root_row_num,
+                        offset_row_num,
+                    );
+            }
+
             // conditional styles
             for conditional_styles in &mut self.conditional_formatting_collection {
                 conditional_styles
@@ -1567,6 +2487,88 @@ impl Worksheet {
         self
     }
 
+    /// Check the cell values against the data validations.
+    /// Blank cells are checked only if the data validation does not allow blanks.
+    /// Cells whose data validation cannot be evaluated are reported with `is_unevaluated`.
+    /// References to the other sheets need `Spreadsheet::validate_cells`.
+    /// # Return value
+    /// * `Vec<DataValidationViolation>` - Cells whose value is not valid or not checked.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    /// for violation in worksheet.validate_cells() {
+    ///     println!("{}: {}", violation.get_coordinate().get_coordinate(), violation.get_value());
+    /// }
+    /// ```
+    pub fn validate_cells(&self) -> Vec<DataValidationViolation> {
+        validate_cells(self, None)
+    }
+
+    pub fn get_sparkline_groups(&self) -> &Option<SparklineGroups> {
+        &self.sparkline_groups
+    }
+
+    pub fn get_sparkline_groups_mut(&mut self) -> &mut Option<SparklineGroups> {
+        &mut self.sparkline_groups
+    }
+
+    pub fn set_sparkline_groups(&mut self, value: SparklineGroups) -> &mut Self {
+        self.sparkline_groups = Some(value);
+        self
+    }
+
+    pub fn remove_sparkline_groups(&mut self) -> &mut Self {
+        self.sparkline_groups = None;
+        self
+    }
+
+    pub fn add_sparkline_group(&mut self, value: SparklineGroup) -> &mut Self {
+        self.sparkline_groups
+            .get_or_insert(SparklineGroups::default())
+            .add_sparkline_group_list(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Keep an element that this crate does not model, as read.
+    pub(crate) fn add_unknown_element<S: Into<String>>(&mut self, name: S, value: S) -> &mut Self {
+        self.unknown_elements.push((name.into(), value.into()));
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the kept elements with the given name, in the order they were read.
+    pub(crate) fn get_unknown_elements(&self, name: &str) -> Vec<&str> {
+        self.unknown_elements
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// (This method is crate only.)
+    /// Keep an extension (ext in extLst) that this crate does not model, as read.
+    pub(crate) fn add_unknown_extension<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.unknown_extensions.push(value.into());
+        self
+    }
+
+    pub(crate) fn get_unknown_extensions(&self) -> &Vec<String> {
+        &self.unknown_extensions
+    }
+
+    /// (This method is crate only.)
+    /// Keep a namespace declared on the worksheet element, so that kept elements stay valid.
+    pub(crate) fn add_namespace<S: Into<String>>(&mut self, key: S, value: S) -> &mut Self {
+        self.namespaces.push((key.into(), value.into()));
+        self
+    }
+
+    pub(crate) fn get_namespaces(&self) -> &Vec<(String, String)> {
+        &self.namespaces
+    }
+
     pub fn get_sheet_format_properties(&self) -> &SheetFormatProperties {
         &self.sheet_format_properties
     }
@@ -1686,6 +2688,44 @@ impl Worksheet {
         self.get_worksheet_drawing_mut().get_charts_mut(&col, &row)
     }
 
+    /// Outputs all Office 2016 Charts contained in the worksheet.
+    /// # Return value
+    /// * `&Vec<ChartEx>` - Chart Object List.
+    pub fn get_chart_ex_collection(&self) -> &Vec<ChartEx> {
+        self.get_worksheet_drawing().get_chart_ex_collection()
+    }
+
+    /// Outputs all Office 2016 Charts contained in the worksheet.
+    /// # Return value
+    /// * `&mut Vec<ChartEx>` - Chart Object List.
+    pub fn get_chart_ex_collection_mut(&mut self) -> &mut Vec<ChartEx> {
+        self.get_worksheet_drawing_mut()
+            .get_chart_ex_collection_mut()
+    }
+
+    pub fn add_chart_ex(&mut self, value: ChartEx) -> &mut Self {
+        self.get_worksheet_drawing_mut()
+            .add_chart_ex_collection(value);
+        self
+    }
+
+    pub fn get_chart_ex<T>(&self, coordinate: T) -> Option<&ChartEx>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing().get_chart_ex(&col, &row)
+    }
+
+    pub fn get_chart_ex_mut<T>(&mut self, coordinate: T) -> Option<&mut ChartEx>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing_mut()
+            .get_chart_ex_mut(&col, &row)
+    }
+
     /// Outputs all media contained in the worksheet.
     /// # Return value
     /// * `Vec<&MediaObject>` - Media Object List.
@@ -1735,6 +2775,20 @@ impl Worksheet {
         result
     }
 
+    /// (This method is crate only.)
+    /// Whether the workbook uses the 1904 date system.
+    pub(crate) fn get_date1904(&self) -> &bool {
+        &self.date1904
+    }
+
+    /// (This method is crate only.)
+    /// Set the date system of the workbook. The value follows Spreadsheet::set_date1904.
+    pub(crate) fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        self.cell_collection.set_date1904(value);
+        self
+    }
+
     /// (This method is crate only.)
     /// Has Defined Names.
     pub(crate) fn has_defined_names(&self) -> bool {
@@ -129,6 +129,22 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         }
     }
 
+    pub(crate) fn add_file_at_threaded_comment(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("xl/threadedComments/threadedComment{}.xml", index);
+            let is_match = self.check_file_exist(&file_path);
+            if !is_match {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_chart(
         &mut self,
         writer: Writer<Cursor<Vec<u8>>>,
@@ -145,6 +161,22 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         }
     }
 
+    pub(crate) fn add_file_at_chart_ex(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("xl/charts/chartEx{}.xml", index);
+            let is_match = self.check_file_exist(&file_path);
+            if !is_match {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_ole_object(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
         let mut index = 0;
         loop {
@@ -229,6 +261,12 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                     "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml";
             }
 
+            // Override chartsheet
+            if file.starts_with("/xl/chartsheets/sheet") {
+                content_type =
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml";
+            }
+
             // Override table
             if file.starts_with("/xl/tables/table") {
                 content_type =
@@ -241,6 +279,28 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                     "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml";
             }
 
+            // Override threadedComments
+            if file.starts_with("/xl/threadedComments/threadedComment") {
+                content_type = "application/vnd.ms-excel.threadedcomments+xml";
+            }
+
+            // Override persons
+            if file.starts_with("/xl/persons/person") {
+                content_type = "application/vnd.ms-excel.person+xml";
+            }
+
+            // Override externalLinks
+            if file.starts_with("/xl/externalLinks/externalLink") {
+                content_type =
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml";
+            }
+
+            // Override metadata
+            if file.starts_with("/xl/metadata") {
+                content_type =
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml";
+            }
+
             // Override theme
             if file.starts_with("/xl/theme/theme") {
                 content_type = "application/vnd.openxmlformats-officedocument.theme+xml";
@@ -268,6 +328,21 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 content_type = "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
             }
 
+            // Override chartEx
+            if file.starts_with("/xl/charts/chartEx") {
+                content_type = "application/vnd.ms-office.chartex+xml";
+            }
+
+            // Override chart style
+            if file.starts_with("/xl/charts/style") {
+                content_type = "application/vnd.ms-office.chartstyle+xml";
+            }
+
+            // Override chart colors
+            if file.starts_with("/xl/charts/colors") {
+                content_type = "application/vnd.ms-office.chartcolorstyle+xml";
+            }
+
             // Override embeddings
             if file.starts_with("/xl/embeddings/oleObject") {
                 content_type = "application/vnd.openxmlformats-officedocument.oleObject";
@@ -289,6 +364,12 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                     "application/vnd.openxmlformats-officedocument.extended-properties+xml";
             }
 
+            // Override docProps/custom
+            if file.starts_with("/docProps/custom.xml") {
+                content_type =
+                    "application/vnd.openxmlformats-officedocument.custom-properties+xml";
+            }
+
             // Override Unsupported
             if content_type.is_empty() {
                 for (old_part_name, old_content_type) in spreadsheet.get_backup_context_types() {
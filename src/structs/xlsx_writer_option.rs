@@ -0,0 +1,27 @@
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct XlsxWriterOption {
+    is_light: bool,
+    compact_styles: bool,
+}
+impl XlsxWriterOption {
+    pub fn get_is_light(&self) -> &bool {
+        &self.is_light
+    }
+
+    /// Write the file without the compression.
+    pub fn set_is_light(&mut self, value: bool) -> &mut Self {
+        self.is_light = value;
+        self
+    }
+
+    pub fn get_compact_styles(&self) -> &bool {
+        &self.compact_styles
+    }
+
+    /// Deduplicate the styles and drop the styles not used by the cells, rows and columns.
+    /// It is skipped if the workbook has a worksheet which is not deserialized.
+    pub fn set_compact_styles(&mut self, value: bool) -> &mut Self {
+        self.compact_styles = value;
+        self
+    }
+}
@@ -10,22 +10,29 @@ use std::path::Path;
 use std::string::FromUtf8Error;
 use structs::Spreadsheet;
 use structs::WriterManager;
+use structs::XlsxWriterOption;
 
 mod chart;
+mod chartsheet;
 mod comment;
 mod content_types;
 mod doc_props_app;
 mod doc_props_core;
+mod doc_props_custom;
 mod drawing;
 mod drawing_rels;
 mod embeddings;
+mod external_link;
 mod media;
+mod metadata;
+mod person;
 mod printer_settings;
 mod rels;
 mod shared_strings;
 mod styles;
 mod table;
 mod theme;
+mod threaded_comment;
 mod vba_project_bin;
 mod vml_drawing;
 mod vml_drawing_rels;
@@ -80,10 +87,13 @@ impl fmt::Display for XlsxError {
 
 impl Error for XlsxError {}
 
-fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Vec<u8>, XlsxError> {
+fn make_buffer(
+    spreadsheet: &Spreadsheet,
+    option: &XlsxWriterOption,
+) -> Result<std::vec::Vec<u8>, XlsxError> {
     let arv = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
     let mut writer_manager = WriterManager::new(arv);
-    writer_manager.set_is_light(is_light);
+    writer_manager.set_is_light(*option.get_is_light());
 
     // Add docProps App
     doc_props_app::write(spreadsheet, &mut writer_manager)?;
@@ -91,6 +101,9 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // Add docProps Core
     doc_props_core::write(spreadsheet, &mut writer_manager)?;
 
+    // Add docProps Custom
+    doc_props_custom::write(spreadsheet, &mut writer_manager)?;
+
     // Add vbaProject.bin
     vba_project_bin::write(spreadsheet, &mut writer_manager)?;
 
@@ -102,7 +115,19 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
 
     // worksheet
     let shared_string_table = spreadsheet.get_shared_string_table();
-    let mut stylesheet = spreadsheet.get_stylesheet().clone();
+    let is_compactable = spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .all(|v| v.is_deserialized());
+    let mut stylesheet = if *option.get_compact_styles() && is_compactable {
+        // the pivot tables refer the dxfs by index.
+        let keep_differential_formats = !spreadsheet.get_pivot_caches().is_empty();
+        spreadsheet
+            .get_stylesheet()
+            .get_compacted(keep_differential_formats)
+    } else {
+        spreadsheet.get_stylesheet().clone()
+    };
     let mut worksheet_no = 1;
     for worksheet in spreadsheet.get_sheet_collection_no_check() {
         match worksheet.is_deserialized() {
@@ -143,12 +168,25 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
                     let chart_no = chart::write(chart_space, spreadsheet, &mut writer_manager)?;
                     chart_no_list.push(chart_no);
                 }
+                let mut chart_ex_no_list: Vec<String> = Vec::new();
+                for chart in worksheet.get_worksheet_drawing().get_chart_ex_collection() {
+                    let chart_space = chart.get_chart_space();
+                    let chart_no =
+                        chart::write_chart_ex(chart_space, spreadsheet, &mut writer_manager)?;
+                    chart_ex_no_list.push(chart_no);
+                }
 
                 // Add drawing
                 let drawing_no = drawing::write(worksheet, &mut writer_manager)?;
 
                 // Add drawing rels
-                drawing_rels::write(worksheet, &drawing_no, &chart_no_list, &mut writer_manager)?;
+                drawing_rels::write(
+                    worksheet,
+                    &drawing_no,
+                    &chart_no_list,
+                    &chart_ex_no_list,
+                    &mut writer_manager,
+                )?;
 
                 // Add vml drawing
                 let vml_drawing_no = vml_drawing::write(worksheet, &mut writer_manager)?;
@@ -159,6 +197,9 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
                 // Add comment
                 let comment_no = comment::write(worksheet, &mut writer_manager)?;
 
+                // Add threaded comment
+                let threaded_comment_no = threaded_comment::write(worksheet, &mut writer_manager)?;
+
                 // Add ole_object and excel
                 let (ole_object_no_list, excel_no_list) =
                     embeddings::write(worksheet, &mut writer_manager)?;
@@ -182,6 +223,7 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
                     &drawing_no,
                     &vml_drawing_no,
                     &comment_no,
+                    &threaded_comment_no,
                     &ole_object_no_list,
                     &excel_no_list,
                     &printer_settings_no,
@@ -193,9 +235,43 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         worksheet_no += 1;
     }
 
+    // chartsheet
+    let mut chart_sheet_no: i32 = 1;
+    for chart_sheet in spreadsheet.get_chart_sheet_collection() {
+        // Add chart
+        let chart_no = match chart_sheet.get_chart_space() {
+            Some(v) => chart::write(v, spreadsheet, &mut writer_manager)?,
+            None => String::from(""),
+        };
+
+        // Add drawing
+        let drawing_no = drawing::write_chart_sheet(chart_sheet, &mut writer_manager)?;
+
+        // Add drawing rels
+        drawing_rels::write_chart_sheet(&drawing_no, &chart_no, &mut writer_manager)?;
+
+        // Add chartsheet and chartsheet rels
+        chartsheet::write(
+            &chart_sheet_no,
+            chart_sheet,
+            &drawing_no,
+            &mut writer_manager,
+        )?;
+        chart_sheet_no += 1;
+    }
+
     // file list sort
     writer_manager.file_list_sort();
 
+    // Add persons
+    person::write(spreadsheet, &mut writer_manager)?;
+
+    // Add external links
+    external_link::write(spreadsheet, &mut writer_manager)?;
+
+    // Add metadata
+    metadata::write(spreadsheet, &mut writer_manager)?;
+
     // Add SharedStrings
     shared_strings::write(shared_string_table.clone(), &mut writer_manager)?;
 
@@ -226,7 +302,7 @@ pub fn write_writer<W: io::Write>(
     spreadsheet: &Spreadsheet,
     mut writer: W,
 ) -> Result<(), XlsxError> {
-    let buffer = make_buffer(spreadsheet, false)?;
+    let buffer = make_buffer(spreadsheet, &XlsxWriterOption::default())?;
     writer.write_all(&buffer)?;
     Ok(())
 }
@@ -241,7 +317,26 @@ pub fn write_writer_light<W: io::Write>(
     spreadsheet: &Spreadsheet,
     mut writer: W,
 ) -> Result<(), XlsxError> {
-    let buffer = make_buffer(spreadsheet, true)?;
+    let mut option = XlsxWriterOption::default();
+    option.set_is_light(true);
+    let buffer = make_buffer(spreadsheet, &option)?;
+    writer.write_all(&buffer)?;
+    Ok(())
+}
+
+/// write spreadsheet file to arbitrary writer with the option.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer to write to.
+/// * `option` - the option of the writer.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer_with_option<W: io::Write>(
+    spreadsheet: &Spreadsheet,
+    mut writer: W,
+    option: &XlsxWriterOption,
+) -> Result<(), XlsxError> {
+    let buffer = make_buffer(spreadsheet, option)?;
     writer.write_all(&buffer)?;
     Ok(())
 }
@@ -308,6 +403,45 @@ pub fn write_light<P: AsRef<Path>>(spreadsheet: &Spreadsheet, path: P) -> Result
     Ok(())
 }
 
+/// write spreadsheet file with the option.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// * `option` - the option of the writer.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.xlsx");
+/// let mut option = umya_spreadsheet::XlsxWriterOption::default();
+/// option.set_compact_styles(true);
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+/// ```
+pub fn write_with_option<P: AsRef<Path>>(
+    spreadsheet: &Spreadsheet,
+    path: P,
+    option: &XlsxWriterOption,
+) -> Result<(), XlsxError> {
+    let extension = path.as_ref().extension().unwrap().to_str().unwrap();
+    let path_tmp = path
+        .as_ref()
+        .with_extension(format!("{}{}", extension, "tmp"));
+    match write_writer_with_option(
+        spreadsheet,
+        &mut io::BufWriter::new(fs::File::create(&path_tmp)?),
+        option,
+    ) {
+        Ok(_) => {}
+        Err(v) => {
+            fs::remove_file(path_tmp)?;
+            return Err(v);
+        }
+    }
+    fs::rename(path_tmp, path)?;
+    Ok(())
+}
+
 /// write spreadsheet file with password.
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
@@ -330,7 +464,7 @@ pub fn write_with_password<P: AsRef<Path>>(
     let path_tmp = path
         .as_ref()
         .with_extension(format!("{}{}", extension, "tmp"));
-    let buffer = match make_buffer(spreadsheet, false) {
+    let buffer = match make_buffer(spreadsheet, &XlsxWriterOption::default()) {
         Ok(v) => v,
         Err(v) => {
             fs::remove_file(path_tmp)?;
@@ -367,7 +501,9 @@ pub fn write_with_password_light<P: AsRef<Path>>(
     let path_tmp = path
         .as_ref()
         .with_extension(format!("{}{}", extension, "tmp"));
-    let buffer = match make_buffer(spreadsheet, true) {
+    let mut option = XlsxWriterOption::default();
+    option.set_is_light(true);
+    let buffer = match make_buffer(spreadsheet, &option) {
         Ok(v) => v,
         Err(v) => {
             fs::remove_file(path_tmp)?;
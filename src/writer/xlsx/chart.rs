@@ -3,6 +3,7 @@ use super::XlsxError;
 use quick_xml::events::{BytesDecl, Event};
 use quick_xml::Writer;
 use std::io;
+use structs::drawing::chart_ex::ChartSpace as ChartExSpace;
 use structs::drawing::charts::ChartSpace;
 use structs::Spreadsheet;
 use structs::WriterManager;
@@ -25,5 +26,138 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     chart_space.write_to(&mut writer, spreadsheet);
 
     let file_no = writer_mng.add_file_at_chart(writer)?;
+
+    // Office 2013+ style and colors parts
+    write_style_parts(
+        chart_space.get_chart_style_xml(),
+        chart_space.get_chart_color_style_xml(),
+        &format!("xl/charts/_rels/chart{}.xml.rels", file_no),
+        writer_mng,
+    )?;
+
+    Ok(file_no.to_string())
+}
+
+pub(crate) fn write_chart_ex<W: io::Seek + io::Write>(
+    chart_space: &ChartExSpace,
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<String, XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // cx:chartSpace
+    chart_space.write_to(&mut writer, spreadsheet);
+
+    let file_no = writer_mng.add_file_at_chart_ex(writer)?;
+
+    // Office 2013+ style and colors parts
+    write_style_parts(
+        chart_space.get_chart_style_xml(),
+        chart_space.get_chart_color_style_xml(),
+        &format!("xl/charts/_rels/chartEx{}.xml.rels", file_no),
+        writer_mng,
+    )?;
+
     Ok(file_no.to_string())
 }
+
+fn write_style_parts<W: io::Seek + io::Write>(
+    chart_style_xml: &Option<String>,
+    chart_color_style_xml: &Option<String>,
+    file_path: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if chart_style_xml.is_none() && chart_color_style_xml.is_none() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![(
+            "xmlns",
+            "http://schemas.openxmlformats.org/package/2006/relationships",
+        )],
+        false,
+    );
+
+    let mut r_id = 1;
+    if let Some(v) = chart_style_xml {
+        let target = make_free_target(writer_mng, "style");
+        writer_mng.add_bin(&format!("xl/charts/{}", target), v.as_bytes())?;
+        write_relationship(
+            &mut writer,
+            &r_id,
+            "http://schemas.microsoft.com/office/2011/relationships/chartStyle",
+            &target,
+            "",
+        );
+        r_id += 1;
+    }
+    if let Some(v) = chart_color_style_xml {
+        let target = make_free_target(writer_mng, "colors");
+        writer_mng.add_bin(&format!("xl/charts/{}", target), v.as_bytes())?;
+        write_relationship(
+            &mut writer,
+            &r_id,
+            "http://schemas.microsoft.com/office/2011/relationships/chartColorStyle",
+            &target,
+            "",
+        );
+    }
+
+    write_end_tag(&mut writer, "Relationships");
+
+    writer_mng.add_writer(file_path, writer)
+}
+
+fn make_free_target<W: io::Seek + io::Write>(
+    writer_mng: &mut WriterManager<W>,
+    prefix: &str,
+) -> String {
+    let mut index = 0;
+    loop {
+        index += 1;
+        let target = format!("{}{}.xml", prefix, index);
+        if !writer_mng.check_file_exist(&format!("xl/charts/{}", target)) {
+            return target;
+        }
+    }
+}
+
+fn write_relationship(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    r_id: &i32,
+    p_type: &str,
+    p_target: &str,
+    p_target_mode: &str,
+) -> bool {
+    let tag_name = "Relationship";
+    let r_id_str = format!("rId{}", r_id);
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    attributes.push(("Id", &r_id_str));
+    attributes.push(("Type", p_type));
+    attributes.push(("Target", p_target));
+    if !p_target_mode.is_empty() {
+        attributes.push(("TargetMode", p_target_mode));
+    }
+    write_start_tag(writer, tag_name, attributes, true);
+    true
+}
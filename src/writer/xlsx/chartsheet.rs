@@ -0,0 +1,118 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::driver::*;
+use super::XlsxError;
+use structs::Chartsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    chart_sheet_no: &i32,
+    chart_sheet: &Chartsheet,
+    drawing_no: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // chartsheet
+    write_start_tag(
+        &mut writer,
+        "chartsheet",
+        vec![
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+        ],
+        false,
+    );
+
+    // sheetPr
+    if let Some(v) = chart_sheet.get_tab_color() {
+        write_start_tag(&mut writer, "sheetPr", vec![], false);
+        v.write_to_tab_color(&mut writer);
+        write_end_tag(&mut writer, "sheetPr");
+    }
+
+    // sheetViews
+    write_start_tag(&mut writer, "sheetViews", vec![], false);
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    let zoom_scale = chart_sheet.get_zoom_scale().to_string();
+    if chart_sheet.get_zoom_scale() != &0 {
+        attributes.push(("zoomScale", &zoom_scale));
+    }
+    attributes.push(("workbookViewId", "0"));
+    if *chart_sheet.get_zoom_to_fit() {
+        attributes.push(("zoomToFit", "1"));
+    }
+    write_start_tag(&mut writer, "sheetView", attributes, true);
+    write_end_tag(&mut writer, "sheetViews");
+
+    // pageMargins
+    chart_sheet.get_page_margins().write_to(&mut writer);
+
+    // drawing
+    if !drawing_no.is_empty() {
+        write_start_tag(&mut writer, "drawing", vec![("r:id", "rId1")], true);
+    }
+
+    write_end_tag(&mut writer, "chartsheet");
+
+    let target = format!("xl/chartsheets/sheet{}.xml", chart_sheet_no);
+    writer_mng.add_writer(&target, writer)?;
+
+    // relationships
+    if drawing_no.is_empty() {
+        return Ok(());
+    }
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![(
+            "xmlns",
+            "http://schemas.openxmlformats.org/package/2006/relationships",
+        )],
+        false,
+    );
+    write_start_tag(
+        &mut writer,
+        "Relationship",
+        vec![
+            ("Id", "rId1"),
+            (
+                "Type",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing",
+            ),
+            (
+                "Target",
+                format!("../drawings/drawing{}.xml", drawing_no).as_str(),
+            ),
+        ],
+        true,
+    );
+    write_end_tag(&mut writer, "Relationships");
+
+    let target = format!("xl/chartsheets/_rels/sheet{}.xml.rels", chart_sheet_no);
+    writer_mng.add_writer(&target, writer)
+}
@@ -58,7 +58,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
 
         // text
-        comment.get_text().write_to_text(&mut writer);
+        match worksheet.get_threaded_comment_placeholder(comment) {
+            Some(v) => v.write_to_text(&mut writer),
+            None => comment.get_text().write_to_text(&mut writer),
+        }
 
         write_end_tag(&mut writer, "comment");
     }
@@ -37,54 +37,59 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         false,
     );
 
+    let app_properties = spreadsheet.get_app_properties();
+    let bool_str = |value: &bool| if *value { "true" } else { "false" };
+
     // Application
     write_start_tag(&mut writer, "Application", vec![], false);
-    write_text_node(&mut writer, "Microsoft Excel");
+    write_text_node(&mut writer, app_properties.get_application());
     write_end_tag(&mut writer, "Application");
 
     // DocSecurity
     write_start_tag(&mut writer, "DocSecurity", vec![], false);
-    write_text_node(&mut writer, "0");
+    write_text_node(&mut writer, app_properties.get_doc_security().to_string());
     write_end_tag(&mut writer, "DocSecurity");
 
     // ScaleCrop
     write_start_tag(&mut writer, "ScaleCrop", vec![], false);
-    write_text_node(&mut writer, "false");
+    write_text_node(&mut writer, bool_str(app_properties.get_scale_crop()));
     write_end_tag(&mut writer, "ScaleCrop");
 
+    let titles_of_parts = spreadsheet.get_titles_of_parts();
+
     // HeadingPairs
     write_start_tag(&mut writer, "HeadingPairs", vec![], false);
 
     // vt:vector
+    let heading_pairs_size = (titles_of_parts.len() * 2).to_string();
     write_start_tag(
         &mut writer,
         "vt:vector",
-        vec![("size", "2"), ("baseType", "variant")],
+        vec![("size", &heading_pairs_size), ("baseType", "variant")],
         false,
     );
 
-    // vt:variant
-    write_start_tag(&mut writer, "vt:variant", vec![], false);
+    for (heading, parts) in &titles_of_parts {
+        // vt:variant
+        write_start_tag(&mut writer, "vt:variant", vec![], false);
 
-    // vt:i4
-    write_start_tag(&mut writer, "vt:lpstr", vec![], false);
-    write_text_node(&mut writer, "Worksheets");
-    write_end_tag(&mut writer, "vt:lpstr");
+        // vt:lpstr
+        write_start_tag(&mut writer, "vt:lpstr", vec![], false);
+        write_text_node(&mut writer, *heading);
+        write_end_tag(&mut writer, "vt:lpstr");
 
-    write_end_tag(&mut writer, "vt:variant");
+        write_end_tag(&mut writer, "vt:variant");
 
-    // vt:variant
-    write_start_tag(&mut writer, "vt:variant", vec![], false);
+        // vt:variant
+        write_start_tag(&mut writer, "vt:variant", vec![], false);
 
-    // vt:i4
-    write_start_tag(&mut writer, "vt:i4", vec![], false);
-    write_text_node(
-        &mut writer,
-        spreadsheet.get_sheet_count().to_string().as_str(),
-    );
-    write_end_tag(&mut writer, "vt:i4");
+        // vt:i4
+        write_start_tag(&mut writer, "vt:i4", vec![], false);
+        write_text_node(&mut writer, parts.len().to_string());
+        write_end_tag(&mut writer, "vt:i4");
 
-    write_end_tag(&mut writer, "vt:variant");
+        write_end_tag(&mut writer, "vt:variant");
+    }
 
     write_end_tag(&mut writer, "vt:vector");
 
@@ -94,20 +99,22 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_start_tag(&mut writer, "TitlesOfParts", vec![], false);
 
     // vt:vector
+    let titles_of_parts_size = titles_of_parts
+        .iter()
+        .map(|(_, parts)| parts.len())
+        .sum::<usize>()
+        .to_string();
     write_start_tag(
         &mut writer,
         "vt:vector",
-        vec![
-            ("size", spreadsheet.get_sheet_count().to_string().as_str()),
-            ("baseType", "lpstr"),
-        ],
+        vec![("size", &titles_of_parts_size), ("baseType", "lpstr")],
         false,
     );
 
-    for workseet in spreadsheet.get_sheet_collection_no_check() {
+    for part in titles_of_parts.iter().flat_map(|(_, parts)| parts) {
         // vt:lpstr
         write_start_tag(&mut writer, "vt:lpstr", vec![], false);
-        write_text_node(&mut writer, workseet.get_name());
+        write_text_node(&mut writer, part);
         write_end_tag(&mut writer, "vt:lpstr");
     }
 
@@ -115,34 +122,55 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     write_end_tag(&mut writer, "TitlesOfParts");
 
+    // the deprecated values of Properties are used when AppProperties has none.
+    let properties = spreadsheet.get_properties();
+    let manager = match app_properties.get_manager() {
+        "" => properties.get_manager(),
+        v => v,
+    };
+    let company = match app_properties.get_company() {
+        "" => properties.get_company(),
+        v => v,
+    };
+
     // Manager
     write_start_tag(&mut writer, "Manager", vec![], false);
-    write_text_node(&mut writer, spreadsheet.get_properties().get_manager());
+    write_text_node(&mut writer, manager);
     write_end_tag(&mut writer, "Manager");
 
     // Company
     write_start_tag(&mut writer, "Company", vec![], false);
-    write_text_node(&mut writer, spreadsheet.get_properties().get_company());
+    write_text_node(&mut writer, company);
     write_end_tag(&mut writer, "Company");
 
     // LinksUpToDate
     write_start_tag(&mut writer, "LinksUpToDate", vec![], false);
-    write_text_node(&mut writer, "false");
+    write_text_node(&mut writer, bool_str(app_properties.get_links_up_to_date()));
     write_end_tag(&mut writer, "LinksUpToDate");
 
     // SharedDoc
     write_start_tag(&mut writer, "SharedDoc", vec![], false);
-    write_text_node(&mut writer, "false");
+    write_text_node(&mut writer, bool_str(app_properties.get_shared_doc()));
     write_end_tag(&mut writer, "SharedDoc");
 
+    // HyperlinkBase
+    if !app_properties.get_hyperlink_base().is_empty() {
+        write_start_tag(&mut writer, "HyperlinkBase", vec![], false);
+        write_text_node(&mut writer, app_properties.get_hyperlink_base());
+        write_end_tag(&mut writer, "HyperlinkBase");
+    }
+
     // HyperlinksChanged
     write_start_tag(&mut writer, "HyperlinksChanged", vec![], false);
-    write_text_node(&mut writer, "false");
+    write_text_node(
+        &mut writer,
+        bool_str(app_properties.get_hyperlinks_changed()),
+    );
     write_end_tag(&mut writer, "HyperlinksChanged");
 
     // AppVersion
     write_start_tag(&mut writer, "AppVersion", vec![], false);
-    write_text_node(&mut writer, "14.0300");
+    write_text_node(&mut writer, app_properties.get_app_version());
     write_end_tag(&mut writer, "AppVersion");
 
     write_end_tag(&mut writer, "Properties");
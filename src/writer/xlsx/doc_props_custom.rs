@@ -0,0 +1,67 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::driver::*;
+use super::XlsxError;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let custom_properties = spreadsheet.get_properties().get_custom_properties();
+    if custom_properties.is_empty() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // Properties
+    write_start_tag(
+        &mut writer,
+        "Properties",
+        vec![
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
+            ),
+            (
+                "xmlns:vt",
+                "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
+            ),
+        ],
+        false,
+    );
+
+    // property
+    let mut pid = custom_properties
+        .iter()
+        .map(|v| *v.get_pid())
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    for property in custom_properties {
+        let property_pid = match property.get_pid() {
+            0 => {
+                pid += 1;
+                pid
+            }
+            v => *v,
+        };
+        property.write_to(&mut writer, &property_pid);
+    }
+
+    write_end_tag(&mut writer, "Properties");
+
+    let target = "docProps/custom.xml";
+    writer_mng.add_writer(target, writer)
+}
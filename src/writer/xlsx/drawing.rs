@@ -4,6 +4,7 @@ use std::io;
 
 use super::driver::*;
 use super::XlsxError;
+use structs::Chartsheet;
 use structs::Worksheet;
 use structs::WriterManager;
 
@@ -31,3 +32,45 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let file_no = writer_mng.add_file_at_drawing(writer)?;
     Ok(file_no.to_string())
 }
+
+pub(crate) fn write_chart_sheet<W: io::Seek + io::Write>(
+    chart_sheet: &Chartsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<String, XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // xdr:wsDr
+    write_start_tag(
+        &mut writer,
+        "xdr:wsDr",
+        vec![
+            (
+                "xmlns:xdr",
+                "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+            ),
+            (
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+        ],
+        false,
+    );
+
+    // xdr:absoluteAnchor
+    let mut r_id = 1;
+    chart_sheet
+        .get_absolute_anchor()
+        .write_to(&mut writer, &mut r_id);
+
+    write_end_tag(&mut writer, "xdr:wsDr");
+
+    let file_no = writer_mng.add_file_at_drawing(writer)?;
+    Ok(file_no.to_string())
+}
@@ -11,6 +11,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
     drawing_no: &str,
     chart_no_list: &Vec<String>,
+    chart_ex_no_list: &Vec<String>,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
@@ -46,6 +47,16 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
         r_id += 1;
     }
+    for chart_no in chart_ex_no_list {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id,
+            "http://schemas.microsoft.com/office/2014/relationships/chartEx",
+            format!("../charts/chartEx{}.xml", chart_no).as_str(),
+            "",
+        );
+        r_id += 1;
+    }
     for image in worksheet.get_media_object_collection() {
         is_write = write_relationship(
             &mut writer,
@@ -65,6 +76,49 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     Ok(())
 }
 
+pub(crate) fn write_chart_sheet<W: io::Seek + io::Write>(
+    drawing_no: &str,
+    chart_no: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if chart_no.is_empty() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![(
+            "xmlns",
+            "http://schemas.openxmlformats.org/package/2006/relationships",
+        )],
+        false,
+    );
+
+    write_relationship(
+        &mut writer,
+        &1,
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+        format!("../charts/chart{}.xml", chart_no).as_str(),
+        "",
+    );
+
+    write_end_tag(&mut writer, "Relationships");
+
+    let file_path = format!("xl/drawings/_rels/drawing{}.xml.rels", drawing_no);
+    writer_mng.add_writer(&file_path, writer)
+}
+
 fn write_relationship(
     writer: &mut Writer<io::Cursor<Vec<u8>>>,
     r_id: &i32,
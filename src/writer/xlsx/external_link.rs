@@ -0,0 +1,97 @@
+use super::driver::*;
+use super::XlsxError;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+use structs::ExternalLink;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut external_link_no = 1;
+    for external_link in spreadsheet.get_external_links() {
+        let file_path = format!("xl/externalLinks/externalLink{}.xml", external_link_no);
+        if let Some((file, relationships)) = external_link.get_raw_data() {
+            // DDE and OLE links are written as read.
+            writer_mng.add_bin(&file_path, file.get_file_data())?;
+            let rels_path = format!(
+                "xl/externalLinks/_rels/externalLink{}.xml.rels",
+                external_link_no
+            );
+            relationships.write_to(writer_mng, Some(&rels_path))?;
+            external_link_no += 1;
+            continue;
+        }
+
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        // XML header
+        let _ = writer.write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )));
+        write_new_line(&mut writer);
+
+        // externalLink
+        external_link.write_to(&mut writer);
+
+        writer_mng.add_writer(&file_path, writer)?;
+
+        write_rels(external_link, &external_link_no, writer_mng)?;
+        external_link_no += 1;
+    }
+    Ok(())
+}
+
+fn write_rels<W: io::Seek + io::Write>(
+    external_link: &ExternalLink,
+    external_link_no: &i32,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![(
+            "xmlns",
+            "http://schemas.openxmlformats.org/package/2006/relationships",
+        )],
+        false,
+    );
+
+    // relationship externalLinkPath
+    write_start_tag(
+        &mut writer,
+        "Relationship",
+        vec![
+            ("Id", "rId1"),
+            (
+                "Type",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLinkPath",
+            ),
+            ("Target", external_link.get_target()),
+            ("TargetMode", "External"),
+        ],
+        true,
+    );
+
+    write_end_tag(&mut writer, "Relationships");
+
+    let file_path = format!(
+        "xl/externalLinks/_rels/externalLink{}.xml.rels",
+        external_link_no
+    );
+    writer_mng.add_writer(&file_path, writer)
+}
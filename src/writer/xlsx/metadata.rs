@@ -0,0 +1,104 @@
+use super::driver::*;
+use super::XlsxError;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if !spreadsheet.has_dynamic_array_formula() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // metadata
+    write_start_tag(
+        &mut writer,
+        "metadata",
+        vec![
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:xda",
+                "http://schemas.microsoft.com/office/spreadsheetml/2017/dynamicarray",
+            ),
+        ],
+        false,
+    );
+
+    // metadataTypes
+    write_start_tag(&mut writer, "metadataTypes", vec![("count", "1")], false);
+    write_start_tag(
+        &mut writer,
+        "metadataType",
+        vec![
+            ("name", "XLDAPR"),
+            ("minSupportedVersion", "120000"),
+            ("copy", "1"),
+            ("pasteAll", "1"),
+            ("pasteValues", "1"),
+            ("merge", "1"),
+            ("splitFirst", "1"),
+            ("rowColShift", "1"),
+            ("clearFormats", "1"),
+            ("clearComments", "1"),
+            ("assign", "1"),
+            ("coerce", "1"),
+            ("cellMeta", "1"),
+        ],
+        true,
+    );
+    write_end_tag(&mut writer, "metadataTypes");
+
+    // futureMetadata
+    write_start_tag(
+        &mut writer,
+        "futureMetadata",
+        vec![("name", "XLDAPR"), ("count", "1")],
+        false,
+    );
+    write_start_tag(&mut writer, "bk", vec![], false);
+    write_start_tag(&mut writer, "extLst", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "ext",
+        vec![("uri", "{bdbb8cdc-fa1e-496e-a857-3c3f30c029c3}")],
+        false,
+    );
+    write_start_tag(
+        &mut writer,
+        "xda:dynamicArrayProperties",
+        vec![("fDynamic", "1"), ("fCollapsed", "0")],
+        true,
+    );
+    write_end_tag(&mut writer, "ext");
+    write_end_tag(&mut writer, "extLst");
+    write_end_tag(&mut writer, "bk");
+    write_end_tag(&mut writer, "futureMetadata");
+
+    // cellMetadata
+    write_start_tag(&mut writer, "cellMetadata", vec![("count", "1")], false);
+    write_start_tag(&mut writer, "bk", vec![], false);
+    write_start_tag(&mut writer, "rc", vec![("t", "1"), ("v", "0")], true);
+    write_end_tag(&mut writer, "bk");
+    write_end_tag(&mut writer, "cellMetadata");
+
+    write_end_tag(&mut writer, "metadata");
+
+    writer_mng.add_writer("xl/metadata.xml", writer)?;
+    Ok(())
+}
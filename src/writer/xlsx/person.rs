@@ -0,0 +1,52 @@
+use super::driver::*;
+use super::XlsxError;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if spreadsheet.get_person_collection().is_empty() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // personList
+    write_start_tag(
+        &mut writer,
+        "personList",
+        vec![
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ],
+        false,
+    );
+
+    // person
+    for person in spreadsheet.get_person_collection() {
+        person.write_to(&mut writer);
+    }
+
+    write_end_tag(&mut writer, "personList");
+
+    writer_mng.add_writer("xl/persons/person.xml", writer)?;
+    Ok(())
+}
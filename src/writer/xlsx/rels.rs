@@ -32,15 +32,19 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     );
 
     // relationship docProps/custom.xml
-    //if spreadsheet.get_properties().get_custom_properties().len() > 0 {
-    //    write_relationship(
-    //        &mut writer,
-    //        "4",
-    //        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
-    //        "docProps/custom.xml",
-    //        ""
-    //    );
-    //}
+    if !spreadsheet
+        .get_properties()
+        .get_custom_properties()
+        .is_empty()
+    {
+        write_relationship(
+            &mut writer,
+            "4",
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
+            "docProps/custom.xml",
+            "",
+        );
+    }
 
     // relationship docProps/app.xml
     write_relationship(
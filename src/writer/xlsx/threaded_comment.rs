@@ -0,0 +1,52 @@
+use super::driver::*;
+use super::XlsxError;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+use structs::Worksheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<String, XlsxError> {
+    if worksheet.get_threaded_comments().is_empty() {
+        return Ok(String::from(""));
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // ThreadedComments
+    write_start_tag(
+        &mut writer,
+        "ThreadedComments",
+        vec![
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ],
+        false,
+    );
+
+    // threadedComment
+    for threaded_comment in worksheet.get_threaded_comments() {
+        threaded_comment.write_to(&mut writer);
+    }
+
+    write_end_tag(&mut writer, "ThreadedComments");
+
+    let file_no = writer_mng.add_file_at_threaded_comment(writer)?;
+    Ok(file_no.to_string())
+}
@@ -4,6 +4,8 @@ use std::io;
 
 use super::driver::*;
 use super::XlsxError;
+use structs::DefinedName;
+use structs::SheetTab;
 use structs::Spreadsheet;
 use structs::WriterManager;
 
@@ -52,6 +54,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     // workbookPr
     let mut attributes: Vec<(&str, &str)> = Vec::new();
+    if *spreadsheet.get_date1904() {
+        attributes.push(("date1904", "1"));
+    }
     attributes.push(("filterPrivacy", "1"));
     //attributes.push(("defaultThemeVersion", "124226"));
     if spreadsheet.get_has_macros() {
@@ -79,14 +84,14 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_start_tag(&mut writer, "sheets", vec![], false);
 
     let mut index = 1;
-    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+    for sheet_tab in spreadsheet.get_sheet_tab_order() {
+        let name = match sheet_tab {
+            SheetTab::Worksheet(i) => spreadsheet.get_sheet_collection_no_check()[i].get_name(),
+            SheetTab::Chartsheet(i) => spreadsheet.get_chart_sheet_collection()[i].get_name(),
+        };
         let id = index.to_string();
         let r_id = format!("rId{}", index);
-        let attributes: Vec<(&str, &str)> = vec![
-            ("name", worksheet.get_name()),
-            ("sheetId", &id),
-            ("r:id", &r_id),
-        ];
+        let attributes: Vec<(&str, &str)> = vec![("name", name), ("sheetId", &id), ("r:id", &r_id)];
 
         // sheet
         write_start_tag(&mut writer, "sheet", attributes, true);
@@ -95,22 +100,43 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
     write_end_tag(&mut writer, "sheets");
 
+    // externalReferences
+    if !spreadsheet.get_external_links().is_empty() {
+        write_start_tag(&mut writer, "externalReferences", vec![], false);
+        for _ in spreadsheet.get_external_links() {
+            let r_id = format!("rId{}", index);
+            write_start_tag(
+                &mut writer,
+                "externalReference",
+                vec![("r:id", &r_id)],
+                true,
+            );
+            index += 1;
+        }
+        write_end_tag(&mut writer, "externalReferences");
+    }
+
     // definedNames
     if spreadsheet.has_defined_names() {
         write_start_tag(&mut writer, "definedNames", vec![], false);
 
-        for sheet in spreadsheet.get_sheet_collection_no_check() {
+        for defined_name in spreadsheet.get_defined_names() {
+            write_defined_name(&mut writer, defined_name, defined_name.get_local_sheet_id());
+        }
+        let sheet_tab_order = spreadsheet.get_sheet_tab_order();
+        for (index, sheet) in spreadsheet
+            .get_sheet_collection_no_check()
+            .iter()
+            .enumerate()
+        {
+            let index = sheet_tab_order
+                .iter()
+                .position(|v| v == &SheetTab::Worksheet(index))
+                .unwrap();
             for defined_name in sheet.get_defined_names() {
-                // definedName
-                let mut attributes: Vec<(&str, &str)> = Vec::new();
-                attributes.push(("name", defined_name.get_name()));
-                if defined_name.get_is_local_only() == &true {
-                    attributes.push(("localSheetId", "0"));
-                    attributes.push(("hidden", "1"));
-                }
-                write_start_tag(&mut writer, "definedName", attributes, false);
-                write_text_node_no_escape(&mut writer, defined_name.get_address_str());
-                write_end_tag(&mut writer, "definedName");
+                // the scope follows the current position of the sheet.
+                let local_sheet_id = defined_name.get_local_sheet_id().map(|_| index as u32);
+                write_defined_name(&mut writer, defined_name, local_sheet_id);
             }
         }
 
@@ -118,19 +144,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
 
     // calcPr
-    write_start_tag(
-        &mut writer,
-        "calcPr",
-        vec![
-            ("calcId", "122211"),
-            //("calcId", "999999"),
-            //("calcMode", "auto"),
-            //("calcCompleted", if recalc_required {"1"} else {"0"}),
-            //("fullCalcOnLoad", if recalc_required {"0"} else {"1"}),
-            //("forceFullCalc", if recalc_required {"0"} else {"1"}),
-        ],
-        true,
-    );
+    spreadsheet
+        .get_calculation_properties()
+        .write_to(&mut writer);
 
     // pivotCaches
     let pivot_cache_definition_collection = spreadsheet.get_pivot_caches();
@@ -154,3 +170,26 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let target = "xl/workbook.xml";
     writer_mng.add_writer(target, writer)
 }
+
+fn write_defined_name(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    defined_name: &DefinedName,
+    local_sheet_id: Option<u32>,
+) {
+    // definedName
+    let local_sheet_id_str = local_sheet_id.map(|v| v.to_string());
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    attributes.push(("name", defined_name.get_name()));
+    if !defined_name.get_comment().is_empty() {
+        attributes.push(("comment", defined_name.get_comment()));
+    }
+    if let Some(v) = &local_sheet_id_str {
+        attributes.push(("localSheetId", v));
+    }
+    if *defined_name.get_hidden() {
+        attributes.push(("hidden", "1"));
+    }
+    write_start_tag(writer, "definedName", attributes, false);
+    write_text_node(writer, defined_name.get_address_str());
+    write_end_tag(writer, "definedName");
+}
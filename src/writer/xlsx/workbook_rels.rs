@@ -4,6 +4,7 @@ use std::io;
 
 use super::driver::*;
 use super::XlsxError;
+use structs::SheetTab;
 use structs::Spreadsheet;
 use structs::WriterManager;
 
@@ -32,16 +33,34 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     let mut index = 1;
 
-    // relationships worksheet
-    for _ in spreadsheet.get_sheet_collection_no_check() {
-        let path_str = format!("worksheets/sheet{}.xml", index);
+    // relationships worksheet, chartsheet
+    for sheet_tab in spreadsheet.get_sheet_tab_order() {
+        let (type_value, path_str) = match sheet_tab {
+            SheetTab::Worksheet(i) => (
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet",
+                format!("worksheets/sheet{}.xml", i + 1),
+            ),
+            SheetTab::Chartsheet(i) => (
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet",
+                format!("chartsheets/sheet{}.xml", i + 1),
+            ),
+        };
+        write_relationship(&mut writer, &index.to_string(), type_value, &path_str, "");
+        index += 1;
+    }
+
+    // relationships externalLink
+    let mut external_link_no = 1;
+    for _ in spreadsheet.get_external_links() {
+        let path_str = format!("externalLinks/externalLink{}.xml", external_link_no);
         write_relationship(
             &mut writer,
             &index.to_string(),
-            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet",
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLink",
             &path_str,
             "",
         );
+        external_link_no += 1;
         index += 1;
     }
 
@@ -98,6 +117,30 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             "vbaProject.bin",
             "",
         );
+        index += 1;
+    }
+
+    // relationship persons/person.xml
+    if !spreadsheet.get_person_collection().is_empty() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            "http://schemas.microsoft.com/office/2017/10/relationships/person",
+            "persons/person.xml",
+            "",
+        );
+        index += 1;
+    }
+
+    // relationship metadata.xml
+    if spreadsheet.has_dynamic_array_formula() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/sheetMetadata",
+            "metadata.xml",
+            "",
+        );
     }
 
     write_end_tag(&mut writer, root_tag_name);
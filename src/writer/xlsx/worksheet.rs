@@ -31,38 +31,39 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_new_line(&mut writer);
 
         // worksheet
-        write_start_tag(
-            &mut writer,
-            "worksheet",
-            vec![
-                (
-                    "xmlns",
-                    "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
-                ),
-                (
-                    "xmlns:r",
-                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
-                ),
-                (
-                    "xmlns:xdr",
-                    "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
-                ),
-                (
-                    "xmlns:x14",
-                    "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
-                ),
-                (
-                    "xmlns:mc",
-                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
-                ),
-                ("mc:Ignorable", "x14ac"),
-                (
-                    "xmlns:x14ac",
-                    "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac",
-                ),
-            ],
-            false,
-        );
+        let mut attributes: Vec<(&str, &str)> = vec![
+            (
+                "xmlns",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+            (
+                "xmlns:xdr",
+                "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+            ),
+            (
+                "xmlns:x14",
+                "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+            ),
+            (
+                "xmlns:mc",
+                "http://schemas.openxmlformats.org/markup-compatibility/2006",
+            ),
+            ("mc:Ignorable", "x14ac"),
+            (
+                "xmlns:x14ac",
+                "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac",
+            ),
+        ];
+        for (key, value) in worksheet.get_namespaces() {
+            if !attributes.iter().any(|(k, _)| k == key) {
+                attributes.push((key, value));
+            }
+        }
+        write_start_tag(&mut writer, "worksheet", attributes, false);
 
         // sheetPr
         let mut attributes: Vec<(&str, &str)> = Vec::new();
@@ -181,6 +182,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             write_end_tag(&mut writer, "sheetData");
         }
 
+        // sheetCalcPr
+        write_unknown_elements(&mut writer, worksheet, &["sheetCalcPr"]);
+
         // sheetProtection
         match worksheet.get_sheet_protection() {
             Some(v) => {
@@ -189,6 +193,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             None => {}
         }
 
+        // protectedRanges, scenarios
+        write_unknown_elements(&mut writer, worksheet, &["protectedRanges", "scenarios"]);
+
         // autoFilter
         match worksheet.get_auto_filter() {
             Some(v) => {
@@ -202,15 +209,31 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             None => {}
         }
 
+        // sortState, dataConsolidate, customSheetViews
+        write_unknown_elements(
+            &mut writer,
+            worksheet,
+            &["sortState", "dataConsolidate", "customSheetViews"],
+        );
+
         // mergeCells
         worksheet.get_merge_cells_crate().write_to(&mut writer);
 
         // phoneticPr
-        write_start_tag(&mut writer, "phoneticPr", vec![("fontId", "1")], true);
+        if worksheet.get_unknown_elements("phoneticPr").is_empty() {
+            write_start_tag(&mut writer, "phoneticPr", vec![("fontId", "1")], true);
+        } else {
+            write_unknown_elements(&mut writer, worksheet, &["phoneticPr"]);
+        }
 
         // conditionalFormatting
+        let mut index = 0;
         for conditional_formatting in worksheet.get_conditional_formatting_collection() {
-            conditional_formatting.write_to(&mut writer, stylesheet.get_differential_formats_mut());
+            conditional_formatting.write_to(
+                &mut writer,
+                stylesheet.get_differential_formats_mut(),
+                &mut index,
+            );
         }
 
         // dataValidations
@@ -228,17 +251,12 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             write_start_tag(&mut writer, "hyperlinks", vec![], false);
 
             // hyperlink
-            for (coordition, hyperlink) in worksheet.get_hyperlink_collection_to_hashmap() {
+            for (coordinate, hyperlink) in worksheet.get_hyperlink_collection() {
                 let r_id_str = format!("rId{}", &r_id);
-                let mut attributes: Vec<(&str, &str)> = Vec::new();
-                attributes.push(("ref", &coordition));
-                if hyperlink.get_location() == &true {
-                    attributes.push(("location", hyperlink.get_url()));
-                } else {
-                    attributes.push(("r:id", r_id_str.as_str()));
+                hyperlink.write_to(&mut writer, &coordinate, &r_id_str);
+                if hyperlink.is_external() {
                     r_id += 1;
                 }
-                write_start_tag(&mut writer, "hyperlink", attributes, true);
             }
 
             write_end_tag(&mut writer, "hyperlinks");
@@ -264,6 +282,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         // colBreaks
         worksheet.get_column_breaks().write_to(&mut writer);
 
+        // customProperties, cellWatches, ignoredErrors, smartTags
+        write_unknown_elements(
+            &mut writer,
+            worksheet,
+            &[
+                "customProperties",
+                "cellWatches",
+                "ignoredErrors",
+                "smartTags",
+            ],
+        );
+
         if worksheet.has_drawing_object() {
             // drawing
             let r_id_str = format!("rId{}", &r_id);
@@ -288,6 +318,17 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             r_id += 1;
         }
 
+        // oleObjects
+        // relationship ids of the tables come first.
+        let ole_r_id = r_id + worksheet.get_tables().len();
+        let ole_id = 1000 + 25;
+        worksheet
+            .get_ole_objects()
+            .write_to(&mut writer, &ole_r_id, &ole_id);
+
+        // webPublishItems
+        write_unknown_elements(&mut writer, worksheet, &["webPublishItems"]);
+
         // tableParts
         if worksheet.has_table() {
             let tables = worksheet.get_tables();
@@ -310,11 +351,102 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             write_end_tag(&mut writer, "tableParts");
         }
 
-        // oleObjects
-        let ole_id = 1000 + 25;
-        worksheet
-            .get_ole_objects()
-            .write_to(&mut writer, &r_id, &ole_id);
+        // extLst
+        let has_x14_conditional_formatting = worksheet
+            .get_conditional_formatting_collection()
+            .iter()
+            .any(|v| v.has_x14_rule())
+            || !worksheet
+                .get_unknown_elements("x14:conditionalFormatting")
+                .is_empty();
+        let has_x14_data_validations = worksheet
+            .get_data_validations()
+            .as_ref()
+            .map_or(false, |v| v.has_x14());
+        let has_sparkline_groups = worksheet.get_sparkline_groups().is_some();
+        let has_ext_lst = has_x14_conditional_formatting
+            || has_x14_data_validations
+            || has_sparkline_groups
+            || !worksheet.get_unknown_extensions().is_empty();
+        if has_ext_lst {
+            write_start_tag(&mut writer, "extLst", vec![], false);
+        }
+        if has_x14_conditional_formatting {
+            // ext
+            write_start_tag(
+                &mut writer,
+                "ext",
+                vec![
+                    ("uri", "{78C0D931-6437-407d-A8EE-F0AAD7539E65}"),
+                    (
+                        "xmlns:x14",
+                        "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+                    ),
+                ],
+                false,
+            );
+
+            // x14:conditionalFormattings
+            write_start_tag(&mut writer, "x14:conditionalFormattings", vec![], false);
+            let mut index = 0;
+            for conditional_formatting in worksheet.get_conditional_formatting_collection() {
+                conditional_formatting.write_to_x14(&mut writer, &mut index);
+            }
+            write_unknown_elements(&mut writer, worksheet, &["x14:conditionalFormatting"]);
+            write_end_tag(&mut writer, "x14:conditionalFormattings");
+
+            write_end_tag(&mut writer, "ext");
+        }
+        if has_x14_data_validations {
+            // ext
+            write_start_tag(
+                &mut writer,
+                "ext",
+                vec![
+                    ("uri", "{CCE6A557-97BC-4b89-ADB6-D9C93CAAB3DF}"),
+                    (
+                        "xmlns:x14",
+                        "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+                    ),
+                ],
+                false,
+            );
+
+            // x14:dataValidations
+            worksheet
+                .get_data_validations()
+                .as_ref()
+                .unwrap()
+                .write_to_x14(&mut writer);
+
+            write_end_tag(&mut writer, "ext");
+        }
+        if let Some(v) = worksheet.get_sparkline_groups() {
+            // ext
+            write_start_tag(
+                &mut writer,
+                "ext",
+                vec![
+                    ("uri", "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}"),
+                    (
+                        "xmlns:x14",
+                        "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+                    ),
+                ],
+                false,
+            );
+
+            // x14:sparklineGroups
+            v.write_to(&mut writer);
+
+            write_end_tag(&mut writer, "ext");
+        }
+        for v in worksheet.get_unknown_extensions() {
+            write_text_node_no_escape(&mut writer, v);
+        }
+        if has_ext_lst {
+            write_end_tag(&mut writer, "extLst");
+        }
     }
 
     write_end_tag(&mut writer, "worksheet");
@@ -322,3 +454,15 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let target = format!("xl/worksheets/sheet{}.xml", sheet_no);
     writer_mng.add_writer(&target, writer)
 }
+
+fn write_unknown_elements(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    worksheet: &Worksheet,
+    names: &[&str],
+) {
+    for name in names {
+        for v in worksheet.get_unknown_elements(name) {
+            write_text_node_no_escape(writer, v);
+        }
+    }
+}
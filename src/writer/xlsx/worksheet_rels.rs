@@ -14,6 +14,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     drawing_no: &str,
     vml_drawing_no: &str,
     comment_no: &str,
+    threaded_comment_no: &str,
     ole_object_no_list: &[String],
     excel_no_list: &[String],
     printer_settings_no: &str,
@@ -45,8 +46,8 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let mut r_id: i32 = 1;
 
     // Write hyperlink relationships
-    for (_, hyperlink) in worksheet.get_hyperlink_collection_to_hashmap() {
-        if hyperlink.get_location() == &false {
+    for (_, hyperlink) in worksheet.get_hyperlink_collection() {
+        if hyperlink.is_external() {
             is_write = write_relationship(
                 &mut writer,
                 r_id.to_string().as_str(),
@@ -163,6 +164,22 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             format!("../comments{}.xml", comment_no.to_string().as_str()).as_str(),
             "",
         );
+        r_id += 1;
+    }
+
+    // Write threadedComments relationship
+    if !worksheet.get_threaded_comments().is_empty() {
+        is_write = write_relationship(
+            &mut writer,
+            r_id.to_string().as_str(),
+            "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment",
+            format!(
+                "../threadedComments/threadedComment{}.xml",
+                threaded_comment_no
+            )
+            .as_str(),
+            "",
+        );
     }
 
     write_end_tag(&mut writer, "Relationships");
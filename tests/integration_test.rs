@@ -159,7 +159,7 @@ fn read_and_wite_method(book: &mut umya_spreadsheet::Spreadsheet) {
         book.get_sheet(&0).unwrap().get_formatted_value("B23")
     );
     assert_eq!(
-        "2:33 pm",
+        "2:33 PM",
         book.get_sheet(&0).unwrap().get_formatted_value("B24")
     );
     assert_eq!(
@@ -183,7 +183,7 @@ fn read_and_wite_method(book: &mut umya_spreadsheet::Spreadsheet) {
         book.get_sheet(&0).unwrap().get_formatted_value("B29")
     );
     assert_eq!(
-        "2020年10月1日",
+        "令和2年10月1日",
         book.get_sheet(&0).unwrap().get_formatted_value("B30")
     );
     assert_eq!(
@@ -1290,3 +1290,2029 @@ fn html_to_richtext_test() {
     let path = std::path::Path::new("./tests/result_files/bbb_html_to_richtext.xlsx");
     let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
 }
+
+#[test]
+fn chart_sheet_test() {
+    use std::io::Read;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=5 {
+        sheet.get_cell_mut((1, row)).set_value_number(row);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("D11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::BarChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$5"],
+    );
+    chart.set_title("Chart Sheet");
+    book.new_chart_sheet("Chart1", &chart)
+        .unwrap()
+        .set_zoom_to_fit(true);
+    assert!(book.new_chart_sheet("Sheet1", &chart).is_err());
+    book.new_sheet("Sheet2").unwrap();
+    book.set_active_sheet(1);
+
+    let path = std::path::Path::new("./tests/result_files/chart_sheet.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_sheet_count(), 2);
+    assert_eq!(book.get_active_sheet().get_name(), "Sheet2");
+    let chart_sheet = book.get_chart_sheet_by_name("Chart1").unwrap();
+    assert!(chart_sheet.has_chart());
+    assert_eq!(chart_sheet.get_zoom_to_fit(), &true);
+
+    // the chart sheet keeps its tab between the worksheets.
+    let path = std::path::Path::new("./tests/result_files/chart_sheet_2.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/workbook.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"activeTab="2""#));
+    let sheet1 = data.find(r#"name="Sheet1""#).unwrap();
+    let chart1 = data.find(r#"name="Chart1""#).unwrap();
+    let sheet2 = data.find(r#"name="Sheet2""#).unwrap();
+    assert!(sheet1 < chart1 && chart1 < sheet2);
+
+    // the active chart sheet falls back to the nearest worksheet.
+    let mut book = book;
+    book.get_workbook_view_mut().set_active_tab(1);
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_workbook_view().get_active_tab(), &1);
+    assert_eq!(book.get_active_sheet().get_name(), "Sheet1");
+    book.remove_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(book.get_workbook_view().get_active_tab(), &0);
+    assert_eq!(book.get_active_sheet().get_name(), "Sheet2");
+}
+
+#[test]
+fn chart_style_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=5 {
+        sheet.get_cell_mut((1, row)).set_value_number(row);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("J15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$5"],
+    );
+    chart
+        .set_title("Styled")
+        .set_style(26)
+        .set_title_font(16.0, true, "1F3864")
+        .set_legend_font(9.0, false, "595959")
+        .set_axis_font(8.0, false, "595959");
+    chart
+        .get_area_chart_series_list_mut()
+        .get_area_chart_series_mut()
+        .get_mut(0)
+        .unwrap()
+        .set_line_color("C00000")
+        .set_line_width(2.25)
+        .set_line_dash(umya_spreadsheet::drawing::PresetLineDashValues::Dash)
+        .set_marker_style(umya_spreadsheet::drawing::charts::MarkerStyleValues::Diamond)
+        .set_marker_size(7);
+    chart.get_chart_space_mut().set_chart_color_style_xml(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><cs:colorStyle xmlns:cs="http://schemas.microsoft.com/office/drawing/2012/chartStyle" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" meth="cycle" id="10"><a:schemeClr val="accent1"/><cs:variation/></cs:colorStyle>"#,
+    );
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_style.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let chart = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_chart_collection()
+        .first()
+        .unwrap();
+    let chart_space = chart.get_chart_space();
+    assert_eq!(chart_space.get_style().get_val(), &26);
+    assert!(chart_space.get_chart_color_style_xml().is_some());
+    assert!(chart_space.get_chart_style_xml().is_none());
+    let series = chart_space
+        .get_chart()
+        .get_plot_area()
+        .get_line_chart()
+        .as_ref()
+        .unwrap()
+        .get_area_chart_series_list()
+        .get_area_chart_series()
+        .first()
+        .unwrap();
+    let outline = series
+        .get_shape_properties()
+        .as_ref()
+        .unwrap()
+        .get_outline()
+        .as_ref()
+        .unwrap();
+    assert_eq!(outline.get_width(), &28575);
+    let marker = series.get_marker().as_ref().unwrap();
+    assert_eq!(marker.get_marker_size().as_ref().unwrap().get_val(), &7);
+}
+
+#[test]
+fn chart_ex_test() {
+    use umya_spreadsheet::drawing::chart_ex::SeriesLayoutValues;
+    use umya_spreadsheet::structs::ChartExType;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let rows = [("Start", 100), ("Sales", 40), ("Costs", -30), ("End", 110)];
+    for (i, (name, value)) in rows.iter().enumerate() {
+        let row = i as u32 + 1;
+        sheet.get_cell_mut((1, row)).set_value(*name);
+        sheet.get_cell_mut((2, row)).set_value_number(*value);
+    }
+
+    let charts = [
+        (
+            ChartExType::Waterfall,
+            "D1",
+            "K15",
+            vec!["Sheet1!$A$1:$A$4", "Sheet1!$B$1:$B$4"],
+        ),
+        (
+            ChartExType::Histogram,
+            "D20",
+            "K35",
+            vec!["Sheet1!$B$1:$B$4"],
+        ),
+        (
+            ChartExType::Treemap,
+            "D40",
+            "K55",
+            vec!["Sheet1!$A$1:$A$4", "Sheet1!$B$1:$B$4"],
+        ),
+    ];
+    for (chart_type, from, to, data_list) in charts {
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate(from);
+        to_marker.set_coordinate(to);
+        let mut chart = umya_spreadsheet::structs::ChartEx::default();
+        chart.new_chart_ex(chart_type, from_marker, to_marker, data_list);
+        chart.set_title("Chart").set_subtotals(vec![3]);
+        sheet.add_chart_ex(chart);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/chart_ex.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_chart_collection().len(), 0);
+    assert_eq!(sheet.get_chart_ex_collection().len(), 3);
+    let layout_ids: Vec<SeriesLayoutValues> = sheet
+        .get_chart_ex_collection()
+        .iter()
+        .map(|v| {
+            v.get_chart_space()
+                .get_chart()
+                .get_plot_area()
+                .get_plot_area_region()
+                .get_series()
+                .first()
+                .unwrap()
+                .get_layout_id()
+                .clone()
+        })
+        .collect();
+    assert_eq!(
+        layout_ids,
+        vec![
+            SeriesLayoutValues::Waterfall,
+            SeriesLayoutValues::ClusteredColumn,
+            SeriesLayoutValues::Treemap
+        ]
+    );
+    let waterfall = sheet.get_chart_ex("D1").unwrap().get_chart_space();
+    let series = waterfall
+        .get_chart()
+        .get_plot_area()
+        .get_plot_area_region()
+        .get_series()
+        .first()
+        .unwrap();
+    assert_eq!(
+        series
+            .get_layout_properties()
+            .as_ref()
+            .unwrap()
+            .get_subtotals(),
+        &vec![3]
+    );
+    let dimension = waterfall
+        .get_chart_data()
+        .get_data()
+        .first()
+        .unwrap()
+        .get_dimension();
+    assert_eq!(dimension[1].get_formula(), "Sheet1!$B$1:$B$4");
+    assert_eq!(dimension[1].get_level_xml().len(), 1);
+
+    // saved again without losing the charts
+    let path = std::path::Path::new("./tests/result_files/chart_ex_resave.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_chart_ex_collection().len(), 3);
+}
+
+#[test]
+fn sparkline_test() {
+    use umya_spreadsheet::structs::SparklineGroup;
+    use umya_spreadsheet::structs::SparklineTypeValues;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for col in 1..=5 {
+        sheet.get_cell_mut((col, 1)).set_value_number(col);
+        sheet.get_cell_mut((col, 2)).set_value_number(6 - col);
+    }
+
+    let mut group = SparklineGroup::default();
+    group
+        .set_type(SparklineTypeValues::Column)
+        .set_high(true)
+        .set_display_x_axis(true)
+        .add_sparkline("Sheet1!A1:E1", "F1")
+        .add_sparkline("Sheet1!A2:E2", "F2");
+    group.get_color_series_mut().set_argb("FF376092");
+    sheet.add_sparkline_group(group);
+
+    let path = std::path::Path::new("./tests/result_files/sparkline.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let groups = sheet.get_sparkline_groups().as_ref().unwrap();
+    let group = groups.get_sparkline_group_list().first().unwrap();
+    assert_eq!(group.get_type(), &SparklineTypeValues::Column);
+    assert_eq!(group.get_high(), &true);
+    assert_eq!(group.get_markers(), &false);
+    assert_eq!(group.get_color_series().get_argb(), "FF376092");
+    assert_eq!(group.get_sparkline_list().len(), 2);
+    let sparkline = &group.get_sparkline_list()[1];
+    assert_eq!(sparkline.get_formula(), "Sheet1!A2:E2");
+    assert_eq!(sparkline.get_sequence_of_references().get_sqref(), "F2");
+}
+
+#[test]
+fn preserve_unknown_worksheet_elements() {
+    use std::io::{Read, Write};
+
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value("001");
+    let path = std::path::Path::new("./tests/result_files/unknown_elements_base.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // add elements that are not modeled.
+    let path_in = std::path::Path::new("./tests/result_files/unknown_elements_in.xlsx");
+    {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path_in).unwrap());
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).unwrap();
+            let mut data = String::new();
+            let name = file.name().to_string();
+            if name == "xl/worksheets/sheet1.xml" {
+                file.read_to_string(&mut data).unwrap();
+                data = data
+                    .replace(
+                        "<worksheet ",
+                        r#"<worksheet xmlns:xr="http://schemas.microsoft.com/office/spreadsheetml/2014/revision" "#,
+                    )
+                    .replace("<phoneticPr fontId=\"1\"/>", "<phoneticPr fontId=\"2\" type=\"noConversion\"/>")
+                    .replace(
+                        "</worksheet>",
+                        r#"<customProperties><customPr name="Tag" r:id="rId9"/></customProperties><ignoredErrors><ignoredError sqref="A1" numberStoredAsText="1"/></ignoredErrors><extLst><ext uri="{B0000000-0000-0000-0000-000000000001}" xmlns:x15="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main"><x15:unknown xr:uid="{00000000-0000-0000-0000-000000000000}"/></ext><ext uri="{A8765BA9-456A-4dab-B4F3-ACF838C121DE}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main"><x14:slicerList><x14:slicer r:id="rId8"/></x14:slicerList></ext></extLst></worksheet>"#,
+                    );
+                zip.start_file(name, zip::write::FileOptions::default())
+                    .unwrap();
+                zip.write_all(data.as_bytes()).unwrap();
+            } else {
+                zip.raw_copy_file(file).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    let book = umya_spreadsheet::reader::xlsx::read(path_in).unwrap();
+    let path_out = std::path::Path::new("./tests/result_files/unknown_elements_out.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path_out);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path_out).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains("xmlns:xr="));
+    assert!(data.contains(r#"<phoneticPr fontId="2" type="noConversion"/>"#));
+    assert!(!data.contains(r#"<phoneticPr fontId="1"/>"#));
+    let ignored_errors = data
+        .find(r#"<ignoredErrors><ignoredError sqref="A1" numberStoredAsText="1"/></ignoredErrors>"#)
+        .unwrap();
+    let ext = data
+        .find(r#"<ext uri="{B0000000-0000-0000-0000-000000000001}""#)
+        .unwrap();
+    assert!(data.find("<pageMargins").unwrap() < ignored_errors);
+    assert!(ignored_errors < data.find("<extLst>").unwrap());
+    assert!(data.find("<extLst>").unwrap() < ext);
+    // the parts they point to are not kept.
+    assert!(!data.contains("customProperties"));
+    assert!(!data.contains("slicerList"));
+}
+
+#[test]
+fn icon_set_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for i in 1..=5 {
+        worksheet
+            .get_cell_mut((1, i))
+            .set_value_number(i as f64 * 10f64);
+    }
+
+    // 3Arrows
+    let mut icon_set = IconSet::default();
+    icon_set
+        .set_icon_set(IconSetValues::ThreeArrows)
+        .set_show_value(false)
+        .set_reverse(true);
+    for (value, gte) in [("0", true), ("33", true), ("67", false)] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(value);
+        if !gte {
+            cfvo.set_gte(false);
+        }
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(1)
+        .set_icon_set(icon_set);
+    let mut conditional_formatting = ConditionalFormatting::default();
+    conditional_formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("A1:A5");
+    conditional_formatting.add_conditional_collection(rule);
+    worksheet.add_conditional_formatting_collection(conditional_formatting);
+
+    // custom icon set (x14)
+    let mut icon_set = IconSet::default();
+    icon_set.set_icon_set(IconSetValues::ThreeStars);
+    for value in ["0", "33", "67"] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(value);
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    for (set, id) in [
+        (IconSetValues::ThreeFlags, 0),
+        (IconSetValues::NoIcons, 0),
+        (IconSetValues::ThreeStars, 2),
+    ] {
+        let mut icon = ConditionalFormattingIcon::default();
+        icon.set_icon_set(set).set_icon_id(id);
+        icon_set.add_cf_icon_collection(icon);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(2)
+        .set_icon_set(icon_set);
+    let mut conditional_formatting = ConditionalFormatting::default();
+    conditional_formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("B1:B5");
+    conditional_formatting.add_conditional_collection(rule);
+    worksheet.add_conditional_formatting_collection(conditional_formatting);
+
+    let path = std::path::Path::new("./tests/result_files/icon_set.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"<iconSet iconSet="3Arrows" showValue="0" reverse="1">"#));
+    assert!(data.contains(r#"<cfvo type="percent" val="67" gte="0"/>"#));
+    assert!(!data.contains("<dataBar"));
+    assert!(!data.contains(r#"<conditionalFormatting sqref="B1:B5""#));
+    assert!(data.contains(r#"<x14:iconSet iconSet="3Stars" custom="1">"#));
+    assert!(data.contains(r#"<x14:cfIcon iconSet="NoIcons" iconId="0"/>"#));
+
+    // round-trip
+    let book = reader::xlsx::read(path).unwrap();
+    let collection = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_conditional_formatting_collection();
+    assert_eq!(collection.len(), 2);
+    let icon_set = collection[0].get_conditional_collection()[0]
+        .get_icon_set()
+        .as_ref()
+        .unwrap();
+    assert_eq!(icon_set.get_icon_set(), &IconSetValues::ThreeArrows);
+    assert_eq!(icon_set.get_show_value(), &false);
+    assert_eq!(icon_set.get_reverse(), &true);
+    assert_eq!(icon_set.get_cfvo_collection()[2].get_gte(), &false);
+
+    assert_eq!(
+        collection[1].get_sequence_of_references().get_sqref(),
+        "B1:B5"
+    );
+    let rule = &collection[1].get_conditional_collection()[0];
+    assert_eq!(rule.get_priority(), &2);
+    let icon_set = rule.get_icon_set().as_ref().unwrap();
+    assert_eq!(icon_set.get_icon_set(), &IconSetValues::ThreeStars);
+    assert_eq!(icon_set.get_cfvo_collection()[1].get_val(), "33");
+    assert_eq!(icon_set.get_cf_icon_collection().len(), 3);
+    assert_eq!(icon_set.get_cf_icon_collection()[2].get_icon_id(), &2);
+    let path = std::path::Path::new("./tests/result_files/icon_set_out.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn icon_set_gte_default_test() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for i in 1..=3 {
+        worksheet.get_cell_mut((1, i)).set_value_number(i);
+    }
+    let mut icon_set = IconSet::default();
+    icon_set.set_icon_set(IconSetValues::ThreeArrows);
+    for value in ["0", "2", "3"] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Number)
+            .set_val(value);
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_icon_set(icon_set);
+    worksheet.add_conditional_formatting_rule("A1:A3", rule);
+
+    let path = std::path::Path::new("./tests/result_files/icon_set_gte.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    // the thresholds are read without gte, which is inclusive by default.
+    let book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let icon_set = worksheet.get_conditional_formatting_collection()[0]
+        .get_conditional_collection()[0]
+        .get_icon_set()
+        .as_ref()
+        .unwrap();
+    assert_eq!(icon_set.get_cfvo_collection()[1].get_gte(), &true);
+    for (coordinate, icon_id) in [("A1", 0), ("A2", 1), ("A3", 2)] {
+        assert_eq!(
+            worksheet.get_effective_style(coordinate).get_icon(),
+            &Some((IconSetValues::ThreeArrows, icon_id))
+        );
+    }
+}
+
+#[test]
+fn conditional_formatting_builder_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for i in 1..=10 {
+        worksheet.get_cell_mut((1, i)).set_value_number(i as f64);
+        worksheet
+            .get_cell_mut((2, i))
+            .set_value(if i % 2 == 0 { "apple" } else { "banana" });
+    }
+
+    let mut style = Style::default();
+    style.set_background_color("FFFFC7CE");
+    worksheet.add_cell_is_rule(
+        "A1:A10",
+        ConditionalFormattingOperatorValues::GreaterThan,
+        "5",
+        style.clone(),
+    );
+    worksheet.add_color_scale_3("A1:A10", "FFF8696B", "FFFFEB84", "FF63BE7B");
+    worksheet.add_data_bar("A1:A10", "FF638EC6");
+    worksheet
+        .add_top_n("A1:A10", 3, style.clone())
+        .set_bottom(true);
+    worksheet.add_duplicate_values("B1:B10", style.clone());
+    worksheet.add_text_contains("B1:B10", "apple", style);
+
+    let priorities: Vec<i32> = worksheet
+        .get_conditional_formatting_collection()
+        .iter()
+        .flat_map(|v| v.get_conditional_collection())
+        .map(|v| *v.get_priority())
+        .collect();
+    assert_eq!(priorities, vec![1, 2, 3, 4, 5, 6]);
+
+    let path = std::path::Path::new("./tests/result_files/conditional_formatting_builder.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(
+        r#"<cfRule type="cellIs" operator="greaterThan" dxfId="0" priority="1"><formula>5</formula></cfRule>"#
+    ));
+    assert!(data.contains(r#"<cfRule type="top10" dxfId="0" priority="4" bottom="1" rank="3"/>"#));
+    assert!(data.contains("SEARCH("));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let collection = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_conditional_formatting_collection();
+    assert_eq!(collection.len(), 6);
+    let rule = &collection[5].get_conditional_collection()[0];
+    assert_eq!(rule.get_type(), &ConditionalFormatValues::ContainsText);
+    assert_eq!(rule.get_text(), "apple");
+    assert_eq!(
+        rule.get_formula().as_ref().unwrap().get_address_str(),
+        "NOT(ISERROR(SEARCH(\"apple\",B1)))"
+    );
+}
+
+#[test]
+fn data_bar_x14_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for i in 1..=10 {
+        worksheet
+            .get_cell_mut((1, i))
+            .set_value_number(i as f64 - 5f64);
+    }
+    worksheet.add_cell_is_rule(
+        "A1:A10",
+        ConditionalFormattingOperatorValues::Equal,
+        "0",
+        Style::default(),
+    );
+    let mut negative_color = Color::default();
+    negative_color.set_argb("FFFF0000");
+    let mut axis_color = Color::default();
+    axis_color.set_argb("FF000000");
+    worksheet
+        .add_data_bar("A1:A10", "FF638EC6")
+        .get_data_bar_mut()
+        .unwrap()
+        .set_min_length(0)
+        .set_max_length(100)
+        .set_x14_max_length(80)
+        .set_gradient(false)
+        .set_border(true)
+        .set_direction(DataBarDirectionValues::LeftToRight)
+        .set_axis_position(DataBarAxisPositionValues::Middle)
+        .set_negative_fill_color(negative_color)
+        .set_axis_color(axis_color);
+
+    let path = std::path::Path::new("./tests/result_files/data_bar_x14.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    let id = "{00000000-0000-0000-0000-000000000002}";
+    assert!(data.contains(r#"<dataBar minLength="0" maxLength="100">"#));
+    assert!(data.contains(r#"<x14:dataBar minLength="0" maxLength="80""#));
+    assert!(data.contains(&format!("<x14:id>{}</x14:id>", id)));
+    assert!(data.contains(&format!(r#"<x14:cfRule type="dataBar" id="{}">"#, id)));
+    assert!(data.contains(r#"<x14:cfvo type="autoMin"/>"#));
+    assert!(data.contains(r#"<x14:negativeFillColor rgb="FFFF0000"/>"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let collection = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_conditional_formatting_collection();
+    assert_eq!(collection.len(), 2);
+    let rule = &collection[1].get_conditional_collection()[0];
+    assert_eq!(rule.get_id(), id);
+    let data_bar = rule.get_data_bar().as_ref().unwrap();
+    assert_eq!(data_bar.get_max_length(), &100);
+    assert_eq!(data_bar.get_x14_max_length(), &80);
+    assert_eq!(data_bar.get_gradient(), &false);
+    assert_eq!(data_bar.get_border(), &true);
+    assert_eq!(
+        data_bar.get_direction(),
+        &DataBarDirectionValues::LeftToRight
+    );
+    assert_eq!(
+        data_bar.get_axis_position(),
+        &DataBarAxisPositionValues::Middle
+    );
+    assert_eq!(data_bar.get_x14_cfvo_collection().len(), 2);
+    assert_eq!(
+        data_bar
+            .get_negative_fill_color()
+            .as_ref()
+            .unwrap()
+            .get_argb(),
+        "FFFF0000"
+    );
+    assert_eq!(data_bar.get_color_collection()[0].get_argb(), "FF638EC6");
+
+    let path = std::path::Path::new("./tests/result_files/data_bar_x14_out.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data_out = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data_out)
+        .unwrap();
+    assert_eq!(data, data_out);
+}
+
+#[test]
+fn conditional_formatting_effective_style_test() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for i in 1..=10 {
+        worksheet.get_cell_mut((1, i)).set_value_number(i as f64);
+        worksheet
+            .get_cell_mut((2, i))
+            .set_value(if i % 2 == 0 { "apple" } else { "banana" });
+    }
+
+    let mut red = Style::default();
+    red.set_background_color("FFFFC7CE");
+    red.get_font_mut().set_bold(true);
+    let mut green = Style::default();
+    green.set_background_color("FFC6EFCE");
+    worksheet
+        .add_cell_is_rule(
+            "A1:A10",
+            ConditionalFormattingOperatorValues::GreaterThan,
+            "8",
+            red,
+        )
+        .set_stop_if_true(true);
+    worksheet.add_top_n("A1:A10", 3, green.clone());
+    worksheet.add_data_bar("A1:A10", "FF638EC6");
+    worksheet.add_color_scale_2("C1:C10", "FFFFFFFF", "FF000000");
+    worksheet.add_text_contains("B1:B10", "APP", green);
+    for i in 1..=10 {
+        worksheet
+            .get_cell_mut((3, i))
+            .set_value_number((i - 1) as f64);
+    }
+
+    // cellIs wins over top10 and stops the evaluation.
+    let style = worksheet.get_effective_style("A10");
+    assert_eq!(
+        style
+            .get_style()
+            .get_background_color()
+            .as_ref()
+            .unwrap()
+            .get_argb(),
+        "FFFFC7CE"
+    );
+    assert!(style.get_style().get_font().as_ref().unwrap().get_bold());
+    assert!(style.get_data_bar_length().is_none());
+
+    // top10 and the data bar.
+    let style = worksheet.get_effective_style("A8");
+    assert_eq!(
+        style
+            .get_style()
+            .get_background_color()
+            .as_ref()
+            .unwrap()
+            .get_argb(),
+        "FFC6EFCE"
+    );
+    let length = style.get_data_bar_length().unwrap();
+    assert!((length - (0.1 + 0.8 * 7.0 / 9.0)).abs() < 1e-9);
+    assert_eq!(
+        style.get_data_bar_color().as_ref().unwrap().get_argb(),
+        "FF638EC6"
+    );
+
+    // no rule applies.
+    let style = worksheet.get_effective_style("A2");
+    assert!(style.get_style().get_background_color().is_none());
+
+    // color scale.
+    let style = worksheet.get_effective_style("C10");
+    assert_eq!(
+        style.get_color_scale_color().as_ref().unwrap().get_argb(),
+        "FF000000"
+    );
+    let style = worksheet.get_effective_style((3, 4));
+    assert_eq!(
+        style.get_color_scale_color().as_ref().unwrap().get_argb(),
+        "FFAAAAAA"
+    );
+
+    // text rule.
+    let style = worksheet.get_effective_style("B2");
+    assert!(style.get_style().get_background_color().is_some());
+    let style = worksheet.get_effective_style("B3");
+    assert!(style.get_style().get_background_color().is_none());
+
+    // between with the bounds given in the two formulas.
+    let mut upper = Formula::default();
+    upper.set_address_str("5");
+    let mut blue = Style::default();
+    blue.set_background_color("FFBDD7EE");
+    worksheet
+        .add_cell_is_rule(
+            "D1:D10",
+            ConditionalFormattingOperatorValues::Between,
+            "3",
+            blue,
+        )
+        .set_formula2(upper);
+    for i in 1..=10 {
+        worksheet.get_cell_mut((4, i)).set_value_number(i as f64);
+    }
+    let styles = worksheet.get_effective_style_by_range("D1:D10");
+    let matched: Vec<usize> = styles
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.get_style().get_background_color().is_some())
+        .map(|(i, _)| i + 1)
+        .collect();
+    assert_eq!(matched, vec![3, 4, 5]);
+    let styles = worksheet.get_effective_style_by_range("A1:A10");
+    assert_eq!(
+        styles[7].get_style().get_background_color(),
+        worksheet
+            .get_effective_style("A8")
+            .get_style()
+            .get_background_color()
+    );
+}
+
+#[test]
+fn data_validation_x14_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    book.new_sheet("Sheet2").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let mut data_validations = DataValidations::default();
+
+    let mut data_validation = DataValidation::default();
+    data_validation
+        .set_type(DataValidationValues::Whole)
+        .set_operator(DataValidationOperatorValues::Between)
+        .set_error_style(DataValidationErrorStyleValues::Warning)
+        .set_ime_mode(DataValidationImeModeValues::Off)
+        .set_show_error_message(true)
+        .set_error_title("Invalid")
+        .set_error("Enter a number from 1 to 10.")
+        .set_formula1("1")
+        .set_formula2("10");
+    data_validation
+        .get_sequence_of_references_mut()
+        .set_sqref("A1:A10");
+    data_validations.add_data_validation_list(data_validation);
+
+    let mut data_validation = DataValidation::default();
+    data_validation
+        .set_type(DataValidationValues::List)
+        .set_allow_blank(true)
+        .set_show_drop_down(false)
+        .set_formula1("Sheet2!$A$1:$A$3");
+    data_validation
+        .get_sequence_of_references_mut()
+        .set_sqref("B1:B10");
+    data_validations.add_data_validation_list(data_validation);
+    worksheet.set_data_validations(data_validations);
+
+    let path = std::path::Path::new("./tests/result_files/data_validation_x14.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"<dataValidations count="1"><dataValidation type="whole" errorStyle="warning" imeMode="off" operator="between" showErrorMessage="1" errorTitle="Invalid" error="Enter a number from 1 to 10." sqref="A1:A10">"#));
+    assert!(data.contains(r#"<x14:dataValidation type="list" allowBlank="1" showDropDown="0"><x14:formula1><xm:f>Sheet2!$A$1:$A$3</xm:f></x14:formula1><xm:sqref>B1:B10</xm:sqref></x14:dataValidation>"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let list = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_data_validations()
+        .as_ref()
+        .unwrap()
+        .get_data_validation_list();
+    assert_eq!(list.len(), 2);
+    assert_eq!(
+        list[0].get_error_style(),
+        &DataValidationErrorStyleValues::Warning
+    );
+    assert_eq!(list[0].get_ime_mode(), &DataValidationImeModeValues::Off);
+    assert_eq!(list[0].get_error_title(), "Invalid");
+    assert_eq!(list[0].get_error(), "Enter a number from 1 to 10.");
+    assert_eq!(list[0].get_formula2(), "10");
+    assert_eq!(list[1].get_type(), &DataValidationValues::List);
+    assert_eq!(list[1].get_formula1(), "Sheet2!$A$1:$A$3");
+    assert_eq!(list[1].get_sequence_of_references().get_sqref(), "B1:B10");
+}
+
+#[test]
+fn validate_cells_test() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let mut data_validations = DataValidations::default();
+    let mut add = |validation_type, operator, formula1: &str, formula2: &str, sqref: &str| {
+        let mut data_validation = DataValidation::default();
+        data_validation
+            .set_type(validation_type)
+            .set_operator(operator)
+            .set_allow_blank(true)
+            .set_error("Invalid value.")
+            .set_formula1(formula1);
+        if !formula2.is_empty() {
+            data_validation.set_formula2(formula2);
+        }
+        data_validation
+            .get_sequence_of_references_mut()
+            .set_sqref(sqref);
+        data_validations.add_data_validation_list(data_validation);
+    };
+    add(
+        DataValidationValues::Whole,
+        DataValidationOperatorValues::Between,
+        "1",
+        "10",
+        "A1:A4",
+    );
+    add(
+        DataValidationValues::List,
+        DataValidationOperatorValues::Between,
+        "\"Yes,No\"",
+        "",
+        "B1:B4",
+    );
+    add(
+        DataValidationValues::List,
+        DataValidationOperatorValues::Between,
+        "$E$1:$E$3",
+        "",
+        "C1:C4",
+    );
+    add(
+        DataValidationValues::TextLength,
+        DataValidationOperatorValues::LessThanOrEqual,
+        "3",
+        "",
+        "D1:D4",
+    );
+    add(
+        DataValidationValues::Date,
+        DataValidationOperatorValues::GreaterThanOrEqual,
+        "DATE(2024,1,1)",
+        "",
+        "F1:F4",
+    );
+    add(
+        DataValidationValues::Custom,
+        DataValidationOperatorValues::Between,
+        "G1>A1",
+        "",
+        "G1:G4",
+    );
+    worksheet.set_data_validations(data_validations);
+
+    worksheet.get_cell_mut("A1").set_value_number(5);
+    worksheet.get_cell_mut("A2").set_value_number(5.5);
+    worksheet.get_cell_mut("A3").set_value_number(11);
+    worksheet.get_cell_mut("B1").set_value("yes");
+    worksheet.get_cell_mut("B2").set_value("Maybe");
+    worksheet.get_cell_mut("C1").set_value("red");
+    worksheet.get_cell_mut("C2").set_value("black");
+    worksheet.get_cell_mut("D1").set_value("abc");
+    worksheet.get_cell_mut("D2").set_value("abcd");
+    worksheet.get_cell_mut("E1").set_value("red");
+    worksheet.get_cell_mut("E2").set_value("green");
+    worksheet.get_cell_mut("E3").set_value("blue");
+    // 2024-01-01 and 2023-12-31
+    worksheet.get_cell_mut("F1").set_value_number(45292);
+    worksheet.get_cell_mut("F2").set_value_number(45291);
+    worksheet.get_cell_mut("G1").set_value_number(6);
+    worksheet.get_cell_mut("G2").set_value_number(1);
+
+    let violations: Vec<String> = worksheet
+        .validate_cells()
+        .iter()
+        .map(|v| v.get_coordinate().get_coordinate())
+        .collect();
+    assert_eq!(violations, vec!["A2", "A3", "B2", "C2", "D2", "F2", "G2"]);
+    let violations = worksheet.validate_cells();
+    assert_eq!(violations[1].get_value(), "11");
+    assert_eq!(
+        violations[1].get_data_validation().get_error(),
+        "Invalid value."
+    );
+
+    // lists on another sheet and on a whole column.
+    let lists = book.new_sheet("Lists").unwrap();
+    lists.get_cell_mut("A1").set_value("S");
+    lists.get_cell_mut("A2").set_value("M");
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let data_validations = worksheet.get_data_validations_mut().as_mut().unwrap();
+    for (formula, sqref) in [("Lists!$A$1:$A$3", "H1:H4"), ("$E:$E", "I1:I4")] {
+        let mut data_validation = DataValidation::default();
+        data_validation
+            .set_type(DataValidationValues::List)
+            .set_allow_blank(true)
+            .set_formula1(formula);
+        data_validation
+            .get_sequence_of_references_mut()
+            .set_sqref(sqref);
+        data_validations.add_data_validation_list(data_validation);
+    }
+    worksheet.get_cell_mut("H1").set_value("M");
+    worksheet.get_cell_mut("H2").set_value("XL");
+    worksheet.get_cell_mut("I1").set_value("green");
+    worksheet.get_cell_mut("I2").set_value("pink");
+
+    // the worksheet alone cannot see the other sheet.
+    let violations: Vec<(String, bool)> = worksheet
+        .validate_cells()
+        .iter()
+        .filter(|v| v.get_coordinate().get_col_num() >= &8)
+        .map(|v| (v.get_coordinate().get_coordinate(), v.is_unevaluated()))
+        .collect();
+    assert_eq!(
+        violations,
+        vec![
+            ("H1".to_string(), true),
+            ("H2".to_string(), true),
+            ("I2".to_string(), false)
+        ]
+    );
+    let violations: Vec<(String, bool)> = book
+        .validate_cells(&0)
+        .iter()
+        .filter(|v| v.get_coordinate().get_col_num() >= &8)
+        .map(|v| (v.get_coordinate().get_coordinate(), v.is_unevaluated()))
+        .collect();
+    assert_eq!(
+        violations,
+        vec![("H2".to_string(), false), ("I2".to_string(), false)]
+    );
+}
+
+#[test]
+fn threaded_comment_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let mut person = Person::default();
+    person
+        .set_display_name("Taro")
+        .set_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}")
+        .set_user_id("taro@example.com")
+        .set_provider_id("None");
+    book.add_person(person);
+
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let mut comment = ThreadedComment::default();
+    comment.get_coordinate_mut().set_coordinate("B2");
+    comment
+        .set_date_time("2024-01-01T09:00:00.00")
+        .set_person_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}")
+        .set_text("@Taro Please check.")
+        .set_done(true);
+    let mut mention = ThreadedCommentMention::default();
+    mention
+        .set_mention_person_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}")
+        .set_mention_id("{00000000-0000-0000-0000-000000000001}")
+        .set_start_index(0)
+        .set_length(5);
+    comment.add_mentions(mention);
+    worksheet.add_threaded_comment(comment);
+    let mut reply = ThreadedComment::default();
+    reply.get_coordinate_mut().set_coordinate("B2");
+    reply
+        .set_person_id("{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}")
+        .set_text("Done.");
+    worksheet.add_threaded_comment(reply);
+    let id = worksheet.get_threaded_comments()[0].get_id().to_string();
+    assert_eq!(worksheet.get_threaded_comments()[1].get_parent_id(), id);
+    assert_eq!(worksheet.get_comments().len(), 1);
+
+    let path = std::path::Path::new("./tests/result_files/threaded_comment.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut read_part = |name: &str| {
+        let mut data = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut data)
+            .unwrap();
+        data
+    };
+    let data = read_part("xl/persons/person.xml");
+    assert!(data.contains(r#"<person displayName="Taro" id="{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}" userId="taro@example.com" providerId="None"/>"#));
+    let data = read_part("xl/threadedComments/threadedComment1.xml");
+    assert!(data.contains(r#"<mention mentionpersonId="{6B0E4C7A-5D4D-4B4B-9D0E-2F9A1C3B5E7D}" mentionId="{00000000-0000-0000-0000-000000000001}" startIndex="0" length="5"/>"#));
+    let data = read_part("xl/worksheets/_rels/sheet1.xml.rels");
+    assert!(data.contains("../threadedComments/threadedComment1.xml"));
+    let data = read_part("xl/_rels/workbook.xml.rels");
+    assert!(data.contains("persons/person.xml"));
+    let data = read_part("[Content_Types].xml");
+    assert!(data.contains("application/vnd.ms-excel.threadedcomments+xml"));
+    assert!(data.contains("application/vnd.ms-excel.person+xml"));
+    let data = read_part("xl/comments1.xml");
+    assert!(data.contains(&format!("<author>tc={}</author>", id)));
+    assert!(data.contains("Reply:\n    Done."));
+
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_person_collection().len(), 1);
+    assert_eq!(book.get_person_collection()[0].get_display_name(), "Taro");
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let threads = worksheet.get_threaded_comments();
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].get_id(), id);
+    assert_eq!(threads[0].get_text(), "@Taro Please check.");
+    assert!(*threads[0].get_done());
+    assert_eq!(threads[0].get_mentions()[0].get_length(), &5);
+    assert_eq!(threads[1].get_parent_id(), id);
+    assert_eq!(worksheet.get_comments().len(), 1);
+    assert_eq!(
+        worksheet.get_comments()[0].get_author(),
+        format!("tc={}", id)
+    );
+}
+
+#[test]
+fn add_note_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.add_note("A1", "Author", "Hidden note.");
+    let comment = worksheet.add_note("A1", "Author", "Replaced note.");
+    assert!(!comment.get_visible());
+    assert_eq!(comment.get_anchor().get_right_column(), &3);
+    assert_eq!(comment.get_anchor().get_bottom_row(), &4);
+
+    let mut font = Font::default();
+    font.set_bold(true).set_size(12.0);
+    let mut option = NoteOption::default();
+    option
+        .set_visible(true)
+        .set_size_in_pixels(100, 30)
+        .set_fill_color("#ccffcc")
+        .set_font(font);
+    let comment = worksheet.add_note_with_option((3, 3), "Author", "Visible note.", &option);
+    assert!(comment.get_visible());
+    // the box starts at D2 with 15px and 10px offsets. (64px x 18px cells)
+    let anchor = comment.get_anchor();
+    assert_eq!(
+        (
+            *anchor.get_left_column(),
+            *anchor.get_top_row(),
+            *anchor.get_right_column(),
+            *anchor.get_right_offset(),
+            *anchor.get_bottom_row(),
+            *anchor.get_bottom_offset()
+        ),
+        (3, 1, 4, 51, 3, 4)
+    );
+    worksheet.add_note("E5", "Author", "Removed note.");
+    assert!(worksheet.remove_comment("E5"));
+    assert!(!worksheet.remove_comment("E5"));
+    assert_eq!(worksheet.get_comments().len(), 2);
+
+    let path = std::path::Path::new("./tests/result_files/add_note.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/drawings/vmlDrawing1.vml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(
+        "margin-left:155.25pt;margin-top:21pt;width:75pt;height:22.5pt;z-index:1;visibility:visible"
+    ));
+    assert!(data.contains(r##"fillcolor="#ccffcc""##));
+    assert!(data.contains("<x:Anchor>3, 15, 1, 10, 4, 51, 3, 4</x:Anchor>"));
+    assert!(data.contains("<x:Visible/>"));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let comments = book.get_sheet_by_name("Sheet1").unwrap().get_comments();
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[0].get_text().get_text(), "Replaced note.");
+    assert!(comments[1].get_visible());
+    assert_eq!(comments[1].get_fill_color(), "#ccffcc");
+}
+
+#[test]
+fn named_cell_style_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    assert!(book.get_named_cell_styles().is_empty());
+    let good = book.add_builtin_cell_style(BuiltinCellStyleValues::Good);
+    assert_eq!(good.get_name(), "Good");
+    assert_eq!(good.get_builtin_id(), &Some(26));
+    assert_eq!(good.get_format_id(), &1);
+    assert_eq!(
+        book.add_builtin_cell_style(BuiltinCellStyleValues::Good),
+        good
+    );
+
+    let mut style = Style::default();
+    style.set_background_color("FFFFCC99");
+    style.get_number_format_mut().set_format_code("#,##0.00");
+    let input = book.add_named_cell_style("Currency Input", &style);
+    assert_eq!(input.get_builtin_id(), &None);
+    assert_eq!(input.get_format_id(), &2);
+
+    let names: Vec<String> = book
+        .get_named_cell_styles()
+        .iter()
+        .map(|v| v.get_name().to_string())
+        .collect();
+    assert_eq!(names, vec!["Normal", "Good", "Currency Input"]);
+
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.get_style_mut("A1").set_named_cell_style(&good);
+    worksheet.set_named_cell_style_by_range("B1:B2", &input);
+    // a cell-level override keeps the other formatting of the style.
+    worksheet.get_style_mut("B2").get_font_mut().set_bold(true);
+
+    let path = std::path::Path::new("./tests/result_files/named_cell_style.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/styles.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"<cellStyles count="3"><cellStyle name="Normal" xfId="0" builtinId="0"/><cellStyle name="Good" xfId="1" builtinId="26"/><cellStyle name="Currency Input" xfId="2"/></cellStyles>"#));
+    assert!(data.contains(r#"<cellStyleXfs count="3">"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_named_cell_styles().len(), 3);
+    assert_eq!(
+        book.get_named_cell_style("Good").unwrap().get_format_id(),
+        &1
+    );
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let a1 = worksheet.get_style("A1");
+    assert_eq!(a1.get_format_id(), &1);
+    assert_eq!(
+        a1.get_background_color().as_ref().unwrap().get_argb(),
+        "FFC6EFCE"
+    );
+    let b2 = worksheet.get_style("B2");
+    assert_eq!(b2.get_format_id(), &2);
+    assert_eq!(
+        b2.get_background_color().as_ref().unwrap().get_argb(),
+        "FFFFCC99"
+    );
+    assert_eq!(
+        b2.get_number_format().as_ref().unwrap().get_format_code(),
+        "#,##0.00"
+    );
+    assert!(*b2.get_font().as_ref().unwrap().get_bold());
+}
+
+#[test]
+fn compact_styles_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=50 {
+        worksheet
+            .get_style_mut((1, row))
+            .set_background_color(format!("FF{:06X}", row * 1000));
+    }
+    let path = std::path::Path::new("./tests/result_files/compact_styles_template.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=50 {
+        worksheet
+            .get_style_mut((1, row))
+            .set_background_color("FFFF0000");
+    }
+    worksheet.get_style_mut("B1").get_font_mut().set_bold(true);
+
+    let read_styles = |path: &std::path::Path| {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut data = String::new();
+        archive
+            .by_name("xl/styles.xml")
+            .unwrap()
+            .read_to_string(&mut data)
+            .unwrap();
+        data
+    };
+
+    let path = std::path::Path::new("./tests/result_files/compact_styles_off.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+    let data = read_styles(path);
+    assert!(data.contains(r#"<fills count="53">"#));
+
+    let path = std::path::Path::new("./tests/result_files/compact_styles_on.xlsx");
+    let mut option = XlsxWriterOption::default();
+    option.set_compact_styles(true);
+    let _ = writer::xlsx::write_with_option(&book, path, &option);
+    let data = read_styles(path);
+    assert!(data.contains(r#"<fonts count="2""#));
+    assert!(data.contains(r#"<fills count="3">"#));
+    assert!(data.contains(r#"<cellXfs count="3">"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(
+        worksheet
+            .get_style("A50")
+            .get_background_color()
+            .as_ref()
+            .unwrap()
+            .get_argb(),
+        "FFFF0000"
+    );
+    assert!(*worksheet
+        .get_style("B1")
+        .get_font()
+        .as_ref()
+        .unwrap()
+        .get_bold());
+}
+
+#[test]
+fn defined_name_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    book.new_sheet("Sheet2").unwrap();
+    book.add_defined_name("TaxRate", "0.2").unwrap();
+    book.add_defined_name("Items", "OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)")
+        .unwrap();
+    book.add_defined_name("Areas", "Sheet1!$A$1:$A$3,Sheet1!$C$1")
+        .unwrap();
+    assert!(book.add_defined_name("taxrate", "0.3").is_err());
+    assert!(book.add_defined_name("A1", "0.3").is_err());
+    let mut defined_name = DefinedName::default();
+    defined_name
+        .set_name("Limit")
+        .set_address("Sheet2!$B$1")
+        .set_local_sheet_id(1)
+        .set_hidden(true)
+        .set_comment("upper limit");
+    book.add_defined_names(defined_name);
+    assert_eq!(book.get_defined_names().len(), 3);
+
+    // a name is usable by the formulas of the worksheet.
+    let worksheet = book.get_sheet_by_name_mut("Sheet2").unwrap();
+    worksheet.get_cell_mut("B1").set_value_number(10);
+    worksheet.get_cell_mut("A1").set_value_number(12);
+    let mut data_validation = DataValidation::default();
+    data_validation
+        .set_type(DataValidationValues::Whole)
+        .set_operator(DataValidationOperatorValues::LessThanOrEqual)
+        .set_formula1("Limit");
+    data_validation
+        .get_sequence_of_references_mut()
+        .set_sqref("A1:A2");
+    let mut data_validations = DataValidations::default();
+    data_validations.add_data_validation_list(data_validation);
+    worksheet.set_data_validations(data_validations);
+    assert_eq!(worksheet.validate_cells().len(), 1);
+
+    // a workbook name needs the workbook to be resolved.
+    worksheet.get_cell_mut("C1").set_value_number(0.5);
+    let mut style = Style::default();
+    style.set_background_color("FFFFC7CE");
+    worksheet.add_cell_is_rule(
+        "C1",
+        ConditionalFormattingOperatorValues::GreaterThan,
+        "TaxRate",
+        style,
+    );
+    let style = worksheet.get_effective_style("C1");
+    assert!(style.get_style().get_background_color().is_none());
+    let style = book.get_effective_style(&1, "C1").unwrap();
+    assert!(style.get_style().get_background_color().is_some());
+    assert_eq!(book.validate_cells(&1).len(), 1);
+
+    let path = std::path::Path::new("./tests/result_files/defined_name.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/workbook.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"<definedName name="TaxRate">0.2</definedName>"#));
+    assert!(data.contains(
+        r#"<definedName name="Limit" comment="upper limit" localSheetId="1" hidden="1">Sheet2!$B$1</definedName>"#
+    ));
+
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_defined_names().len(), 3);
+    assert_eq!(
+        book.find_defined_name("TAXRATE", None)
+            .unwrap()
+            .get_address_str(),
+        "0.2"
+    );
+    assert_eq!(
+        book.find_defined_name("Areas", None)
+            .unwrap()
+            .get_address_str(),
+        "Sheet1!$A$1:$A$3,Sheet1!$C$1"
+    );
+    assert!(book.find_defined_name("Limit", Some(0)).is_none());
+    let defined_name = book.find_defined_name("Limit", Some(1)).unwrap();
+    assert_eq!(defined_name.get_local_sheet_id(), Some(1));
+    assert!(*defined_name.get_hidden());
+    assert_eq!(defined_name.get_comment(), "upper limit");
+}
+
+#[test]
+fn date1904_and_calculation_properties_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.get_cell_mut("A1").set_value_number(45435);
+    worksheet
+        .get_style_mut("A1")
+        .get_number_format_mut()
+        .set_format_code(NumberingFormat::FORMAT_DATE_YYYYMMDD2);
+    assert_eq!(worksheet.get_formatted_value("A1"), "2024-05-23");
+
+    book.set_date1904(true);
+    book.get_calculation_properties_mut()
+        .set_calculation_mode(CalculationModeValues::Manual)
+        .set_full_calculation_on_load(true)
+        .set_iterate(true)
+        .set_iterate_count(50);
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(worksheet.get_formatted_value("A1"), "2028-05-24");
+
+    let path = std::path::Path::new("./tests/result_files/date1904.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/workbook.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert!(data.contains(r#"<workbookPr date1904="1""#));
+    assert!(data.contains(
+        r#"<calcPr calcId="122211" calcMode="manual" fullCalcOnLoad="1" iterate="1" iterateCount="50"/>"#
+    ));
+
+    let book = reader::xlsx::read(path).unwrap();
+    assert!(*book.get_date1904());
+    let calculation_properties = book.get_calculation_properties();
+    assert!(*calculation_properties.get_full_calculation_on_load());
+    assert_eq!(calculation_properties.get_iterate_count(), &50);
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(worksheet.get_formatted_value("A1"), "2028-05-24");
+}
+
+#[test]
+fn datetime_value_test() {
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use umya_spreadsheet::*;
+
+    let date_time =
+        NaiveDateTime::parse_from_str("2024-05-23 13:45:10.250", "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    let date = NaiveDate::from_ymd_opt(1900, 2, 28).unwrap();
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.get_cell_mut("A1").set_value_datetime(date_time);
+    worksheet.get_cell_mut("A2").set_value_date(date);
+    worksheet
+        .get_cell_mut("A3")
+        .set_value_duration(Duration::minutes(26 * 60 + 30));
+    worksheet.get_cell_mut("A4").set_value_number(45435);
+    assert_eq!(worksheet.get_value_number("A2"), Some(59f64));
+    assert_eq!(worksheet.get_value_number("A3"), Some(26.5 / 24f64));
+    assert_eq!(
+        worksheet
+            .get_style("A3")
+            .get_number_format()
+            .as_ref()
+            .unwrap()
+            .get_format_code(),
+        NumberingFormat::FORMAT_DURATION
+    );
+    assert_eq!(worksheet.get_cell("A4").unwrap().get_value_datetime(), None);
+
+    let path = std::path::Path::new("./tests/result_files/datetime_value.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(
+        worksheet.get_cell("A1").unwrap().get_value_datetime(),
+        Some(date_time)
+    );
+    assert_eq!(
+        worksheet.get_cell("A2").unwrap().get_value_datetime(),
+        Some(date.and_hms_opt(0, 0, 0).unwrap())
+    );
+
+    // the cells follow the date system of the workbook.
+    let date = NaiveDate::from_ymd_opt(2024, 5, 23).unwrap();
+    let mut book = new_file();
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("B1")
+        .set_value_number(1);
+    book.set_date1904(true);
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("B1")
+        .set_value_date(date);
+    book.new_sheet("Sheet2").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet2").unwrap();
+    worksheet.set_value_datetime("A1", date_time);
+    worksheet.get_cell_mut("A2").set_value_date(date);
+    assert_eq!(worksheet.get_value_number("A1").unwrap().floor(), 43973f64);
+    assert_eq!(worksheet.get_value_number("A2"), Some(43973f64));
+
+    let path = std::path::Path::new("./tests/result_files/datetime_value_1904.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet2").unwrap();
+    assert_eq!(worksheet.get_value_datetime("A1"), Some(date_time));
+    let cell = worksheet.get_cell("A2").unwrap();
+    assert_eq!(cell.get_value_datetime(), date.and_hms_opt(0, 0, 0));
+    assert_eq!(
+        cell.get_typed_value(),
+        CellData::Date(date.and_hms_opt(0, 0, 0).unwrap())
+    );
+    let cell = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_cell("B1")
+        .unwrap();
+    assert_eq!(cell.get_value_datetime(), date.and_hms_opt(0, 0, 0));
+}
+
+#[test]
+fn typed_value_and_error_test() {
+    use chrono::NaiveDate;
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let date = NaiveDate::from_ymd_opt(2024, 5, 23).unwrap();
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value_error(CellErrorType::NA);
+    worksheet
+        .get_cell_mut("A2")
+        .set_value_error(CellErrorType::Div0)
+        .set_formula("1/0");
+    worksheet.get_cell_mut("A3").set_value("#REF!");
+    worksheet.get_cell_mut("A4").set_value_number(1.5);
+    worksheet.get_cell_mut("A5").set_value_string("text");
+    worksheet.get_cell_mut("A6").set_value_bool(true);
+    worksheet.set_value_date("A7", date);
+
+    assert_eq!(worksheet.get_value("A1"), "#N/A");
+    assert_eq!(
+        worksheet.get_typed_value("A3"),
+        CellData::Error(CellErrorType::Ref)
+    );
+
+    let path = std::path::Path::new("./tests/result_files/typed_value.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains(r#"<c r="A1" t="e"><v>#N/A</v></c>"#));
+    assert!(xml.contains(r#"<c r="A2" t="e"><f>1/0</f><v>#DIV/0!</v></c>"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(
+        worksheet.get_typed_value("A1"),
+        CellData::Error(CellErrorType::NA)
+    );
+    let cell = worksheet.get_cell("A2").unwrap();
+    assert_eq!(cell.get_value_error(), Some(&CellErrorType::Div0));
+    assert_eq!(cell.get_formula(), "1/0");
+    assert_eq!(worksheet.get_typed_value("A4"), CellData::Number(1.5));
+    assert_eq!(
+        worksheet.get_typed_value("A5"),
+        CellData::Text("text".into())
+    );
+    assert_eq!(worksheet.get_typed_value("A6"), CellData::Bool(true));
+    assert_eq!(
+        worksheet.get_typed_value("A7"),
+        CellData::Date(date.and_hms_opt(0, 0, 0).unwrap())
+    );
+    assert_eq!(worksheet.get_typed_value("A8"), CellData::Empty);
+}
+
+#[test]
+fn shared_and_array_formula_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.get_cell_mut("A1").set_value_number(1);
+    worksheet.get_cell_mut("A2").set_value_number(2);
+    worksheet.get_cell_mut("A3").set_value_number(3);
+    worksheet.set_formula_shared("B1:B3", "A1*2");
+    worksheet
+        .get_cell_mut("C1")
+        .set_formula_array("SUM(A1:A3*B1:B3)", "C1");
+    worksheet
+        .get_cell_mut("D1")
+        .set_formula_dynamic_array("FILTER(A1:A3,A1:A3>1)", "D1:D2");
+
+    assert_eq!(worksheet.get_formula("B1"), "A1*2");
+    assert_eq!(worksheet.get_formula("B3"), "A3*2");
+    worksheet.set_formula_shared("F1:G3", "A1+$A$1");
+    assert_eq!(worksheet.get_formula("G3"), "B3+$A$1");
+    worksheet.get_cell_mut("F2").set_value_number(0);
+    assert_eq!(worksheet.get_formula("F3"), "A3+$A$1");
+
+    let path = std::path::Path::new("./tests/result_files/shared_formula.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains(r#"<f t="shared" ref="B1:B3" si="0">A1*2</f>"#));
+    assert!(xml.contains(r#"<c r="B2"><f t="shared" si="0"/>"#));
+    assert!(xml.contains(r#"<f t="array" ref="C1">SUM(A1:A3*B1:B3)</f>"#));
+    assert!(xml.contains(
+        r#"<c r="D1" cm="1"><f t="array" ref="D1:D2">_xlfn._xlws.FILTER(A1:A3,A1:A3&gt;1)</f>"#
+    ));
+    assert!(archive.by_name("xl/metadata.xml").is_ok());
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    assert_eq!(worksheet.get_formula("B2"), "A2*2");
+    let cell = worksheet.get_cell("D1").unwrap();
+    assert_eq!(cell.get_formula(), "FILTER(A1:A3,A1:A3>1)");
+    assert!(*cell.get_cell_formula().unwrap().get_dynamic_array());
+
+    book.insert_new_row("Sheet1", &1, &1);
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let formula = worksheet
+        .get_cell("B2")
+        .unwrap()
+        .get_cell_formula()
+        .unwrap();
+    assert_eq!(formula.get_reference(), "B2:B4");
+    assert_eq!(formula.get_shared_index(), Some(0));
+
+    // the deprecated formula attributes map to the CellFormula.
+    #[allow(deprecated)]
+    {
+        let cell_value = worksheet.get_cell("B3").unwrap().get_cell_value();
+        assert_eq!(
+            cell_value.get_formula_attributes(),
+            vec![("t", "shared"), ("si", "0")]
+        );
+        let mut cell_value = cell_value.clone();
+        cell_value.set_formula_attributes(vec![
+            ("t".into(), "shared".into()),
+            ("si".into(), "1".into()),
+        ]);
+        let formula = cell_value.get_cell_formula().unwrap();
+        assert!(formula.is_shared());
+        assert_eq!(formula.get_shared_index(), Some(1));
+        cell_value.set_formula_attributes(Vec::new());
+        assert!(!cell_value.is_formula());
+    }
+}
+
+#[test]
+fn hyperlink_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    book.new_sheet("Sheet 2").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value("Example")
+        .get_hyperlink_mut()
+        .set_url("https://example.com/")
+        .set_tooltip("Open")
+        .set_reference("A1:C1");
+    worksheet
+        .get_cell_mut("A2")
+        .get_hyperlink_mut()
+        .set_location("'Sheet 2'!B3")
+        .set_display("Go");
+    worksheet
+        .get_cell_mut("A5")
+        .get_hyperlink_mut()
+        .set_location("B3:C4");
+    worksheet
+        .get_cell_mut("A3")
+        .get_hyperlink_mut()
+        .set_url("https://example.com/removed");
+    worksheet.remove_hyperlink("A3");
+    worksheet
+        .get_cell_mut("A4")
+        .set_formula(r#"HYPERLINK("https://example.com/","Example")"#);
+
+    let hyperlink = worksheet.get_cell("A4").unwrap().get_formula_hyperlink();
+    assert_eq!(hyperlink.unwrap().get_url(), "https://example.com/");
+    worksheet.get_cell_mut("A5").set_formula("売上データ!A1");
+    assert!(worksheet
+        .get_cell("A5")
+        .unwrap()
+        .get_formula_hyperlink()
+        .is_none());
+    assert!(worksheet.get_cell("A3").unwrap().get_hyperlink().is_none());
+
+    let path = std::path::Path::new("./tests/result_files/hyperlink.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains(r#"<hyperlink ref="A1:C1" r:id="rId1" tooltip="Open"/>"#));
+    assert!(xml.contains(r#"<hyperlink ref="A2" location="&apos;Sheet 2&apos;!B3" display="Go"/>"#));
+    let mut rels = String::new();
+    archive
+        .by_name("xl/worksheets/_rels/sheet1.xml.rels")
+        .unwrap()
+        .read_to_string(&mut rels)
+        .unwrap();
+    assert_eq!(rels.matches("relationships/hyperlink").count(), 1);
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    let hyperlink = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_cell("A1")
+        .unwrap()
+        .get_hyperlink()
+        .clone()
+        .unwrap();
+    assert_eq!(hyperlink.get_url(), "https://example.com/");
+    assert_eq!(hyperlink.get_reference(), "A1:C1");
+
+    book.insert_new_column("Sheet1", "A", &1);
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let hyperlink = worksheet.get_cell("B1").unwrap().get_hyperlink().clone();
+    assert_eq!(hyperlink.unwrap().get_reference(), "B1:D1");
+    let hyperlink = worksheet.get_cell("B2").unwrap().get_hyperlink().clone();
+    assert_eq!(hyperlink.unwrap().get_location(), "'Sheet 2'!B3");
+    let hyperlink = worksheet.get_cell("B5").unwrap().get_hyperlink().clone();
+    assert_eq!(hyperlink.unwrap().get_location(), "C3:D4");
+}
+
+#[test]
+fn custom_properties_test() {
+    use chrono::NaiveDate;
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let date = NaiveDate::from_ymd_opt(2024, 5, 23)
+        .unwrap()
+        .and_hms_opt(9, 30, 0)
+        .unwrap();
+
+    let mut book = new_file();
+    book.get_properties_mut()
+        .set_custom_property("MSIP_Label_0000_Enabled", "true")
+        .set_custom_property("Revision", 3)
+        .set_custom_property("Approved", false)
+        .set_custom_property("Reviewed", date);
+
+    let path = std::path::Path::new("./tests/result_files/custom_properties.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("docProps/custom.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains(r#"<property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="MSIP_Label_0000_Enabled"><vt:lpwstr>true</vt:lpwstr></property>"#));
+    assert!(xml.contains(r#"pid="3" name="Revision"><vt:i4>3</vt:i4>"#));
+    assert!(xml.contains(r#"<vt:filetime>2024-05-23T09:30:00Z</vt:filetime>"#));
+    let mut rels = String::new();
+    archive
+        .by_name("_rels/.rels")
+        .unwrap()
+        .read_to_string(&mut rels)
+        .unwrap();
+    assert!(rels.contains("docProps/custom.xml"));
+    let mut content_types = String::new();
+    archive
+        .by_name("[Content_Types].xml")
+        .unwrap()
+        .read_to_string(&mut content_types)
+        .unwrap();
+    assert!(content_types.contains("custom-properties+xml"));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let properties = book.get_properties();
+    assert_eq!(properties.get_custom_properties().len(), 4);
+    let value = properties
+        .get_custom_property("MSIP_Label_0000_Enabled")
+        .unwrap()
+        .get_value();
+    assert_eq!(value.get_text(), Some("true"));
+    let value = properties
+        .get_custom_property("Revision")
+        .unwrap()
+        .get_value();
+    assert_eq!(value.get_number(), Some(3.0));
+    let value = properties
+        .get_custom_property("Approved")
+        .unwrap()
+        .get_value();
+    assert_eq!(value.get_bool(), Some(false));
+    let value = properties
+        .get_custom_property("Reviewed")
+        .unwrap()
+        .get_value();
+    assert_eq!(value.get_date(), Some(&date));
+}
+
+#[test]
+fn app_properties_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    book.new_sheet("Data Sheet").unwrap();
+    book.add_defined_name("TaxRate", "0.2").unwrap();
+    let mut defined_name = DefinedName::default();
+    defined_name
+        .set_name("Local")
+        .set_address("'Data Sheet'!$A$1")
+        .set_local_sheet_id(1);
+    book.get_defined_names_mut().push(defined_name);
+    book.get_app_properties_mut()
+        .set_application("Report Builder")
+        .set_app_version("2.0100")
+        .set_company("Example Inc.")
+        .set_hyperlink_base("https://example.com/docs/");
+    book.get_properties_mut().set_manager("Jane Doe");
+
+    let path = std::path::Path::new("./tests/result_files/app_properties.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("docProps/app.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains("<Application>Report Builder</Application>"));
+    assert!(xml.contains("<AppVersion>2.0100</AppVersion>"));
+    assert!(xml.contains("<Manager>Jane Doe</Manager>"));
+    assert!(xml.contains("<HyperlinkBase>https://example.com/docs/</HyperlinkBase>"));
+    assert!(xml.contains(r#"<vt:vector size="4" baseType="variant"><vt:variant><vt:lpstr>Worksheets</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant><vt:variant><vt:lpstr>Named Ranges</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant></vt:vector>"#));
+    assert!(xml.contains(r#"<vt:vector size="4" baseType="lpstr"><vt:lpstr>Sheet1</vt:lpstr><vt:lpstr>Data Sheet</vt:lpstr><vt:lpstr>TaxRate</vt:lpstr><vt:lpstr>&apos;Data Sheet&apos;!Local</vt:lpstr></vt:vector>"#));
+
+    let book = reader::xlsx::read(path).unwrap();
+    let app_properties = book.get_app_properties();
+    assert_eq!(app_properties.get_application(), "Report Builder");
+    assert_eq!(app_properties.get_app_version(), "2.0100");
+    assert_eq!(app_properties.get_company(), "Example Inc.");
+    assert_eq!(app_properties.get_manager(), "Jane Doe");
+    assert_eq!(
+        app_properties.get_hyperlink_base(),
+        "https://example.com/docs/"
+    );
+    assert_eq!(book.get_properties().get_company(), "Example Inc.");
+}
+
+#[test]
+fn external_link_test() {
+    use std::io::Read;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let mut link = ExternalLink::default();
+    link.set_target("Budget.xlsx")
+        .set_cell_value("Sheet1", "A1", "100", "n")
+        .set_cell_value("Sheet1", "B1", "Total", "str");
+    book.add_external_link(link);
+    book.add_defined_name("Budget", "[1]Sheet1!$A$1").unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value("200")
+        .set_formula("[1]Sheet1!A1*2");
+    worksheet.get_cell_mut("A2").set_formula("SUM(B1:B2)");
+
+    let path = std::path::Path::new("./tests/result_files/external_link.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut read_file = |name: &str| {
+        let mut xml = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+        xml
+    };
+    let xml = read_file("xl/externalLinks/externalLink1.xml");
+    assert!(xml.contains(r#"<externalBook r:id="rId1">"#));
+    assert!(xml.contains(r#"<sheetNames><sheetName val="Sheet1"/></sheetNames>"#));
+    assert!(
+        xml.contains(r#"<cell r="A1"><v>100</v></cell><cell r="B1" t="str"><v>Total</v></cell>"#)
+    );
+    let xml = read_file("xl/externalLinks/_rels/externalLink1.xml.rels");
+    assert!(xml.contains(r#"Target="Budget.xlsx" TargetMode="External""#));
+    let xml = read_file("xl/workbook.xml");
+    assert!(xml
+        .contains(r#"<externalReferences><externalReference r:id="rId2"/></externalReferences>"#));
+    let xml = read_file("xl/_rels/workbook.xml.rels");
+    assert!(xml.contains(r#"Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLink" Target="externalLinks/externalLink1.xml""#));
+    let xml = read_file("[Content_Types].xml");
+    assert!(xml.contains(r#"PartName="/xl/externalLinks/externalLink1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml""#));
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    let link = &book.get_external_links()[0];
+    assert_eq!(link.get_target(), "Budget.xlsx");
+    assert_eq!(link.get_cell("Sheet1", "A1").unwrap().get_value(), "100");
+    assert_eq!(
+        link.get_cell("Sheet1", "B1").unwrap().get_data_type(),
+        "str"
+    );
+
+    book.get_external_links_mut()[0].set_target("Budget 2024.xlsx");
+    book.break_external_link(&0);
+    assert!(book.get_external_links().is_empty());
+    assert!(book.get_defined_names().is_empty());
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert!(!worksheet.get_cell("A1").unwrap().is_formula());
+    assert_eq!(worksheet.get_value("A1"), "200");
+    assert_eq!(worksheet.get_formula("A2"), "SUM(B1:B2)");
+}
+
+#[test]
+fn external_link_dde_test() {
+    use std::io::Read;
+    use std::io::Write;
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let mut link = ExternalLink::default();
+    link.set_target("Budget.xlsx");
+    book.add_external_link(link);
+    let path = std::path::Path::new("./tests/result_files/external_link_dde.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    // replace the workbook link by a DDE link.
+    let dde_link = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<externalLink xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><ddeLink ddeService="Excel" ddeTopic="Budget.xlsx"><ddeItems><ddeItem name="R1C1" advise="1"/></ddeItems></ddeLink></externalLink>"#;
+    let path_in = std::path::Path::new("./tests/result_files/external_link_dde_in.xlsx");
+    {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path_in).unwrap());
+        for i in 0..archive.len() {
+            let file = archive.by_index(i).unwrap();
+            let name = file.name().to_string();
+            if name == "xl/externalLinks/externalLink1.xml" {
+                zip.start_file(name, zip::write::FileOptions::default())
+                    .unwrap();
+                zip.write_all(dde_link.as_bytes()).unwrap();
+            } else if name != "xl/externalLinks/_rels/externalLink1.xml.rels" {
+                zip.raw_copy_file(file).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    let book = reader::xlsx::read(path_in).unwrap();
+    assert!(!book.get_external_links()[0].is_external_book());
+    let path_out = std::path::Path::new("./tests/result_files/external_link_dde_out.xlsx");
+    let _ = writer::xlsx::write(&book, path_out);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path_out).unwrap()).unwrap();
+    let mut data = String::new();
+    archive
+        .by_name("xl/externalLinks/externalLink1.xml")
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert_eq!(data, dde_link);
+    assert!(archive
+        .by_name("xl/externalLinks/_rels/externalLink1.xml.rels")
+        .is_err());
+}